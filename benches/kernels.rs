@@ -7,6 +7,10 @@
 //! - throughput (GB/s for memory-bound ops)
 //! - fused vs unfused comparison
 
+use blawktrust::builtins::kernels_masked::dlog_masked;
+use blawktrust::builtins::kernels_wordwise::dlog_wordwise;
+use blawktrust::table::Bitmap;
+use blawktrust::{sum, Column};
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
 // Tight loop kernels (kdb-style)
@@ -170,6 +174,63 @@ fn bench_dlog_fused_vs_unfused(c: &mut Criterion) {
     group.finish();
 }
 
+/// Word-wise vs per-bit masked dlog at lag=1 (unaligned lagged word)
+fn bench_dlog_wordwise_vs_masked(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dlog_wordwise_vs_masked");
+
+    let size = 1_000_000;
+    let data: Vec<f64> = (0..size).map(|i| 100.0 + (i as f64) * 0.01).collect();
+    let valid = Bitmap::new_all_valid(size);
+    let mut out = vec![0.0; size];
+    let mut out_valid = Bitmap::new_all_null(size);
+
+    group.throughput(Throughput::Elements(size as u64));
+
+    group.bench_function("wordwise", |b| {
+        b.iter(|| {
+            dlog_wordwise(
+                &mut out,
+                &mut out_valid,
+                black_box(&data),
+                &valid,
+                black_box(1),
+            );
+            black_box(&out);
+        });
+    });
+
+    group.bench_function("masked_per_bit", |b| {
+        b.iter(|| {
+            dlog_masked(
+                &mut out,
+                &mut out_valid,
+                black_box(&data),
+                &valid,
+                black_box(1),
+            );
+            black_box(&out);
+        });
+    });
+
+    group.finish();
+}
+
+/// `ops::sum` (4-lane SIMD path when built with `--features simd`)
+fn bench_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+
+    for size in [1_000, 10_000, 100_000, 1_000_000].iter() {
+        let col = Column::F64((0..*size).map(|i| 100.0 + (i as f64) * 0.01).collect());
+
+        group.throughput(Throughput::Elements(*size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
+            b.iter(|| black_box(sum(black_box(&col))));
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_throughput(c: &mut Criterion) {
     let mut group = c.benchmark_group("throughput");
 
@@ -202,6 +263,8 @@ criterion_group!(
     bench_shift,
     bench_sub,
     bench_dlog_fused_vs_unfused,
+    bench_dlog_wordwise_vs_masked,
+    bench_sum,
     bench_throughput
 );
 criterion_main!(benches);
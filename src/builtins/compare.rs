@@ -0,0 +1,148 @@
+//! Comparison kernels producing Bool mask columns
+//!
+//! IEEE semantics: any comparison involving NaN is false, so a NaN
+//! position never survives a filter built from these masks.
+
+use crate::table::Column;
+
+/// Elementwise compare against a constant
+#[inline]
+fn compare_const<F>(x: &Column, c: f64, name: &str, f: F) -> Column
+where
+    F: Fn(f64, f64) -> bool,
+{
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("{}: expected F64 column", name);
+    };
+
+    Column::Bool(data.iter().map(|&v| f(v, c)).collect())
+}
+
+/// Elementwise compare two columns of equal length
+#[inline]
+fn compare_cols<F>(x: &Column, y: &Column, name: &str, f: F) -> Column
+where
+    F: Fn(f64, f64) -> bool,
+{
+    let (Ok(a), Ok(b)) = (x.as_f64_slice(), y.as_f64_slice()) else {
+        panic!("{}: expected F64 columns", name);
+    };
+    assert_eq!(a.len(), b.len(), "{}: column length mismatch", name);
+
+    Column::Bool(a.iter().zip(b).map(|(&u, &v)| f(u, v)).collect())
+}
+
+/// x > c
+pub fn gt_const(x: &Column, c: f64) -> Column {
+    compare_const(x, c, "gt_const", |a, b| a > b)
+}
+
+/// x < c
+pub fn lt_const(x: &Column, c: f64) -> Column {
+    compare_const(x, c, "lt_const", |a, b| a < b)
+}
+
+/// x >= c
+pub fn ge_const(x: &Column, c: f64) -> Column {
+    compare_const(x, c, "ge_const", |a, b| a >= b)
+}
+
+/// x <= c
+pub fn le_const(x: &Column, c: f64) -> Column {
+    compare_const(x, c, "le_const", |a, b| a <= b)
+}
+
+/// x == c
+pub fn eq_const(x: &Column, c: f64) -> Column {
+    compare_const(x, c, "eq_const", |a, b| a == b)
+}
+
+/// x > y (elementwise)
+pub fn gt(x: &Column, y: &Column) -> Column {
+    compare_cols(x, y, "gt", |a, b| a > b)
+}
+
+/// x < y (elementwise)
+pub fn lt(x: &Column, y: &Column) -> Column {
+    compare_cols(x, y, "lt", |a, b| a < b)
+}
+
+/// x >= y (elementwise)
+pub fn ge(x: &Column, y: &Column) -> Column {
+    compare_cols(x, y, "ge", |a, b| a >= b)
+}
+
+/// x <= y (elementwise)
+pub fn le(x: &Column, y: &Column) -> Column {
+    compare_cols(x, y, "le", |a, b| a <= b)
+}
+
+/// x == y (elementwise)
+pub fn eq(x: &Column, y: &Column) -> Column {
+    compare_cols(x, y, "eq", |a, b| a == b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Table;
+
+    #[test]
+    fn test_const_comparisons() {
+        let x = Column::new_f64(vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(gt_const(&x, 2.0).bool_data(), &[false, false, true]);
+        assert_eq!(lt_const(&x, 2.0).bool_data(), &[true, false, false]);
+        assert_eq!(ge_const(&x, 2.0).bool_data(), &[false, true, true]);
+        assert_eq!(le_const(&x, 2.0).bool_data(), &[true, true, false]);
+        assert_eq!(eq_const(&x, 2.0).bool_data(), &[false, true, false]);
+    }
+
+    #[test]
+    fn test_const_nan_is_false() {
+        let x = Column::new_f64(vec![f64::NAN, 1.0]);
+
+        for mask in [
+            gt_const(&x, 0.0),
+            lt_const(&x, 5.0),
+            ge_const(&x, 0.0),
+            le_const(&x, 5.0),
+        ] {
+            assert_eq!(mask.bool_data(), &[false, true]);
+        }
+        assert_eq!(eq_const(&x, f64::NAN).bool_data(), &[false, false]);
+    }
+
+    #[test]
+    fn test_column_comparisons_nan_both_sides() {
+        let x = Column::new_f64(vec![1.0, f64::NAN, 3.0, f64::NAN]);
+        let y = Column::new_f64(vec![2.0, 2.0, f64::NAN, f64::NAN]);
+
+        assert_eq!(lt(&x, &y).bool_data(), &[true, false, false, false]);
+        assert_eq!(gt(&x, &y).bool_data(), &[false, false, false, false]);
+        assert_eq!(le(&x, &y).bool_data(), &[true, false, false, false]);
+        assert_eq!(ge(&x, &y).bool_data(), &[false, false, false, false]);
+        assert_eq!(eq(&x, &x).bool_data(), &[true, false, true, false]);
+    }
+
+    #[test]
+    #[should_panic(expected = "gt: column length mismatch")]
+    fn test_column_length_mismatch() {
+        gt(
+            &Column::new_f64(vec![1.0]),
+            &Column::new_f64(vec![1.0, 2.0]),
+        );
+    }
+
+    #[test]
+    fn test_mask_feeds_filter_rows() {
+        let table = Table::new(
+            vec!["px".to_string()],
+            vec![Column::new_f64(vec![10.0, f64::NAN, 30.0])],
+        );
+        let mask = gt_const(&table.columns[0], 15.0);
+
+        let out = table.filter_rows(&mask).unwrap();
+        assert_eq!(out.columns[0].f64_data(), &[30.0]);
+    }
+}
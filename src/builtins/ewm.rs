@@ -0,0 +1,106 @@
+//! Exponentially weighted moving average
+//!
+//! Recursive, causal kernels: out[i] uses x[0..=i] only. NaN inputs are
+//! skipped without resetting the average (the prior value is carried), and
+//! decay advances only on valid observations.
+
+use crate::table::Column;
+
+/// Smoothing factor for a span: alpha = 2 / (span + 1)
+pub fn alpha_from_span(span: f64) -> f64 {
+    2.0 / (span + 1.0)
+}
+
+/// Single-pass EWMA kernel
+///
+/// # Arguments
+/// * `x` - Input data
+/// * `alpha` - Smoothing factor in (0, 1]; outside that range yields all NaN
+/// * `adjust` - Recurrence to use (see below)
+///
+/// With `adjust = true` (pandas default) each output is the weighted mean
+/// sum((1-alpha)^k * x[t-k]) / sum((1-alpha)^k) over the valid observations
+/// so far. With `adjust = false` it is y = (1-alpha) * y_prev + alpha * x,
+/// seeded with the first valid value.
+///
+/// # Returns
+/// NaN before the first valid observation; at a NaN input, the previous
+/// output.
+pub fn ewma_f64(x: &[f64], alpha: f64, adjust: bool) -> Vec<f64> {
+    let mut out = vec![f64::NAN; x.len()];
+    if !(alpha > 0.0 && alpha <= 1.0) {
+        return out;
+    }
+
+    let decay = 1.0 - alpha;
+    let mut current = f64::NAN;
+    // Adjusted form: weighted sum and total weight
+    let mut num = 0.0;
+    let mut den = 0.0;
+
+    for (slot, &val) in out.iter_mut().zip(x) {
+        if !val.is_nan() {
+            current = if adjust {
+                num = decay * num + val;
+                den = decay * den + 1.0;
+                num / den
+            } else if current.is_nan() {
+                val
+            } else {
+                decay * current + alpha * val
+            };
+        }
+        *slot = current;
+    }
+
+    out
+}
+
+/// EWMA of an F64 column (adjusted weights, see `ewma_f64`)
+pub fn ewma_column(x: &Column, alpha: f64) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("ewma_column: expected F64 column");
+    };
+
+    Column::F64(ewma_f64(data, alpha, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtins::test_util::assert_f64_close;
+
+    #[test]
+    fn test_ewma_unadjusted_interior_nan() {
+        let x = [1.0, 2.0, f64::NAN, 4.0];
+        // y = 1; 0.5*1 + 0.5*2 = 1.5; carried; 0.5*1.5 + 0.5*4 = 2.75
+        assert_f64_close(&ewma_f64(&x, 0.5, false), &[1.0, 1.5, 1.5, 2.75], 1e-12);
+    }
+
+    #[test]
+    fn test_ewma_adjusted_interior_nan() {
+        let x = [1.0, 2.0, f64::NAN, 4.0];
+        // (2 + 0.5*1) / 1.5; then (4 + 0.5*2 + 0.25*1) / 1.75
+        assert_f64_close(
+            &ewma_f64(&x, 0.5, true),
+            &[1.0, 5.0 / 3.0, 5.0 / 3.0, 3.0],
+            1e-12,
+        );
+
+        let col = ewma_column(&Column::F64(x.to_vec()), 0.5);
+        assert_f64_close(col.f64_data(), &[1.0, 5.0 / 3.0, 5.0 / 3.0, 3.0], 1e-12);
+    }
+
+    #[test]
+    fn test_ewma_leading_nan_and_bad_alpha() {
+        let x = [f64::NAN, f64::NAN, 3.0, 5.0];
+        let out = ewma_f64(&x, alpha_from_span(3.0), false);
+        assert_f64_close(&out, &[f64::NAN, f64::NAN, 3.0, 4.0], 1e-12);
+
+        assert!(ewma_f64(&x, 0.0, true).iter().all(|v| v.is_nan()));
+        assert!(ewma_f64(&x, 1.5, true).iter().all(|v| v.is_nan()));
+
+        // alpha = 1 tracks the input
+        assert_f64_close(&ewma_f64(&[1.0, 7.0], 1.0, true), &[1.0, 7.0], 1e-12);
+    }
+}
@@ -1,4 +1,9 @@
 /// Ultra-fast kernels following kdb optimization principles
+///
+/// Every level computes each return with `dlog_value`, so non-positive
+/// inputs give NaN as in every other dlog path; `NA` marks only missing
+/// inputs (and the first `lag` rows where noted).
+use crate::builtins::kernels_masked::dlog_value;
 use std::mem::MaybeUninit;
 
 const NA: f64 = -99999.0;
@@ -11,8 +16,8 @@ pub fn dlog_v0_baseline(data: &[f64], lag: usize) -> Vec<f64> {
         let curr = data[i];
         let prev = data[i - lag];
 
-        if curr != NA && curr > 0.0 && prev != NA && prev > 0.0 {
-            result[i] = curr.ln() - prev.ln();
+        if curr != NA && prev != NA {
+            result[i] = dlog_value(curr, prev);
         }
     }
 
@@ -34,8 +39,8 @@ pub fn dlog_v1_no_init(data: &[f64], lag: usize) -> Vec<f64> {
     for i in lag..n {
         let curr = data[i];
         let prev = data[i - lag];
-        let ok = curr != NA && curr > 0.0 && prev != NA && prev > 0.0;
-        out[i].write(if ok { curr.ln() - prev.ln() } else { NA });
+        let ok = curr != NA && prev != NA;
+        out[i].write(if ok { dlog_value(curr, prev) } else { NA });
     }
 
     unsafe { std::mem::transmute(out) }
@@ -57,15 +62,15 @@ pub fn dlog_v2_no_bounds(data: &[f64], lag: usize) -> Vec<f64> {
             let curr = *dp.add(i);
             let prev = *dp.add(i - lag);
 
-            if curr != NA && curr > 0.0 && prev != NA && prev > 0.0 {
-                *op.add(i) = curr.ln() - prev.ln();
+            if curr != NA && prev != NA {
+                *op.add(i) = dlog_value(curr, prev);
             }
         }
     }
     out
 }
 
-/// Level 3: Fast path for no-nulls (assumes all positive prices)
+/// Level 3: Fast path for no-nulls (non-positive prices give NaN)
 pub fn dlog_v3_no_nulls(data: &[f64], lag: usize) -> Vec<f64> {
     let n = data.len();
     let mut out = vec![0.0; n];
@@ -84,7 +89,7 @@ pub fn dlog_v3_no_nulls(data: &[f64], lag: usize) -> Vec<f64> {
         for i in lag..n {
             let curr = *xp.add(i);
             let prev = *xp.add(i - lag);
-            *op.add(i) = curr.ln() - prev.ln();
+            *op.add(i) = dlog_value(curr, prev);
         }
     }
     out
@@ -115,7 +120,7 @@ pub fn dlog_v4_masked(data: &[f64], valid: &[u8], lag: usize) -> (Vec<f64>, Vec<
             if (v_curr & v_prev) == 1 {
                 let curr = *xp.add(i);
                 let prev = *xp.add(i - lag);
-                *op.add(i) = curr.ln() - prev.ln();
+                *op.add(i) = dlog_value(curr, prev);
                 *ov.add(i) = 1;
             }
         }
@@ -139,3 +144,30 @@ pub fn dlog_v5_masked_fast(
     let (out, out_valid) = dlog_v4_masked(data, valid, lag);
     (out, Some(out_valid))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_level_guards_non_positive_inputs() {
+        // 100 -> 0 -> 50 -> -5 -> 20: every return touching 0 or -5 is NaN
+        let data = [100.0, 0.0, 50.0, -5.0, 20.0];
+        let valid = [1u8; 5];
+        let outputs = [
+            dlog_v0_baseline(&data, 1),
+            dlog_v1_no_init(&data, 1),
+            dlog_v2_no_bounds(&data, 1),
+            dlog_v3_no_nulls(&data, 1),
+            dlog_v4_masked(&data, &valid, 1).0,
+            dlog_v5_masked_fast(&data, None, 1).0,
+        ];
+        for out in &outputs {
+            assert!(out[1..].iter().all(|v| v.is_nan()), "{:?}", out);
+        }
+
+        // Positive inputs agree with the shared rule
+        let out = dlog_v2_no_bounds(&[100.0, 110.0], 1);
+        assert_eq!(out[1], dlog_value(110.0, 100.0));
+    }
+}
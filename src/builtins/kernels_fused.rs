@@ -6,6 +6,7 @@
 //! Philosophy: "kdb primitive set" - small number of heavily-used
 //! fused ops, optimized hard.
 
+use crate::builtins::kernels_masked::{dlog_value, ln_value};
 use crate::table::Bitmap;
 
 // ===========================================================================
@@ -40,9 +41,7 @@ pub fn dlog_scale_add_no_nulls(out: &mut [f64], x: &[f64], lag: usize, a: f64, b
 
         // 🔥 FUSED LOOP: ln() -> diff -> scale -> add in ONE PASS
         for i in lag..n {
-            let curr_ln = (*xp.add(i)).ln();
-            let prev_ln = (*xp.add(i - lag)).ln();
-            *op.add(i) = a * (curr_ln - prev_ln) + b;
+            *op.add(i) = a * dlog_value(*xp.add(i), *xp.add(i - lag)) + b;
         }
     }
 }
@@ -88,9 +87,7 @@ pub fn dlog_scale_add_masked(
 
             if v_curr && v_prev {
                 // Both valid: fused compute
-                let curr_ln = (*xp.add(i)).ln();
-                let prev_ln = (*xp.add(i - lag)).ln();
-                *op.add(i) = a * (curr_ln - prev_ln) + b;
+                *op.add(i) = a * dlog_value(*xp.add(i), *xp.add(i - lag)) + b;
                 out_valid.set(i, true);
             } else {
                 // Invalid: just set bit, don't write data (Step 1)
@@ -103,7 +100,7 @@ pub fn dlog_scale_add_masked(
 // ===========================================================================
 // LN_SCALE_ADD: a * ln(x) + b
 // ===========================================================================
-// Pattern: out = a * ln(x) + b
+// Pattern: out = a * ln(x) + b (NaN for x <= 0, see `ln_value`)
 // Use case: Log transform with scaling/offset
 
 /// ln_scale_add fast path: No nulls
@@ -115,7 +112,7 @@ pub fn ln_scale_add_no_nulls(out: &mut [f64], x: &[f64], a: f64, b: f64) {
         let op = out.as_mut_ptr();
 
         for i in 0..x.len() {
-            *op.add(i) = a * (*xp.add(i)).ln() + b;
+            *op.add(i) = a * ln_value(*xp.add(i)) + b;
         }
     }
 }
@@ -140,7 +137,7 @@ pub fn ln_scale_add_masked(
 
         for i in 0..n {
             if x_valid.get(i) {
-                *op.add(i) = a * (*xp.add(i)).ln() + b;
+                *op.add(i) = a * ln_value(*xp.add(i)) + b;
                 out_valid.set(i, true);
             } else {
                 out_valid.set(i, false);
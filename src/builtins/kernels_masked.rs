@@ -10,7 +10,34 @@ use crate::table::Bitmap;
 // DLOG: Log returns
 // ===========================================================================
 
-/// dlog fast path: No nulls (assumes all data valid and positive)
+/// One log return: ln(curr) - ln(prev), NaN unless both inputs are positive
+///
+/// Shared by every dlog kernel (column, fused, rowwise, masked) so they agree
+/// on non-positive and NaN inputs. Without the guard ln(0) = -inf would leak
+/// ±inf into the result while a negative input gives NaN.
+#[inline(always)]
+pub(crate) fn dlog_value(curr: f64, prev: f64) -> f64 {
+    if curr > 0.0 && prev > 0.0 {
+        curr.ln() - prev.ln()
+    } else {
+        f64::NAN
+    }
+}
+
+/// Natural log of one value, NaN unless the input is positive
+///
+/// Shared by every ln path (column kernel, fused op, expression evaluator
+/// and constant folding) so ln(0) is NaN everywhere instead of -inf in some.
+#[inline(always)]
+pub(crate) fn ln_value(x: f64) -> f64 {
+    if x > 0.0 {
+        x.ln()
+    } else {
+        f64::NAN
+    }
+}
+
+/// dlog fast path: No nulls (non-positive inputs yield NaN, see `dlog_value`)
 pub fn dlog_no_nulls(out: &mut [f64], x: &[f64], lag: usize) {
     let n = x.len();
     assert_eq!(out.len(), n);
@@ -27,11 +54,9 @@ pub fn dlog_no_nulls(out: &mut [f64], x: &[f64], lag: usize) {
         let xp = x.as_ptr();
         let op = out.as_mut_ptr();
 
-        // 🔥 CLEAN LOOP: No branches!
+        // 🔥 CLEAN LOOP: No validity checks
         for i in lag..n {
-            let curr = *xp.add(i);
-            let prev = *xp.add(i - lag);
-            *op.add(i) = curr.ln() - prev.ln();
+            *op.add(i) = dlog_value(*xp.add(i), *xp.add(i - lag));
         }
     }
 }
@@ -74,9 +99,7 @@ pub fn dlog_masked(
 
             if v_curr && v_prev {
                 // Both valid: compute result
-                let curr = *xp.add(i);
-                let prev = *xp.add(i - lag);
-                *op.add(i) = curr.ln() - prev.ln();
+                *op.add(i) = dlog_value(*xp.add(i), *xp.add(i - lag));
                 out_valid.set(i, true);
             } else {
                 // Invalid: just set bit, don't write data (DON'T CARE)
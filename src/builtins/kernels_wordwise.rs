@@ -6,9 +6,52 @@
 //! - Otherwise, fall back to per-bit checks
 //!
 //! This reduces masked overhead significantly when nulls are clustered.
+//!
+//! Lagged kernels test the current word together with the validity of
+//! x[i-lag], which for an unaligned lag (e.g. lag=1) straddles two words:
+//! `lagged_word` shifts the pair into one word so the fast path still fires.
 
+use crate::builtins::kernels_masked::dlog_value;
 use crate::table::Bitmap;
 
+#[cfg(test)]
+thread_local! {
+    /// Words that took the all-valid fast path (test instrumentation)
+    static FAST_WORDS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+#[inline(always)]
+fn count_fast_word() {
+    #[cfg(test)]
+    FAST_WORDS.with(|c| c.set(c.get() + 1));
+}
+
+/// Validity of x[i-lag] for the 64 positions of the word starting at `start`
+///
+/// Bit k is `valid.get(start + k - lag)`. Requires `start >= lag`.
+#[inline(always)]
+fn lagged_word(valid: &Bitmap, start: usize, lag: usize) -> u64 {
+    let src = start - lag;
+    let (w, offset) = (src / 64, src % 64);
+    if offset == 0 {
+        valid.word(w)
+    } else {
+        // src < start, so word w + 1 exists (it is at most the current word)
+        (valid.word(w) >> offset) | (valid.word(w + 1) << (64 - offset))
+    }
+}
+
+/// Bits in use for a word covering [start, end) (end - start <= 64)
+#[inline(always)]
+fn span_mask(start: usize, end: usize) -> u64 {
+    let len = end - start;
+    if len == 64 {
+        !0u64
+    } else {
+        (1u64 << len) - 1
+    }
+}
+
 /// Word-wise dlog: Process 64 elements at once based on validity word
 pub fn dlog_wordwise(
     out: &mut [f64],
@@ -52,7 +95,7 @@ pub fn dlog_wordwise(
                     let v_curr = x_valid.get(i);
                     let v_prev = x_valid.get(i - lag);
                     if v_curr && v_prev {
-                        *op.add(i) = (*xp.add(i)).ln() - (*xp.add(i - lag)).ln();
+                        *op.add(i) = dlog_value(*xp.add(i), *xp.add(i - lag));
                         out_valid.set(i, true);
                     } else {
                         out_valid.set(i, false);
@@ -61,28 +104,19 @@ pub fn dlog_wordwise(
                 continue;
             }
 
-            // Check if all elements in current AND lagged word are valid
-            let curr_word = x_valid.word(word_idx);
-            let lag_word_idx = (start_idx - lag) / 64;
-            let lag_offset = (start_idx - lag) % 64;
-
-            // Simplified: Check if spans are valid
-            let all_valid = if lag_offset == 0 {
-                // Aligned: just check both words
-                curr_word == !0u64 && x_valid.word(lag_word_idx) == !0u64
-            } else {
-                // Unaligned: conservative fallback
-                false
-            };
+            // Output is valid where both x[i] and x[i-lag] are
+            let mask = span_mask(start_idx, end_idx);
+            let both = x_valid.word(word_idx) & lagged_word(x_valid, start_idx, lag) & mask;
 
-            if all_valid {
-                // 🔥 FAST: All 64 elements valid, tight loop, no checks
+            if both == mask {
+                // 🔥 FAST: All elements valid, tight loop, no checks
+                count_fast_word();
                 for i in start_idx..end_idx {
-                    *op.add(i) = (*xp.add(i)).ln() - (*xp.add(i - lag)).ln();
+                    *op.add(i) = dlog_value(*xp.add(i), *xp.add(i - lag));
                 }
-                out_valid.bits_mut()[word_idx] = !0u64;
-            } else if curr_word == 0 {
-                // 🔥 SKIP: All 64 elements null, skip compute
+                out_valid.bits_mut()[word_idx] = mask;
+            } else if both == 0 {
+                // 🔥 SKIP: No valid pair, skip compute
                 out_valid.bits_mut()[word_idx] = 0;
             } else {
                 // Mixed: Per-bit fallback
@@ -90,7 +124,7 @@ pub fn dlog_wordwise(
                     let v_curr = x_valid.get(i);
                     let v_prev = x_valid.get(i - lag);
                     if v_curr && v_prev {
-                        *op.add(i) = (*xp.add(i)).ln() - (*xp.add(i - lag)).ln();
+                        *op.add(i) = dlog_value(*xp.add(i), *xp.add(i - lag));
                         out_valid.set(i, true);
                     } else {
                         out_valid.set(i, false);
@@ -142,9 +176,7 @@ pub fn dlog_scale_add_wordwise(
                     let v_curr = x_valid.get(i);
                     let v_prev = x_valid.get(i - lag);
                     if v_curr && v_prev {
-                        let curr_ln = (*xp.add(i)).ln();
-                        let prev_ln = (*xp.add(i - lag)).ln();
-                        *op.add(i) = a * (curr_ln - prev_ln) + b;
+                        *op.add(i) = a * dlog_value(*xp.add(i), *xp.add(i - lag)) + b;
                         out_valid.set(i, true);
                     } else {
                         out_valid.set(i, false);
@@ -153,25 +185,17 @@ pub fn dlog_scale_add_wordwise(
                 continue;
             }
 
-            let curr_word = x_valid.word(word_idx);
-            let lag_word_idx = (start_idx - lag) / 64;
-            let lag_offset = (start_idx - lag) % 64;
+            let mask = span_mask(start_idx, end_idx);
+            let both = x_valid.word(word_idx) & lagged_word(x_valid, start_idx, lag) & mask;
 
-            let all_valid = if lag_offset == 0 {
-                curr_word == !0u64 && x_valid.word(lag_word_idx) == !0u64
-            } else {
-                false
-            };
-
-            if all_valid {
+            if both == mask {
                 // 🔥 TIGHT LOOP: No validity checks for 64 elements
+                count_fast_word();
                 for i in start_idx..end_idx {
-                    let curr_ln = (*xp.add(i)).ln();
-                    let prev_ln = (*xp.add(i - lag)).ln();
-                    *op.add(i) = a * (curr_ln - prev_ln) + b;
+                    *op.add(i) = a * dlog_value(*xp.add(i), *xp.add(i - lag)) + b;
                 }
-                out_valid.bits_mut()[word_idx] = !0u64;
-            } else if curr_word == 0 {
+                out_valid.bits_mut()[word_idx] = mask;
+            } else if both == 0 {
                 // Skip compute
                 out_valid.bits_mut()[word_idx] = 0;
             } else {
@@ -180,9 +204,7 @@ pub fn dlog_scale_add_wordwise(
                     let v_curr = x_valid.get(i);
                     let v_prev = x_valid.get(i - lag);
                     if v_curr && v_prev {
-                        let curr_ln = (*xp.add(i)).ln();
-                        let prev_ln = (*xp.add(i - lag)).ln();
-                        *op.add(i) = a * (curr_ln - prev_ln) + b;
+                        *op.add(i) = a * dlog_value(*xp.add(i), *xp.add(i - lag)) + b;
                         out_valid.set(i, true);
                     } else {
                         out_valid.set(i, false);
@@ -215,6 +237,63 @@ mod tests {
         assert!(out_valid.get(127));
     }
 
+    fn fast_words() -> usize {
+        FAST_WORDS.with(|c| c.get())
+    }
+
+    #[test]
+    fn test_dlog_wordwise_lag1_takes_fast_path() {
+        let x: Vec<f64> = (0..128).map(|i| 100.0 + i as f64).collect();
+        let x_valid = Bitmap::new_all_valid(128);
+        let mut out = vec![0.0; 128];
+        let mut out_valid = Bitmap::new_all_null(128);
+
+        let before = fast_words();
+        dlog_wordwise(&mut out, &mut out_valid, &x, &x_valid, 1);
+
+        // Word 0 overlaps the prefix; word 1 reads bits 63..127 (unaligned)
+        assert_eq!(fast_words() - before, 1);
+        for i in 1..128 {
+            assert!(out_valid.get(i));
+            assert_eq!(out[i], x[i].ln() - x[i - 1].ln());
+        }
+    }
+
+    #[test]
+    fn test_dlog_wordwise_unaligned_matches_masked() {
+        use crate::builtins::kernels_masked::dlog_masked;
+
+        let n = 300;
+        let x: Vec<f64> = (0..n).map(|i| 50.0 + (i % 13) as f64).collect();
+        let mut x_valid = Bitmap::new_all_valid(n);
+        for i in [70, 130, 131, 250] {
+            x_valid.set(i, false);
+        }
+
+        for lag in [1, 3, 64, 65, 130] {
+            let mut out = vec![0.0; n];
+            let mut out_valid = Bitmap::new_all_null(n);
+            dlog_wordwise(&mut out, &mut out_valid, &x, &x_valid, lag);
+
+            let mut expected = vec![0.0; n];
+            let mut expected_valid = Bitmap::new_all_null(n);
+            dlog_masked(&mut expected, &mut expected_valid, &x, &x_valid, lag);
+
+            for i in 0..n {
+                assert_eq!(
+                    out_valid.get(i),
+                    expected_valid.get(i),
+                    "lag {} row {}",
+                    lag,
+                    i
+                );
+                if expected_valid.get(i) {
+                    assert_eq!(out[i], expected[i], "lag {} row {}", lag, i);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_dlog_wordwise_all_null() {
         let x = vec![100.0; 128];
@@ -1,18 +1,37 @@
 //! Built-in operations
 
+pub mod compare;
+pub mod ewm;
 pub mod fast_kernels;
 pub mod kernels_fused;
 pub mod kernels_masked;
 pub mod kernels_wordwise;
 pub mod math;
-// pub mod nulls;  // Obsolete: kdb-style uses embedded sentinels, not bitmap conversion
 pub mod ops;
 pub mod ori_ops;
+pub mod rank;
+pub mod rolling_apply;
 pub mod rolling_moments;
+pub mod rolling_quantile;
 pub mod scratch;
+pub mod stats;
+#[cfg(test)]
+pub(crate) mod test_util;
 
 // Re-exports from math are unused at module level
-// pub use nulls::*;  // Removed: bitmap-based null handling obsolete
-pub use ops::{abs_column, dlog_column, ln_column, mean, mean0, sum, sum0};
-pub use rolling_moments::{rolling_moments_past_only_f64, MomentsMask};
+pub use ewm::{alpha_from_span, ewma_column, ewma_f64};
+pub use ops::{
+    abs_column, clip_column, diff_column, dlog_base_column, dlog_column, dlog_signed_column,
+    ffill_column, fillna_const, interpolate_linear_column, ln_column, mean, mean0,
+    pct_change_column, shift_column, sum, sum0, sum_with_count, try_abs_column, try_dlog_column,
+    try_interpolate_linear_column, try_ln_column, winsorize_column, LogBase,
+};
+pub use rank::{rank_column, RankMethod};
+pub use rolling_apply::rolling_apply_past_only;
+pub use rolling_moments::{
+    rolling_minmax_past_only_f64, rolling_moments_past_only_f64, rolling_moments_table,
+    rolling_sum_past_only_f64, rolling_zscore_past_only_f64, MomentsMask,
+};
+pub use rolling_quantile::rolling_quantile_past_only_f64;
 pub use scratch::Scratch;
+pub use stats::{corr, cov, rolling_beta_past_only_f64, rolling_corr_past_only_f64};
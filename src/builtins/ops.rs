@@ -3,45 +3,378 @@
 //! All operations work directly on data vectors.
 //! NaN propagation handled by IEEE 754 automatically.
 
-use crate::builtins::kernels_masked::{dlog_no_nulls, unary_no_nulls};
-use crate::table::Column;
+use crate::builtins::kernels_masked::{dlog_no_nulls, dlog_value, ln_value, unary_no_nulls};
+use crate::table::{
+    Column, ColumnTypeError, NULL_DATE, NULL_I64, NULL_SYM, NULL_TIMESTAMP, NULL_TS,
+};
+use std::sync::Arc;
 
 /// dlog: Log returns (kdb-style)
 ///
-/// NaN values propagate automatically via IEEE 754.
+/// out[i] = ln(x[i]) - ln(x[i-lag]). NaN for the first `lag` positions and
+/// wherever either input is NaN or non-positive (zero included).
+///
+/// # Panics
+/// Panics if `x` is not an F64 column (see `try_dlog_column`).
 pub fn dlog_column(x: &Column, lag: usize) -> Column {
-    let Column::F64(data) = x else {
-        panic!("dlog_column: expected F64 column");
+    try_dlog_column(x, lag).expect("dlog_column: expected F64 column")
+}
+
+/// dlog: Log returns, returning an error on a non-F64 column
+///
+/// An F32 column yields an F32 result, computed in f64.
+pub fn try_dlog_column(x: &Column, lag: usize) -> Result<Column, ColumnTypeError> {
+    let data = match x {
+        Column::F64(data) => data,
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => m.as_slice(),
+        Column::F32(data) => return Ok(Column::F32(dlog_f32(data, lag))),
+        _ => return Err(ColumnTypeError::new("F64", x.type_name())),
     };
 
     let n = data.len();
     let mut out_data = vec![0.0; n];
     dlog_no_nulls(&mut out_data, data, lag);
-    Column::F64(out_data)
+    Ok(Column::F64(out_data))
+}
+
+/// dlog kernel for F32 storage: logs taken in f64, rounded once on output
+fn dlog_f32(x: &[f32], lag: usize) -> Vec<f32> {
+    let n = x.len();
+    let mut out = vec![f32::NAN; n];
+    if lag == 0 {
+        return out;
+    }
+
+    for i in lag..n {
+        out[i] = dlog_value(x[i] as f64, x[i - lag] as f64) as f32;
+    }
+    out
+}
+
+/// dlog with a signed period: backward (period > 0) or forward (period < 0)
+///
+/// - period = k > 0: out[i] = ln(x[i]) - ln(x[i-k]), same as `dlog_column(x, k)`
+/// - period = -k < 0: out[i] = ln(x[i+k]) - ln(x[i]), last k positions NaN
+/// - period = 0: all NaN
+///
+/// **Not Ft-measurable for negative periods**: out[i] uses x[i+k], which is
+/// unknown at time i. Use forward returns only as research labels, never as
+/// signal inputs (compare the past-only kernels in `rolling_moments`).
+///
+/// # Panics
+/// Panics if `x` is not an F64 column.
+pub fn dlog_signed_column(x: &Column, period: i64) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("dlog_signed_column: expected F64 column");
+    };
+
+    if period >= 0 {
+        return dlog_column(x, period as usize);
+    }
+
+    let n = data.len();
+    let k = period.unsigned_abs() as usize;
+    let mut out = vec![f64::NAN; n];
+    for i in 0..n.saturating_sub(k) {
+        out[i] = dlog_value(data[i + k], data[i]);
+    }
+    Column::F64(out)
+}
+
+/// Logarithm base for `dlog_base_column`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LogBase {
+    /// ln (the `dlog_column` default)
+    #[default]
+    Natural,
+    /// log2
+    Two,
+    /// log10
+    Ten,
+}
+
+impl LogBase {
+    /// 1/ln(base): converts a natural log to this base
+    pub fn inv_ln(self) -> f64 {
+        match self {
+            LogBase::Natural => 1.0,
+            LogBase::Two => std::f64::consts::LOG2_E,
+            LogBase::Ten => std::f64::consts::LOG10_E,
+        }
+    }
+}
+
+/// dlog in an arbitrary log base
+///
+/// out[i] = log_base(x[i]) - log_base(x[i-lag]), computed as the natural
+/// `dlog_column` scaled by `base.inv_ln()`, so every base shares one kernel
+/// and the same NaN rules.
+///
+/// # Panics
+/// Panics if `x` is not an F64 column (see `try_dlog_column`).
+pub fn dlog_base_column(x: &Column, lag: usize, base: LogBase) -> Column {
+    let mut out = dlog_column(x, lag);
+    if base != LogBase::Natural {
+        let k = base.inv_ln();
+        match &mut out {
+            Column::F64(data) => data.iter_mut().for_each(|v| *v *= k),
+            Column::F32(data) => data.iter_mut().for_each(|v| *v = (*v as f64 * k) as f32),
+            _ => unreachable!("dlog_column returns F64 or F32"),
+        }
+    }
+    out
 }
 
 /// ln: Natural logarithm (kdb-style)
+///
+/// Non-positive inputs (zero included) yield NaN, see `ln_value`.
+///
+/// # Panics
+/// Panics if `x` is not an F64 column (see `try_ln_column`).
 pub fn ln_column(x: &Column) -> Column {
-    let Column::F64(data) = x else {
-        panic!("ln_column: expected F64 column");
+    try_ln_column(x).expect("ln_column: expected F64 column")
+}
+
+/// ln: Natural logarithm, returning an error on a non-F64 column
+pub fn try_ln_column(x: &Column) -> Result<Column, ColumnTypeError> {
+    let Ok(data) = x.as_f64_slice() else {
+        return Err(ColumnTypeError::new("F64", x.type_name()));
     };
 
     let n = data.len();
     let mut out_data = vec![0.0; n];
-    unary_no_nulls(&mut out_data, data, |x| x.ln());
-    Column::F64(out_data)
+    unary_no_nulls(&mut out_data, data, ln_value);
+    Ok(Column::F64(out_data))
 }
 
 /// abs: Absolute value (kdb-style)
+///
+/// # Panics
+/// Panics if `x` is not an F64 column (see `try_abs_column`).
 pub fn abs_column(x: &Column) -> Column {
-    let Column::F64(data) = x else {
-        panic!("abs_column: expected F64 column");
+    try_abs_column(x).expect("abs_column: expected F64 column")
+}
+
+/// abs: Absolute value, returning an error on a non-F64 column
+pub fn try_abs_column(x: &Column) -> Result<Column, ColumnTypeError> {
+    let Ok(data) = x.as_f64_slice() else {
+        return Err(ColumnTypeError::new("F64", x.type_name()));
     };
 
     let n = data.len();
     let mut out_data = vec![0.0; n];
     unary_no_nulls(&mut out_data, data, |x| x.abs());
-    Column::F64(out_data)
+    Ok(Column::F64(out_data))
+}
+
+/// diff: First difference x[i] - x[i-lag] (kdb-style)
+///
+/// First `lag` positions are NaN (all NaN if lag >= len).
+/// NaN values propagate automatically via IEEE 754.
+pub fn diff_column(x: &Column, lag: usize) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("diff_column: expected F64 column");
+    };
+
+    Column::F64(diff_slice(data, lag))
+}
+
+/// Diff kernel: out[i] = x[i] - x[i-lag], NaN prefix
+pub(crate) fn diff_slice(x: &[f64], lag: usize) -> Vec<f64> {
+    let n = x.len();
+    let k = lag.min(n);
+    let mut out = vec![f64::NAN; n];
+
+    for i in k..n {
+        out[i] = x[i] - x[i - lag];
+    }
+    out
+}
+
+/// pct_change: Simple return x[i] / x[i-lag] - 1
+///
+/// First `lag` positions are NaN (all NaN if lag >= len), as are positions
+/// where either operand is NaN or the denominator x[i-lag] is zero. Use
+/// `dlog_column` for log returns.
+///
+/// # Panics
+/// Panics if `x` is not an F64 column.
+pub fn pct_change_column(x: &Column, lag: usize) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("pct_change_column: expected F64 column");
+    };
+
+    Column::F64(pct_change_slice(data, lag))
+}
+
+/// Pct-change kernel: out[i] = x[i] / x[i-lag] - 1, NaN prefix and NaN on zero base
+pub(crate) fn pct_change_slice(x: &[f64], lag: usize) -> Vec<f64> {
+    let n = x.len();
+    let k = lag.min(n);
+    let mut out = vec![f64::NAN; n];
+
+    for i in k..n {
+        let base = x[i - lag];
+        if base != 0.0 {
+            out[i] = x[i] / base - 1.0;
+        }
+    }
+    out
+}
+
+/// shift: Lag (lag > 0) or lead (lag < 0) a column, keeping its shape
+///
+/// out[i] = x[i - lag]. Vacated positions get the type-specific null
+/// (NaN, NULL_DATE, NULL_TIMESTAMP, NULL_TS, NULL_I64, NULL_SYM; `false` for Bool).
+pub fn shift_column(x: &Column, lag: i64) -> Column {
+    match x {
+        Column::F64(data) => Column::F64(shift_slice(data, lag, f64::NAN)),
+        Column::Date(data) => Column::Date(shift_slice(data, lag, NULL_DATE)),
+        Column::Timestamp(data) => Column::Timestamp(shift_slice(data, lag, NULL_TIMESTAMP)),
+        Column::Ts(data) => Column::Ts(shift_slice(data, lag, NULL_TS)),
+        Column::Bool(data) => Column::Bool(shift_slice(data, lag, false)),
+        Column::I64(data) => Column::I64(shift_slice(data, lag, NULL_I64)),
+        Column::F32(data) => Column::F32(shift_slice(data, lag, f32::NAN)),
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => Column::F64(shift_slice(m.as_slice(), lag, f64::NAN)),
+        Column::Sym { codes, dict } => Column::Sym {
+            codes: shift_slice(codes, lag, NULL_SYM),
+            dict: Arc::clone(dict),
+        },
+    }
+}
+
+/// Shift kernel: out[i] = x[i - lag], `fill` where i - lag is out of range
+pub(crate) fn shift_slice<T: Copy>(x: &[T], lag: i64, fill: T) -> Vec<T> {
+    let n = x.len();
+    let k = (lag.unsigned_abs() as usize).min(n);
+    let mut out = vec![fill; n];
+
+    if lag >= 0 {
+        out[k..].copy_from_slice(&x[..n - k]);
+    } else {
+        out[..n - k].copy_from_slice(&x[k..]);
+    }
+    out
+}
+
+/// ffill: Replace each null with the most recent non-null predecessor
+///
+/// Leading nulls (no predecessor) stay null. Temporal, I64 and Sym columns
+/// fill their sentinels (NULL_DATE, NULL_TIMESTAMP, NULL_TS, NULL_I64,
+/// NULL_SYM); Bool has no null and is returned unchanged.
+pub fn ffill_column(x: &Column) -> Column {
+    match x {
+        Column::F64(data) => Column::F64(ffill_slice(data, |v| v.is_nan())),
+        Column::Date(data) => Column::Date(ffill_slice(data, |&v| v == NULL_DATE)),
+        Column::Timestamp(data) => Column::Timestamp(ffill_slice(data, |&v| v == NULL_TIMESTAMP)),
+        Column::Ts(data) => Column::Ts(ffill_slice(data, |&v| v == NULL_TS)),
+        Column::I64(data) => Column::I64(ffill_slice(data, |&v| v == NULL_I64)),
+        Column::F32(data) => Column::F32(ffill_slice(data, |v| v.is_nan())),
+        Column::Bool(_) => x.clone(),
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => Column::F64(ffill_slice(m.as_slice(), |v| v.is_nan())),
+        Column::Sym { codes, dict } => Column::Sym {
+            codes: ffill_slice(codes, |&c| c == NULL_SYM),
+            dict: Arc::clone(dict),
+        },
+    }
+}
+
+/// Forward-fill kernel: carry the last non-null value over null positions
+fn ffill_slice<T: Copy>(x: &[T], is_null: impl Fn(&T) -> bool) -> Vec<T> {
+    let mut out = x.to_vec();
+    let mut last: Option<T> = None;
+
+    for v in out.iter_mut() {
+        if is_null(v) {
+            if let Some(prev) = last {
+                *v = prev;
+            }
+        } else {
+            last = Some(*v);
+        }
+    }
+    out
+}
+
+/// Linear interpolation of interior NaN runs
+///
+/// Each run of NaNs with a valid value on both sides is filled on the
+/// straight line between those two values, by position (rows are taken as
+/// evenly spaced). Leading and trailing NaNs have only one neighbour and
+/// stay NaN.
+///
+/// # Panics
+/// Panics if `x` is not an F64 column (see `try_interpolate_linear_column`).
+pub fn interpolate_linear_column(x: &Column) -> Column {
+    try_interpolate_linear_column(x).expect("interpolate_linear_column: expected F64 column")
+}
+
+/// Linear interpolation, returning an error on a non-F64 column
+pub fn try_interpolate_linear_column(x: &Column) -> Result<Column, ColumnTypeError> {
+    let data = x
+        .as_f64_slice()
+        .map_err(|_| ColumnTypeError::new("F64", x.type_name()))?;
+
+    let mut out = data.to_vec();
+    let mut last: Option<usize> = None;
+    for i in 0..out.len() {
+        if out[i].is_nan() {
+            continue;
+        }
+        if let Some(j) = last.filter(|&j| i - j > 1) {
+            let (a, b) = (out[j], out[i]);
+            let span = (i - j) as f64;
+            for (step, v) in out[j + 1..i].iter_mut().enumerate() {
+                *v = a + (b - a) * ((step + 1) as f64 / span);
+            }
+        }
+        last = Some(i);
+    }
+    Ok(Column::F64(out))
+}
+
+/// fillna: Replace every null with `value`
+///
+/// For Date/Timestamp/Ts/I64 columns `value` is truncated to the column's
+/// integer type (days for Date, nanoseconds for Timestamp/Ts). Bool has no
+/// null and Sym has no numeric fill; both are returned unchanged.
+pub fn fillna_const(x: &Column, value: f64) -> Column {
+    match x {
+        Column::F64(data) => Column::F64(
+            data.iter()
+                .map(|&v| if v.is_nan() { value } else { v })
+                .collect(),
+        ),
+        Column::Date(data) => Column::Date(fill_sentinel(data, NULL_DATE, value as i32)),
+        Column::Timestamp(data) => {
+            Column::Timestamp(fill_sentinel(data, NULL_TIMESTAMP, value as i64))
+        }
+        Column::Ts(data) => Column::Ts(fill_sentinel(data, NULL_TS, value as i64)),
+        Column::I64(data) => Column::I64(fill_sentinel(data, NULL_I64, value as i64)),
+        Column::F32(data) => Column::F32(
+            data.iter()
+                .map(|&v| if v.is_nan() { value as f32 } else { v })
+                .collect(),
+        ),
+        Column::Bool(_) | Column::Sym { .. } => x.clone(),
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => Column::F64(
+            m.as_slice()
+                .iter()
+                .map(|&v| if v.is_nan() { value } else { v })
+                .collect(),
+        ),
+    }
+}
+
+/// Replace `sentinel` with `fill`
+fn fill_sentinel<T: Copy + PartialEq>(x: &[T], sentinel: T, fill: T) -> Vec<T> {
+    x.iter()
+        .map(|&v| if v == sentinel { fill } else { v })
+        .collect()
 }
 
 // ============================================================================
@@ -51,25 +384,135 @@ pub fn abs_column(x: &Column) -> Column {
 /// sum: Sum column (propagates NaN) — fast path
 ///
 /// If any value is NaN, result is NaN. Uses tight loop with no branching.
+/// Always sums in the 4-lane order of `sum_f64_lanes`; feature `simd` only
+/// swaps in AVX2/NEON kernels (detected at runtime) with that same order,
+/// so the result is bitwise identical with or without the feature. F32
+/// columns accumulate in f64.
 #[inline]
 pub fn sum(x: &Column) -> f64 {
-    let Column::F64(data) = x else {
-        panic!("sum: expected F64 column");
+    let data = match x {
+        Column::F64(data) => data,
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => m.as_slice(),
+        Column::F32(data) => return sum_f32(data),
+        _ => panic!("sum: expected F64 column"),
     };
 
+    #[cfg(feature = "simd")]
+    {
+        simd::sum_f64(data)
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        sum_f64_lanes(data) // NaN propagates automatically
+    }
+}
+
+/// NaN-propagating sum of f32 storage with an f64 accumulator
+fn sum_f32(data: &[f32]) -> f64 {
     let mut result = 0.0;
     for &val in data {
-        result += val; // NaN propagates automatically
+        result += val as f64;
     }
     result
 }
 
+/// Portable 4-lane sum: the reference order for `sum` in every build
+///
+/// Accumulates lane k over elements 4c+k, combines lanes as
+/// (l0 + l1) + (l2 + l3), then adds the tail in order. The SIMD kernels
+/// follow the same order, so all paths produce bitwise-identical results.
+pub(crate) fn sum_f64_lanes(data: &[f64]) -> f64 {
+    let mut acc = [0.0f64; 4];
+    let chunks = data.chunks_exact(4);
+    let tail = chunks.remainder();
+    for c in chunks {
+        acc[0] += c[0];
+        acc[1] += c[1];
+        acc[2] += c[2];
+        acc[3] += c[3];
+    }
+    combine_lanes(acc, tail)
+}
+
+#[inline(always)]
+fn combine_lanes(acc: [f64; 4], tail: &[f64]) -> f64 {
+    let mut result = (acc[0] + acc[1]) + (acc[2] + acc[3]);
+    for &val in tail {
+        result += val;
+    }
+    result
+}
+
+/// 4-lane NaN-propagating SIMD sum kernels (feature `simd`)
+#[cfg(feature = "simd")]
+pub(crate) mod simd {
+    use super::{combine_lanes as combine, sum_f64_lanes};
+
+    /// Sum with the best available 4-lane kernel
+    pub(crate) fn sum_f64(data: &[f64]) -> f64 {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                // SAFETY: AVX2 availability checked at runtime above.
+                return unsafe { sum_f64_avx2(data) };
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                // SAFETY: NEON availability checked at runtime above.
+                return unsafe { sum_f64_neon(data) };
+            }
+        }
+
+        sum_f64_lanes(data)
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn sum_f64_avx2(data: &[f64]) -> f64 {
+        use std::arch::x86_64::*;
+
+        let chunks = data.chunks_exact(4);
+        let tail = chunks.remainder();
+        let mut vacc = _mm256_setzero_pd();
+        for c in chunks {
+            vacc = _mm256_add_pd(vacc, _mm256_loadu_pd(c.as_ptr()));
+        }
+        let mut acc = [0.0f64; 4];
+        _mm256_storeu_pd(acc.as_mut_ptr(), vacc);
+        combine(acc, tail)
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[target_feature(enable = "neon")]
+    pub(crate) unsafe fn sum_f64_neon(data: &[f64]) -> f64 {
+        use std::arch::aarch64::*;
+
+        let chunks = data.chunks_exact(4);
+        let tail = chunks.remainder();
+        let mut lo = vdupq_n_f64(0.0);
+        let mut hi = vdupq_n_f64(0.0);
+        for c in chunks {
+            lo = vaddq_f64(lo, vld1q_f64(c.as_ptr()));
+            hi = vaddq_f64(hi, vld1q_f64(c.as_ptr().add(2)));
+        }
+        let mut acc = [0.0f64; 4];
+        vst1q_f64(acc.as_mut_ptr(), lo);
+        vst1q_f64(acc.as_mut_ptr().add(2), hi);
+        combine(acc, tail)
+    }
+}
+
 /// sum0: Sum column (ignores NaN) — explicit slower path
 ///
 /// Skips NaN values. Only use when you explicitly want to ignore nulls.
 #[inline]
 pub fn sum0(x: &Column) -> f64 {
-    let Column::F64(data) = x else {
+    let Ok(data) = x.as_f64_slice() else {
         panic!("sum0: expected F64 column");
     };
 
@@ -82,21 +525,47 @@ pub fn sum0(x: &Column) -> f64 {
     result
 }
 
+/// sum_with_count: NaN-skipping sum and the number of non-NaN values
+///
+/// One pass; `(sum, count)` lets callers combine partial means across
+/// tables. All-NaN or empty yields `(0.0, 0)`.
+#[inline]
+pub fn sum_with_count(x: &Column) -> (f64, usize) {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("sum_with_count: expected F64 column");
+    };
+
+    sum_count_slice(data)
+}
+
+/// NaN-skipping (sum, count) of a slice
+pub(crate) fn sum_count_slice(data: &[f64]) -> (f64, usize) {
+    let mut s = 0.0;
+    let mut count = 0;
+    for &val in data {
+        if !val.is_nan() {
+            s += val;
+            count += 1;
+        }
+    }
+    (s, count)
+}
+
 /// mean: Mean (propagates NaN) — fast path
 ///
 /// If any value is NaN, result is NaN.
 #[inline]
 pub fn mean(x: &Column) -> f64 {
-    let Column::F64(data) = x else {
+    if x.as_f64_slice().is_err() && !matches!(x, Column::F32(_)) {
         panic!("mean: expected F64 column");
-    };
+    }
 
-    if data.is_empty() {
+    if x.is_empty() {
         return f64::NAN;
     }
 
     let s = sum(x);
-    s / (data.len() as f64)
+    s / (x.len() as f64)
 }
 
 /// mean0: Mean (ignores NaN) — explicit slower path
@@ -104,16 +573,178 @@ pub fn mean(x: &Column) -> f64 {
 /// Skips NaN values. Returns NaN if all values are NaN.
 #[inline]
 pub fn mean0(x: &Column) -> f64 {
-    let Column::F64(data) = x else {
+    let Ok(data) = x.as_f64_slice() else {
         panic!("mean0: expected F64 column");
     };
 
+    let (s, count) = sum_count_slice(data);
+    if count == 0 {
+        f64::NAN
+    } else {
+        s / (count as f64)
+    }
+}
+
+/// min: Minimum (propagates NaN) — fast path
+///
+/// If any value is NaN, result is NaN. Empty column yields NaN.
+#[inline]
+pub fn min(x: &Column) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("min: expected F64 column");
+    };
+
     if data.is_empty() {
         return f64::NAN;
     }
 
+    let mut result = f64::INFINITY;
+    for &val in data {
+        if val.is_nan() {
+            return f64::NAN;
+        }
+        if val < result {
+            result = val;
+        }
+    }
+    result
+}
+
+/// max: Maximum (propagates NaN) — fast path
+///
+/// If any value is NaN, result is NaN. Empty column yields NaN.
+#[inline]
+pub fn max(x: &Column) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("max: expected F64 column");
+    };
+
+    if data.is_empty() {
+        return f64::NAN;
+    }
+
+    let mut result = f64::NEG_INFINITY;
+    for &val in data {
+        if val.is_nan() {
+            return f64::NAN;
+        }
+        if val > result {
+            result = val;
+        }
+    }
+    result
+}
+
+/// min0: Minimum (ignores NaN) — explicit slower path
+///
+/// Returns NaN if empty or all values are NaN.
+#[inline]
+pub fn min0(x: &Column) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("min0: expected F64 column");
+    };
+
+    argmin(x).map(|i| data[i]).unwrap_or(f64::NAN)
+}
+
+/// max0: Maximum (ignores NaN) — explicit slower path
+///
+/// Returns NaN if empty or all values are NaN.
+#[inline]
+pub fn max0(x: &Column) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("max0: expected F64 column");
+    };
+
+    argmax(x).map(|i| data[i]).unwrap_or(f64::NAN)
+}
+
+/// argmin: Index of the first minimum (ignores NaN)
+///
+/// Returns None for empty or all-NaN columns. Ties resolve to the first index.
+pub fn argmin(x: &Column) -> Option<usize> {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("argmin: expected F64 column");
+    };
+
+    let mut best: Option<usize> = None;
+    for (i, &val) in data.iter().enumerate() {
+        if !val.is_nan() && best.is_none_or(|b| val < data[b]) {
+            best = Some(i);
+        }
+    }
+    best
+}
+
+/// argmax: Index of the first maximum (ignores NaN)
+///
+/// Returns None for empty or all-NaN columns. Ties resolve to the first index.
+pub fn argmax(x: &Column) -> Option<usize> {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("argmax: expected F64 column");
+    };
+
+    let mut best: Option<usize> = None;
+    for (i, &val) in data.iter().enumerate() {
+        if !val.is_nan() && best.is_none_or(|b| val > data[b]) {
+            best = Some(i);
+        }
+    }
+    best
+}
+
+/// var: Sample variance, ddof=1 (propagates NaN) — fast path
+///
+/// If any value is NaN, result is NaN. Fewer than 2 values yields NaN.
+/// Two-pass (mean, then squared deviations) to avoid catastrophic
+/// cancellation on large-magnitude levels.
+#[inline]
+pub fn var(x: &Column) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("var: expected F64 column");
+    };
+
+    if data.len() < 2 {
+        return f64::NAN;
+    }
+
+    let m = mean(x); // NaN propagates
+    let mut ss = 0.0;
+    for &val in data {
+        let d = val - m;
+        ss += d * d;
+    }
+    ss / ((data.len() - 1) as f64)
+}
+
+/// var0: Sample variance, ddof=1 (ignores NaN) — explicit slower path
+///
+/// Returns NaN if fewer than 2 non-NaN values.
+#[inline]
+pub fn var0(x: &Column) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("var0: expected F64 column");
+    };
+
+    var0_slice(data)
+}
+
+/// std: Sample standard deviation, ddof=1 (propagates NaN)
+#[inline]
+pub fn std(x: &Column) -> f64 {
+    var(x).sqrt()
+}
+
+/// std0: Sample standard deviation, ddof=1 (ignores NaN)
+#[inline]
+pub fn std0(x: &Column) -> f64 {
+    var0(x).sqrt()
+}
+
+/// Two-pass NaN-skipping sample variance of a slice (ddof=1)
+pub(crate) fn var0_slice(data: &[f64]) -> f64 {
     let mut s = 0.0;
-    let mut count = 0;
+    let mut count = 0usize;
     for &val in data {
         if !val.is_nan() {
             s += val;
@@ -121,11 +752,122 @@ pub fn mean0(x: &Column) -> f64 {
         }
     }
 
-    if count == 0 {
-        f64::NAN
-    } else {
-        s / (count as f64)
+    if count < 2 {
+        return f64::NAN;
+    }
+
+    let m = s / (count as f64);
+    let mut ss = 0.0;
+    for &val in data {
+        if !val.is_nan() {
+            let d = val - m;
+            ss += d * d;
+        }
+    }
+    ss / ((count - 1) as f64)
+}
+
+/// zscore: Whole-column standardization `(x - mean0) / std0`
+///
+/// Mean and std (ddof=1) skip NaN; NaN inputs stay NaN. If the std is
+/// undefined (fewer than 2 valid values) every output is NaN, and if it is
+/// 0 (a constant column) every output is NaN too; use
+/// `zscore_column_zero_std` to choose that value instead.
+pub fn zscore_column(x: &Column) -> Column {
+    zscore_column_zero_std(x, f64::NAN)
+}
+
+/// `zscore_column` with the output for a zero std chosen by the caller
+///
+/// `zero_std` (typically `0.0` or `f64::NAN`) replaces every non-NaN input
+/// when the column is constant, as in `rolling_zscore_past_only_f64`.
+///
+/// # Panics
+/// Panics if `x` is not an F64 column.
+pub fn zscore_column_zero_std(x: &Column, zero_std: f64) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("zscore_column: expected F64 column");
+    };
+
+    let m = mean0(x);
+    let s = var0_slice(data).sqrt();
+    if s.is_nan() {
+        return Column::F64(vec![f64::NAN; data.len()]);
+    }
+    if s == 0.0 {
+        let fill = |v: f64| if v.is_nan() { f64::NAN } else { zero_std };
+        return Column::F64(data.iter().map(|&v| fill(v)).collect());
+    }
+
+    Column::F64(data.iter().map(|&v| (v - m) / s).collect())
+}
+
+/// quantile: q-th quantile with linear interpolation (ignores NaN)
+///
+/// Uses the order statistics of the non-NaN values: position `q * (n - 1)`,
+/// interpolated between its neighbours (numpy's default "linear" method).
+/// Returns NaN if empty, all NaN, or `q` outside [0, 1].
+///
+/// Unlike the streaming reductions this sorts a copy: O(n log n).
+pub fn quantile(x: &Column, q: f64) -> f64 {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("quantile: expected F64 column");
+    };
+
+    let mut sorted: Vec<f64> = data.iter().copied().filter(|v| !v.is_nan()).collect();
+    sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+    quantile_sorted(&sorted, q)
+}
+
+/// clip: Clamp each value into [lo, hi]; either bound may be omitted
+///
+/// NaN stays NaN. If `lo > hi`, every non-NaN value becomes `hi`.
+pub fn clip_column(x: &Column, lo: Option<f64>, hi: Option<f64>) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("clip_column: expected F64 column");
+    };
+
+    Column::F64(clip_slice(data, lo, hi))
+}
+
+/// Clip kernel (see `clip_column`)
+pub(crate) fn clip_slice(x: &[f64], lo: Option<f64>, hi: Option<f64>) -> Vec<f64> {
+    x.iter()
+        .map(|&v| {
+            if v.is_nan() {
+                return v;
+            }
+            let v = lo.map_or(v, |lo| v.max(lo));
+            hi.map_or(v, |hi| v.min(hi))
+        })
+        .collect()
+}
+
+/// winsorize: Clip to the column's own `lower_q` and `upper_q` quantiles
+///
+/// Quantiles are computed as in `quantile` (NaN-skipping, linear
+/// interpolation). NaN stays NaN; an all-NaN column is returned unchanged.
+pub fn winsorize_column(x: &Column, lower_q: f64, upper_q: f64) -> Column {
+    let bound = |q: f64| Some(quantile(x, q)).filter(|b| !b.is_nan());
+    clip_column(x, bound(lower_q), bound(upper_q))
+}
+
+/// median: `quantile(x, 0.5)` (ignores NaN)
+pub fn median(x: &Column) -> f64 {
+    quantile(x, 0.5)
+}
+
+/// Linear-interpolated quantile of an ascending, NaN-free slice
+pub(crate) fn quantile_sorted(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() || !(0.0..=1.0).contains(&q) {
+        return f64::NAN;
     }
+
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    let frac = pos - lo as f64;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * frac
 }
 
 #[cfg(test)]
@@ -156,6 +898,19 @@ mod tests {
         assert_eq!(sum0(&col), 0.0);
     }
 
+    #[test]
+    fn test_sum_with_count() {
+        let col = Column::new_f64(vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(sum_with_count(&col), (10.0, 4));
+
+        let col = Column::new_f64(vec![1.0, f64::NAN, 3.0, f64::NAN]);
+        assert_eq!(sum_with_count(&col), (4.0, 2));
+
+        let col = Column::new_f64(vec![f64::NAN, f64::NAN]);
+        assert_eq!(sum_with_count(&col), (0.0, 0));
+        assert_eq!(sum_with_count(&Column::new_f64(vec![])), (0.0, 0));
+    }
+
     #[test]
     fn test_mean_no_nulls() {
         let col = Column::new_f64(vec![1.0, 2.0, 3.0, 4.0]);
@@ -191,4 +946,487 @@ mod tests {
         let col = Column::new_f64(vec![]);
         assert!(mean0(&col).is_nan());
     }
+
+    #[test]
+    fn test_min_max() {
+        let col = Column::new_f64(vec![3.0, -1.0, 4.0, 1.0]);
+        assert_eq!(min(&col), -1.0);
+        assert_eq!(max(&col), 4.0);
+        assert_eq!(min0(&col), -1.0);
+        assert_eq!(max0(&col), 4.0);
+    }
+
+    #[test]
+    fn test_min_max_with_nan() {
+        let col = Column::new_f64(vec![3.0, f64::NAN, 1.0]);
+        assert!(min(&col).is_nan());
+        assert!(max(&col).is_nan());
+        assert_eq!(min0(&col), 1.0);
+        assert_eq!(max0(&col), 3.0);
+    }
+
+    #[test]
+    fn test_min_max_empty_and_all_nan() {
+        let empty = Column::new_f64(vec![]);
+        assert!(min(&empty).is_nan());
+        assert!(max0(&empty).is_nan());
+        assert_eq!(argmin(&empty), None);
+        assert_eq!(argmax(&empty), None);
+
+        let all_nan = Column::new_f64(vec![f64::NAN, f64::NAN]);
+        assert!(min0(&all_nan).is_nan());
+        assert!(max0(&all_nan).is_nan());
+        assert_eq!(argmin(&all_nan), None);
+        assert_eq!(argmax(&all_nan), None);
+    }
+
+    #[test]
+    fn test_argmin_argmax_ties_and_nan() {
+        let col = Column::new_f64(vec![f64::NAN, 2.0, 5.0, 2.0, 5.0]);
+        assert_eq!(argmin(&col), Some(1)); // first of the tied minima
+        assert_eq!(argmax(&col), Some(2)); // first of the tied maxima
+    }
+
+    #[test]
+    fn test_var_std_known_dataset() {
+        // Classic dataset: mean 5, sum of squared deviations 32
+        let col = Column::new_f64(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert!((var(&col) - 32.0 / 7.0).abs() < 1e-12);
+        assert!((std(&col) - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+        assert!((var0(&col) - var(&col)).abs() < 1e-12);
+        assert!((std0(&col) - std(&col)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_var_with_nan_and_short() {
+        let col = Column::new_f64(vec![1.0, f64::NAN, 3.0]);
+        assert!(var(&col).is_nan());
+        assert!((var0(&col) - 2.0).abs() < 1e-12);
+
+        let one = Column::new_f64(vec![1.0, f64::NAN]);
+        assert!(var0(&one).is_nan());
+        assert!(std(&Column::new_f64(vec![])).is_nan());
+    }
+
+    #[test]
+    fn test_var_stable_large_magnitude() {
+        // Values around 1e8: naive E[x^2] - E[x]^2 loses all precision here
+        let col = Column::new_f64(vec![1e8 + 4.0, 1e8 + 7.0, 1e8 + 13.0, 1e8 + 16.0]);
+        assert!((var(&col) - 30.0).abs() < 1e-6);
+        assert!((var0(&col) - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_try_ops_type_mismatch() {
+        let date = Column::new_date(vec![18628, 18629]);
+
+        let err = try_dlog_column(&date, 1).unwrap_err();
+        assert_eq!(err, ColumnTypeError::new("F64", "Date"));
+
+        let ts = Column::new_timestamp(vec![0]);
+        assert_eq!(try_ln_column(&ts).unwrap_err().actual, "Timestamp");
+        assert_eq!(try_abs_column(&ts).unwrap_err().actual, "Timestamp");
+    }
+
+    #[test]
+    fn test_try_ops_match_panicking() {
+        let col = Column::new_f64(vec![100.0, 110.0, 121.0]);
+
+        let a = try_dlog_column(&col, 1).unwrap();
+        let b = dlog_column(&col, 1);
+        assert!(a.f64_data()[0].is_nan());
+        assert_eq!(a.f64_data()[1..], b.f64_data()[1..]);
+
+        assert_eq!(
+            try_ln_column(&col).unwrap().f64_data(),
+            ln_column(&col).f64_data()
+        );
+        assert_eq!(
+            try_abs_column(&col).unwrap().f64_data(),
+            abs_column(&col).f64_data()
+        );
+    }
+
+    #[test]
+    fn test_diff_column() {
+        let col = Column::new_f64(vec![1.0, 4.0, 9.0, 16.0]);
+
+        let d1 = diff_column(&col, 1);
+        let d = d1.f64_data();
+        assert!(d[0].is_nan());
+        assert_eq!(&d[1..], &[3.0, 5.0, 7.0]);
+
+        let d2 = diff_column(&col, 2);
+        let d = d2.f64_data();
+        assert!(d[0].is_nan() && d[1].is_nan());
+        assert_eq!(&d[2..], &[8.0, 12.0]);
+    }
+
+    #[test]
+    fn test_diff_column_edge_cases() {
+        let col = Column::new_f64(vec![1.0, f64::NAN, 3.0]);
+
+        // lag >= len: all NaN
+        assert!(diff_column(&col, 3).f64_data().iter().all(|x| x.is_nan()));
+        assert!(diff_column(&col, 10).f64_data().iter().all(|x| x.is_nan()));
+
+        // NaN propagates to both positions that touch it
+        let d = diff_column(&col, 1);
+        assert!(d.f64_data()[1].is_nan());
+        assert!(d.f64_data()[2].is_nan());
+    }
+
+    #[test]
+    fn test_pct_change_column() {
+        let x = [100.0, 110.0, 99.0, 0.0, 5.0, f64::NAN, 7.0];
+        let col = Column::new_f64(x.to_vec());
+
+        let p = pct_change_column(&col, 1);
+        let p = p.f64_data();
+        assert!(p[0].is_nan());
+        for i in 1..3 {
+            assert!((p[i] - (x[i] / x[i - 1] - 1.0)).abs() < 1e-15);
+        }
+        assert_eq!(p[3], -1.0); // 0 / 99 - 1
+        assert!(p[4].is_nan()); // zero denominator
+        assert!(p[5].is_nan() && p[6].is_nan()); // NaN operand
+
+        let p2 = pct_change_column(&col, 2);
+        assert!((p2.f64_data()[2] - (99.0 / 100.0 - 1.0)).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_pct_change_lag_at_least_len() {
+        let col = Column::new_f64(vec![1.0, 2.0, 3.0]);
+        assert!(pct_change_column(&col, 3)
+            .f64_data()
+            .iter()
+            .all(|x| x.is_nan()));
+        assert!(pct_change_column(&col, 10)
+            .f64_data()
+            .iter()
+            .all(|x| x.is_nan()));
+        assert!(pct_change_column(&Column::new_f64(vec![]), 1).is_empty());
+    }
+
+    #[test]
+    fn test_shift_column_lag_and_lead() {
+        let col = Column::new_f64(vec![1.0, 2.0, 3.0, 4.0]);
+
+        let lagged = shift_column(&col, 1);
+        let d = lagged.f64_data();
+        assert!(d[0].is_nan());
+        assert_eq!(&d[1..], &[1.0, 2.0, 3.0]);
+
+        let led = shift_column(&col, -2);
+        let d = led.f64_data();
+        assert_eq!(&d[..2], &[3.0, 4.0]);
+        assert!(d[2].is_nan() && d[3].is_nan());
+
+        assert_eq!(shift_column(&col, 0).f64_data(), col.f64_data());
+        assert!(shift_column(&col, 10).f64_data().iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_shift_column_all_types() {
+        let date = shift_column(&Column::new_date(vec![10, 20, 30]), 1);
+        assert_eq!(date.date_data(), &[NULL_DATE, 10, 20]);
+
+        let ts = shift_column(&Column::new_timestamp(vec![1, 2, 3]), -1);
+        assert_eq!(ts.timestamp_data(), &[2, 3, NULL_TIMESTAMP]);
+
+        let old_ts = shift_column(&Column::new_ts(vec![5, 6]), 2);
+        assert_eq!(old_ts.ts_data(), &[NULL_TS, NULL_TS]);
+
+        let mask = shift_column(&Column::new_bool(vec![true, true]), 1);
+        assert_eq!(mask.bool_data(), &[false, true]);
+    }
+
+    #[test]
+    fn test_ffill_leading_and_interior_nan() {
+        let nan = f64::NAN;
+        let col = Column::new_f64(vec![nan, nan, 1.0, nan, nan, 4.0, nan]);
+        let out = ffill_column(&col);
+        let data = out.f64_data();
+        assert!(data[0].is_nan() && data[1].is_nan());
+        assert_eq!(&data[2..], &[1.0, 1.0, 1.0, 4.0, 4.0]);
+
+        let filled = fillna_const(&col, 0.0);
+        assert_eq!(filled.f64_data(), &[0.0, 0.0, 1.0, 0.0, 0.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    fn test_ffill_all_nan() {
+        let col = Column::new_f64(vec![f64::NAN; 3]);
+        assert!(ffill_column(&col).f64_data().iter().all(|x| x.is_nan()));
+        assert_eq!(fillna_const(&col, -1.0).f64_data(), &[-1.0; 3]);
+    }
+
+    #[test]
+    fn test_interpolate_single_and_run() {
+        let col = Column::new_f64(vec![1.0, f64::NAN, 3.0]);
+        assert_eq!(interpolate_linear_column(&col).f64_data(), &[1.0, 2.0, 3.0]);
+
+        // A run of three is filled at even steps
+        let col = Column::new_f64(vec![10.0, f64::NAN, f64::NAN, f64::NAN, 18.0, 20.0]);
+        assert_eq!(
+            interpolate_linear_column(&col).f64_data(),
+            &[10.0, 12.0, 14.0, 16.0, 18.0, 20.0]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_edges_untouched() {
+        let col = Column::new_f64(vec![f64::NAN, f64::NAN, 4.0, f64::NAN, 8.0, f64::NAN]);
+        let out = interpolate_linear_column(&col);
+        let data = out.f64_data();
+        assert!(data[0].is_nan() && data[1].is_nan() && data[5].is_nan());
+        assert_eq!(&data[2..5], &[4.0, 6.0, 8.0]);
+
+        let all_nan = Column::new_f64(vec![f64::NAN; 4]);
+        let out = interpolate_linear_column(&all_nan);
+        assert!(out.f64_data().iter().all(|x| x.is_nan()));
+        assert_eq!(interpolate_linear_column(&Column::new_f64(vec![])).len(), 0);
+    }
+
+    #[test]
+    fn test_interpolate_rejects_non_f64() {
+        let err = try_interpolate_linear_column(&Column::new_date(vec![1, 2])).unwrap_err();
+        assert_eq!(err, ColumnTypeError::new("F64", "Date"));
+    }
+
+    #[test]
+    fn test_ffill_temporal_sentinels() {
+        let date = Column::new_date(vec![NULL_DATE, 10, NULL_DATE, NULL_DATE, 13]);
+        assert_eq!(
+            ffill_column(&date).date_data(),
+            &[NULL_DATE, 10, 10, 10, 13]
+        );
+        assert_eq!(fillna_const(&date, 0.0).date_data(), &[0, 10, 0, 0, 13]);
+
+        let ts = Column::new_timestamp(vec![5, NULL_TIMESTAMP]);
+        assert_eq!(ffill_column(&ts).timestamp_data(), &[5, 5]);
+
+        let n = Column::I64(vec![NULL_I64, 2, NULL_I64]);
+        assert_eq!(fillna_const(&n, 7.0).i64_data(), &[7, 2, 7]);
+    }
+
+    #[test]
+    fn test_sum_f32_accumulates_in_f64() {
+        // 2^24 + 1 ones: an f32 accumulator stalls at 2^24
+        let n = (1usize << 24) + 1;
+        let ones = Column::new_f32(vec![1.0; n]);
+        assert_eq!(sum(&ones), n as f64);
+        assert_eq!(mean(&ones), 1.0);
+
+        let tenths = Column::new_f32(vec![0.1; 1_000_000]);
+        let expected = 1_000_000.0 * (0.1f32 as f64);
+        assert!((sum(&tenths) - expected).abs() < 1e-6);
+
+        assert!(sum(&Column::new_f32(vec![1.0, f32::NAN])).is_nan());
+        assert!(mean(&Column::new_f32(vec![])).is_nan());
+    }
+
+    #[test]
+    fn test_dlog_signed_forward() {
+        let x = Column::new_f64(vec![100.0, 110.0, 99.0, 120.0]);
+
+        let fwd = dlog_signed_column(&x, -1);
+        let data = fwd.f64_data();
+        assert_eq!(data[0], 110.0f64.ln() - 100.0f64.ln()); // x[1] / x[0]
+        assert_eq!(data[2], 120.0f64.ln() - 99.0f64.ln());
+        assert!(data[3].is_nan()); // no x[4]
+
+        // Forward at i equals backward at i + 1
+        let back = dlog_column(&x, 1);
+        assert_eq!(&data[..3], &back.f64_data()[1..]);
+
+        let fwd2 = dlog_signed_column(&x, -2);
+        assert!(fwd2.f64_data()[2..].iter().all(|v| v.is_nan()));
+        assert!(dlog_signed_column(&x, -10)
+            .f64_data()
+            .iter()
+            .all(|v| v.is_nan()));
+
+        assert_eq!(dlog_signed_column(&x, 1).f64_data()[1], back.f64_data()[1]);
+    }
+
+    #[test]
+    fn test_dlog_f32_output() {
+        let col = Column::new_f32(vec![100.0, 110.0, f32::NAN, 121.0]);
+        let out = dlog_column(&col, 1);
+        let data = out.f32_data();
+        assert!(data[0].is_nan() && data[2].is_nan() && data[3].is_nan());
+        assert_eq!(data[1], ((110.0f64).ln() - (100.0f64).ln()) as f32);
+        assert!(col.has_nulls());
+        assert_eq!(col.type_name(), "F32");
+    }
+
+    #[test]
+    fn test_dlog_base_scales_natural() {
+        let col = Column::new_f64(vec![100.0, 110.0, 99.0, f64::NAN, 120.0, 0.0]);
+        let natural = dlog_column(&col, 1);
+        assert!(dlog_base_column(&col, 1, LogBase::Natural).approx_eq(&natural, 0.0));
+
+        let log10 = dlog_base_column(&col, 1, LogBase::Ten);
+        let log2 = dlog_base_column(&col, 1, LogBase::Two);
+        for i in 0..col.len() {
+            let n = natural.f64_data()[i];
+            let (t, b) = (log10.f64_data()[i], log2.f64_data()[i]);
+            if n.is_nan() {
+                assert!(t.is_nan() && b.is_nan());
+                continue;
+            }
+            assert!((t - n / std::f64::consts::LN_10).abs() < 1e-15);
+            assert!((b - n / std::f64::consts::LN_2).abs() < 1e-15);
+        }
+        // 100 -> 1000 is exactly one decade
+        let decade = dlog_base_column(&Column::new_f64(vec![100.0, 1000.0]), 1, LogBase::Ten);
+        assert!((decade.f64_data()[1] - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "dlog_column: expected F64 column")]
+    fn test_dlog_column_panics_on_date() {
+        dlog_column(&Column::new_date(vec![1, 2]), 1);
+    }
+
+    #[test]
+    fn test_clip_bounds_and_nan() {
+        let x = Column::new_f64(vec![-5.0, 0.5, f64::NAN, 3.0, 10.0]);
+
+        let out = clip_column(&x, Some(0.0), Some(2.0));
+        let data = out.f64_data();
+        assert_eq!(data[0], 0.0);
+        assert_eq!(data[1], 0.5);
+        assert!(data[2].is_nan());
+        assert_eq!(&data[3..], &[2.0, 2.0]);
+
+        let out = clip_column(&x, None, Some(1.0));
+        assert_eq!(out.f64_data()[0], -5.0);
+        assert_eq!(out.f64_data()[4], 1.0);
+
+        let out = clip_column(&x, Some(1.0), None);
+        assert_eq!(out.f64_data()[1], 1.0);
+        assert_eq!(out.f64_data()[4], 10.0);
+        assert!(out.f64_data()[2].is_nan());
+    }
+
+    #[test]
+    fn test_winsorize_5_95() {
+        // 0..=100 with an interior NaN: quantiles 5 and 95 of 101 values
+        let mut values: Vec<f64> = (0..=100).map(|i| i as f64).collect();
+        values.push(f64::NAN);
+        let x = Column::new_f64(values);
+
+        let out = winsorize_column(&x, 0.05, 0.95);
+        let data = out.f64_data();
+        assert_eq!(data[0], 5.0);
+        assert_eq!(data[4], 5.0);
+        assert_eq!(data[50], 50.0);
+        assert_eq!(data[96], 95.0);
+        assert_eq!(data[100], 95.0);
+        assert!(data[101].is_nan());
+
+        let all_nan = Column::new_f64(vec![f64::NAN; 3]);
+        assert!(winsorize_column(&all_nan, 0.05, 0.95)
+            .f64_data()
+            .iter()
+            .all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_quantile_known_values() {
+        let col = Column::new_f64(vec![4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(quantile(&col, 0.0), 1.0);
+        assert_eq!(quantile(&col, 0.25), 1.75);
+        assert_eq!(quantile(&col, 0.5), 2.5);
+        assert_eq!(quantile(&col, 0.75), 3.25);
+        assert_eq!(quantile(&col, 1.0), 4.0);
+
+        let odd = Column::new_f64(vec![10.0, 50.0, 20.0, 40.0, 30.0]);
+        assert_eq!(median(&odd), 30.0);
+        assert_eq!(quantile(&odd, 0.25), 20.0);
+        assert_eq!(quantile(&odd, 0.1), 14.0);
+    }
+
+    #[test]
+    fn test_quantile_skips_nan_and_edge_cases() {
+        let col = Column::new_f64(vec![f64::NAN, 3.0, 1.0, f64::NAN, 2.0]);
+        assert_eq!(median(&col), 2.0);
+        assert_eq!(quantile(&col, 0.75), 2.5);
+
+        assert!(quantile(&col, -0.1).is_nan());
+        assert!(quantile(&col, 1.5).is_nan());
+        assert!(median(&Column::new_f64(vec![])).is_nan());
+        assert!(median(&Column::new_f64(vec![f64::NAN, f64::NAN])).is_nan());
+    }
+
+    #[test]
+    fn test_zscore_column() {
+        let col = Column::new_f64(vec![1.0, f64::NAN, 2.0, 3.0]);
+        let z = zscore_column(&col);
+        let d = z.f64_data();
+        assert_eq!(d[0], -1.0);
+        assert!(d[1].is_nan());
+        assert_eq!(d[2], 0.0);
+        assert_eq!(d[3], 1.0);
+
+        let flat = zscore_column(&Column::new_f64(vec![4.0, 4.0, 4.0]));
+        assert!(flat.f64_data().iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_zscore_constant_column_zero_std() {
+        let flat = Column::new_f64(vec![4.0, f64::NAN, 4.0, 4.0]);
+        let z = zscore_column_zero_std(&flat, 0.0);
+        let d = z.f64_data();
+        assert_eq!(d[0], 0.0);
+        assert!(d[1].is_nan());
+        assert_eq!(&d[2..], &[0.0, 0.0]);
+
+        // Undefined std (one valid value) stays NaN whatever zero_std is
+        let one = zscore_column_zero_std(&Column::new_f64(vec![4.0, f64::NAN]), 0.0);
+        assert!(one.f64_data().iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_sum_uses_lane_order_in_every_build() {
+        // Values where sequential and 4-lane reassociation round differently
+        let data = vec![1e16, 1.0, -1e16, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5];
+        let sequential: f64 = data.iter().sum();
+        let lanes: f64 = ((1e16 + 1.0) + (1.0 + 1.0)) + ((-1e16 + 1.0) + (1.0 + 1.0)) + 0.5;
+        assert_ne!(sequential.to_bits(), lanes.to_bits());
+
+        assert_eq!(sum_f64_lanes(&data).to_bits(), lanes.to_bits());
+        assert_eq!(sum(&Column::F64(data.clone())).to_bits(), lanes.to_bits());
+        // The order costs at most rounding error against a plain fold
+        assert!((sum(&Column::F64(data)) - sequential).abs() <= 4.0);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_sum_matches_scalar_bitwise() {
+        let data: Vec<f64> = (0..1003)
+            .map(|i| 0.1 * i as f64 + 1e-3 / (i + 1) as f64)
+            .collect();
+        let reference = sum_f64_lanes(&data);
+        assert_eq!(simd::sum_f64(&data).to_bits(), reference.to_bits());
+        assert_eq!(
+            sum(&Column::F64(data.clone())).to_bits(),
+            reference.to_bits()
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        if std::arch::is_x86_feature_detected!("avx2") {
+            let avx2 = unsafe { simd::sum_f64_avx2(&data) };
+            assert_eq!(avx2.to_bits(), reference.to_bits());
+        }
+
+        let mut with_nan = data;
+        with_nan[517] = f64::NAN;
+        let reference = sum_f64_lanes(&with_nan);
+        assert!(reference.is_nan());
+        assert_eq!(simd::sum_f64(&with_nan).to_bits(), reference.to_bits());
+    }
 }
@@ -6,12 +6,58 @@
 //! Demonstrates the O(1) orientation system in action.
 
 use crate::builtins::dlog_column;
-use crate::table::{Column, OriClass, Table, TableView};
+use crate::builtins::kernels_masked::dlog_value;
+use crate::builtins::ops::{sum_count_slice, var0_slice};
+use crate::builtins::rank::{rank_slice, RankMethod};
+use crate::table::{
+    Column, Ori, OriClass, Table, TableView, NULL_DATE, NULL_I64, NULL_SYM, NULL_TIMESTAMP, NULL_TS,
+};
+use std::borrow::Cow;
+use std::fmt;
+
+/// NaN handling for the orientation-aware aggregations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NaPolicy {
+    /// Ignore NaN; only an all-NaN vector yields NaN (the default)
+    Skip,
+    /// Any NaN in a vector makes its result NaN (surfaces data problems)
+    Propagate,
+}
+
+/// Error from the `try_*` orientation-aware ops
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OriError {
+    /// `op` has no meaning in `orientation` (e.g. dlog under R or X)
+    Undefined { op: &'static str, orientation: Ori },
+}
+
+impl fmt::Display for OriError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OriError::Undefined { op, orientation } => {
+                let name = orientation.canonical_name();
+                match orientation.class() {
+                    OriClass::Real => {
+                        write!(f, "{} not defined for Real ({}) orientation", op, name)
+                    }
+                    OriClass::Each => {
+                        write!(f, "{} not defined for Each ({}) orientation", op, name)
+                    }
+                    _ => write!(f, "{} not defined for {} orientation", op, name),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for OriError {}
 
-// Temporary stub for wmean0 (windowed mean)
-// TODO: Implement proper windowed mean function
-fn wmean0(_col: &Column, _window: usize) -> Column {
-    panic!("wmean0 not yet implemented - rolling window mean needs proper implementation")
+/// `Err(Undefined)` naming `op` and `view`'s orientation
+fn undefined<T>(op: &'static str, view: &TableView) -> Result<T, OriError> {
+    Err(OriError::Undefined {
+        op,
+        orientation: view.ori,
+    })
 }
 
 /// Sum operation with orientation-aware dispatch
@@ -45,15 +91,76 @@ fn wmean0(_col: &Column, _window: usize) -> Column {
 /// let result = sum(&view_z);
 /// // result = [5.0, 7.0, 9.0] (sum of each row)
 /// ```
+///
+/// # Panics
+/// Panics under Each (X); see `try_sum` for the non-panicking form.
 pub fn sum(view: &TableView) -> Column {
-    match view.ori_class() {
+    sum_with_policy(view, NaPolicy::Skip)
+}
+
+/// `sum` that returns `OriError::Undefined` under Each (X) instead of panicking
+pub fn try_sum(view: &TableView) -> Result<Column, OriError> {
+    try_sum_with_policy(view, NaPolicy::Skip)
+}
+
+/// `sum` with an explicit NaN policy
+pub fn sum_with_policy(view: &TableView, policy: NaPolicy) -> Column {
+    try_sum_with_policy(view, policy).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// `try_sum` with an explicit NaN policy
+pub fn try_sum_with_policy(view: &TableView, policy: NaPolicy) -> Result<Column, OriError> {
+    let result = match view.ori_class() {
         OriClass::ColwiseLike => sum_colwise(&view.table),
         OriClass::RowwiseLike => sum_rowwise_tiled(&view.table),
         OriClass::Real => sum_scalar(&view.table),
-        OriClass::Each => {
-            panic!("sum not defined for Each (X) orientation - use for broadcast context only")
+        OriClass::Each => return undefined("sum", view),
+    };
+    Ok(apply_na_policy(view, policy, result))
+}
+
+/// Under `Propagate`, set to NaN every result whose vector contains a NaN
+///
+/// `result` is a Skip-policy reduction of `view`: one value per column
+/// (ColwiseLike), per row (RowwiseLike) or a single scalar (Real). Every
+/// float storage (F64, F32, F64Mmap) is checked; other types have no NaN.
+fn apply_na_policy(view: &TableView, policy: NaPolicy, mut result: Column) -> Column {
+    if policy == NaPolicy::Skip {
+        return result;
+    }
+
+    let table = &view.table;
+    let out = result.f64_data_mut();
+    match view.ori_class() {
+        OriClass::ColwiseLike => {
+            for (slot, col) in out.iter_mut().zip(&table.columns) {
+                if float_values(col).is_some_and(|data| data.iter().any(|x| x.is_nan())) {
+                    *slot = f64::NAN;
+                }
+            }
+        }
+        OriClass::RowwiseLike => {
+            for data in table.columns.iter().filter_map(float_values) {
+                for (slot, x) in out.iter_mut().zip(data.iter()) {
+                    if x.is_nan() {
+                        *slot = f64::NAN;
+                    }
+                }
+            }
+        }
+        OriClass::Real => {
+            let any_nan = table
+                .columns
+                .iter()
+                .filter_map(float_values)
+                .any(|data| data.iter().any(|x| x.is_nan()));
+            if any_nan {
+                out.fill(f64::NAN);
+            }
         }
+        OriClass::Each => unreachable!("Each panics before reduction"),
     }
+    result
 }
 
 /// Sum each column (ColwiseLike mode)
@@ -61,33 +168,37 @@ pub fn sum(view: &TableView) -> Column {
 /// Fast path: columns are contiguous in memory.
 /// Output has one value per column.
 fn sum_colwise(table: &Table) -> Column {
-    let ncols = table.col_count();
-    let mut result = Vec::with_capacity(ncols);
-
-    for col in &table.columns {
-        match col {
-            Column::F64(data) => {
-                // Sum this column, skipping NaN values
-                let mut sum = 0.0;
-                let mut has_valid = false;
-                for &val in data {
-                    if !val.is_nan() {
-                        sum += val;
-                        has_valid = true;
-                    }
+    let result = table
+        .columns
+        .iter()
+        .map(|col| match float_values(col) {
+            // Sum this column, skipping NaN values
+            Some(data) => {
+                let valid = data.iter().filter(|x| !x.is_nan());
+                let (sum, count) = valid.fold((0.0, 0), |(s, c), &x| (s + x, c + 1));
+                if count > 0 {
+                    sum
+                } else {
+                    f64::NAN
                 }
-                result.push(if has_valid { sum } else { f64::NAN });
             }
-            Column::Date(_) | Column::Timestamp(_) | Column::Ts(_) => {
-                // Non-numeric columns: output NA
-                result.push(f64::NAN);
-            }
-        }
-    }
+            // Non-float columns: output NA
+            None => f64::NAN,
+        })
+        .collect();
 
     Column::F64(result)
 }
 
+/// Tile height for rowwise kernels (rows per cache block)
+const TILE_SIZE: usize = 128;
+
+/// Row count below which `sum_rowwise_tiled` stays serial (feature `parallel`)
+///
+/// Tunable: below this, rayon's fork/join overhead outweighs the per-row work.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_ROWS: usize = 1 << 16;
+
 /// Sum each row (RowwiseLike mode) with tiling
 ///
 /// Cache-friendly tiled implementation:
@@ -95,10 +206,12 @@ fn sum_colwise(table: &Table) -> Column {
 /// - Accumulate within each tile
 /// - Reduces cache misses on wide tables
 ///
+/// With feature `parallel`, tiles are distributed across rayon threads once
+/// the table has at least `PARALLEL_MIN_ROWS` rows. Each tile owns a disjoint
+/// slice of the output, so the result is identical to the serial path.
+///
 /// Output has one value per row.
 fn sum_rowwise_tiled(table: &Table) -> Column {
-    const TILE_SIZE: usize = 128;
-
     let nrows = table.row_count();
     let ncols = table.col_count();
     let mut result = vec![0.0; nrows];
@@ -107,15 +220,9 @@ fn sum_rowwise_tiled(table: &Table) -> Column {
         return Column::F64(result);
     }
 
-    // Extract F64 columns (skip temporal columns)
-    let f64_cols: Vec<&[f64]> = table
-        .columns
-        .iter()
-        .filter_map(|col| match col {
-            Column::F64(data) => Some(data.as_slice()),
-            _ => None,
-        })
-        .collect();
+    // Float columns as f64 (skip temporal and other columns)
+    let floats = float_columns(table);
+    let f64_cols: Vec<&[f64]> = floats.iter().map(|c| c.as_ref()).collect();
 
     if f64_cols.is_empty() {
         // No numeric columns: all NaN
@@ -123,27 +230,52 @@ fn sum_rowwise_tiled(table: &Table) -> Column {
         return Column::F64(result);
     }
 
-    // Process in tiles for cache efficiency
-    for tile_start in (0..nrows).step_by(TILE_SIZE) {
-        let tile_end = (tile_start + TILE_SIZE).min(nrows);
+    #[cfg(feature = "parallel")]
+    if nrows >= PARALLEL_MIN_ROWS {
+        sum_tiles_parallel(&f64_cols, &mut result);
+        return Column::F64(result);
+    }
 
-        for row in tile_start..tile_end {
-            let mut sum = 0.0;
-            let mut has_valid = false;
+    sum_tiles_serial(&f64_cols, &mut result);
+    Column::F64(result)
+}
 
-            for col_data in &f64_cols {
-                let val = col_data[row];
-                if !val.is_nan() {
-                    sum += val;
-                    has_valid = true;
-                }
-            }
+/// Process tiles in order on the current thread
+fn sum_tiles_serial(f64_cols: &[&[f64]], result: &mut [f64]) {
+    for (tile, out) in result.chunks_mut(TILE_SIZE).enumerate() {
+        sum_tile(f64_cols, tile * TILE_SIZE, out);
+    }
+}
+
+/// Process tiles across rayon threads (disjoint output slices)
+#[cfg(feature = "parallel")]
+fn sum_tiles_parallel(f64_cols: &[&[f64]], result: &mut [f64]) {
+    use rayon::prelude::*;
+
+    result
+        .par_chunks_mut(TILE_SIZE)
+        .enumerate()
+        .for_each(|(tile, out)| sum_tile(f64_cols, tile * TILE_SIZE, out));
+}
+
+/// NaN-skipping row sums for rows `tile_start..tile_start + out.len()`
+#[inline]
+fn sum_tile(f64_cols: &[&[f64]], tile_start: usize, out: &mut [f64]) {
+    for (k, slot) in out.iter_mut().enumerate() {
+        let row = tile_start + k;
+        let mut sum = 0.0;
+        let mut has_valid = false;
 
-            result[row] = if has_valid { sum } else { f64::NAN };
+        for col_data in f64_cols {
+            let val = col_data[row];
+            if !val.is_nan() {
+                sum += val;
+                has_valid = true;
+            }
         }
-    }
 
-    Column::F64(result)
+        *slot = if has_valid { sum } else { f64::NAN };
+    }
 }
 
 /// Sum all values (Real mode)
@@ -153,18 +285,12 @@ fn sum_scalar(table: &Table) -> Column {
     let mut total = 0.0;
     let mut has_valid = false;
 
-    for col in &table.columns {
-        match col {
-            Column::F64(data) => {
-                for &val in data {
-                    if !val.is_nan() {
-                        total += val;
-                        has_valid = true;
-                    }
-                }
-            }
-            Column::Date(_) | Column::Timestamp(_) | Column::Ts(_) => {
-                // Skip non-numeric columns
+    // Non-float columns are skipped
+    for data in table.columns.iter().filter_map(float_values) {
+        for &val in data.iter() {
+            if !val.is_nan() {
+                total += val;
+                has_valid = true;
             }
         }
     }
@@ -173,259 +299,616 @@ fn sum_scalar(table: &Table) -> Column {
     Column::F64(vec![result])
 }
 
-/// Daily log returns (dlog) with orientation-aware dispatch
-///
-/// Computes: dlog(x[i]) = log(x[i] / x[i-1])
+/// A float column's values as f64: F64/F64Mmap borrowed, F32 widened
 ///
-/// # Behavior by orientation:
-/// - ColwiseLike (H, N, _N, _H): Apply dlog down each column (vector is along i)
-/// - RowwiseLike (Z, S, _Z, _S): Apply dlog across each row (vector is along j)
-/// - Real (R): Not defined (panic) - dlog requires sequence
-/// - Each (X): Not defined (panic) - dlog requires sequence
-///
-/// # Example:
-/// ```
-/// use blawktrust::{Table, TableView, Column, ORI_H, ORI_Z};
-/// use blawktrust::builtins::ori_ops::dlog;
-///
-/// let table = Table::new(
-///     vec!["a".to_string(), "b".to_string()],
-///     vec![
-///         Column::F64(vec![100.0, 110.0, 105.0]),
-///         Column::F64(vec![50.0, 52.0, 51.0]),
-///     ]
-/// );
-///
-/// // H orientation: dlog down each column
-/// let view_h = TableView::with_ori(table.clone(), ORI_H);
-/// let result = dlog(&view_h);
-/// // Each column transformed independently
-///
-/// // Z orientation: dlog across each row
-/// let view_z = TableView::with_ori(table, ORI_Z);
-/// let result = dlog(&view_z);
-/// // Each row transformed independently
-/// ```
-pub fn dlog(view: &TableView) -> Table {
-    match view.ori_class() {
-        OriClass::ColwiseLike => dlog_colwise(&view.table),
-        OriClass::RowwiseLike => dlog_rowwise(&view.table),
-        OriClass::Real => panic!("dlog not defined for Real (R) orientation - requires sequence"),
-        OriClass::Each => panic!("dlog not defined for Each (X) orientation - requires sequence"),
+/// `None` for every other type; the vector ops pass those through
+/// unchanged. Widening keeps one f64 code path for all float storage.
+pub(crate) fn float_values(col: &Column) -> Option<Cow<'_, [f64]>> {
+    match col {
+        Column::F64(data) => Some(Cow::Borrowed(data)),
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => Some(Cow::Borrowed(m.as_slice())),
+        Column::F32(data) => Some(Cow::Owned(data.iter().map(|&x| x as f64).collect())),
+        Column::Date(_)
+        | Column::Timestamp(_)
+        | Column::Ts(_)
+        | Column::Bool(_)
+        | Column::I64(_)
+        | Column::Sym { .. } => None,
     }
 }
 
-/// Apply dlog down each column (ColwiseLike mode)
-///
-/// Each column is a time series; compute dlog within each column.
-fn dlog_colwise(table: &Table) -> Table {
-    let mut new_columns = Vec::with_capacity(table.columns.len());
+/// `float_values` of every float column, in table order
+fn float_columns(table: &Table) -> Vec<Cow<'_, [f64]>> {
+    table.columns.iter().filter_map(float_values).collect()
+}
 
-    for col in &table.columns {
-        let new_col = match col {
-            Column::F64(_) => dlog_column(col, 1), // lag=1 for daily returns
-            Column::Date(_) | Column::Timestamp(_) | Column::Ts(_) => col.clone(),
-        };
-        new_columns.push(new_col);
+/// Wrap f64 results in `col`'s float storage: F32 stays F32 (rounded
+/// once), F64 and F64Mmap become F64
+fn float_like(col: &Column, values: Vec<f64>) -> Column {
+    match col {
+        Column::F32(_) => Column::F32(values.into_iter().map(|x| x as f32).collect()),
+        _ => Column::F64(values),
     }
+}
+
+/// Apply a vector transform down each float column (ColwiseLike mode)
+///
+/// Non-float columns are copied unchanged.
+fn map_columns(table: &Table, f: impl Fn(&[f64]) -> Vec<f64>) -> Table {
+    let new_columns = table
+        .columns
+        .iter()
+        .map(|col| match float_values(col) {
+            Some(data) => float_like(col, f(&data)),
+            None => col.clone(),
+        })
+        .collect();
 
     Table::new(table.names.clone(), new_columns)
 }
 
-/// Apply dlog across each row (RowwiseLike mode)
+/// Apply a vector transform across each row's float cells (RowwiseLike mode)
 ///
-/// Each row is a sequence; compute dlog within each row.
-/// Output has same shape as input.
-fn dlog_rowwise(table: &Table) -> Table {
+/// Each row's sequence is its float cells in column order; non-float
+/// columns are copied unchanged and do not take part.
+fn map_rows(table: &Table, f: impl Fn(&[f64]) -> Vec<f64>) -> Table {
     let nrows = table.row_count();
-    let ncols = table.col_count();
+    let floats: Vec<Option<Cow<[f64]>>> = table.columns.iter().map(float_values).collect();
+    let inputs: Vec<&[f64]> = floats.iter().flatten().map(|c| c.as_ref()).collect();
 
-    if nrows == 0 || ncols == 0 {
-        return Table::new(table.names.clone(), table.columns.clone());
+    let mut outputs: Vec<Vec<f64>> = vec![Vec::with_capacity(nrows); inputs.len()];
+    let mut row_values: Vec<f64> = Vec::with_capacity(inputs.len());
+    for row in 0..nrows {
+        row_values.clear();
+        row_values.extend(inputs.iter().map(|data| data[row]));
+        for (out, v) in outputs.iter_mut().zip(f(&row_values)) {
+            out.push(v);
+        }
     }
 
-    // Extract F64 columns (preserve temporal as-is)
-    let f64_indices: Vec<usize> = table
+    let mut outputs = outputs.into_iter();
+    let new_columns = table
         .columns
         .iter()
-        .enumerate()
-        .filter_map(|(i, col)| match col {
-            Column::F64(_) => Some(i),
-            _ => None,
+        .zip(&floats)
+        .map(|(col, values)| match values {
+            Some(_) => float_like(col, outputs.next().expect("one output per float column")),
+            None => col.clone(),
         })
         .collect();
 
-    // Build result columns
-    let mut new_columns = vec![Column::F64(vec![f64::NAN; nrows]); ncols];
+    Table::new(table.names.clone(), new_columns)
+}
+
+/// Mean operation with orientation-aware dispatch
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Mean of each column → ncols values
+/// - RowwiseLike (Z, S, _Z, _S): Mean of each row → nrows values
+/// - Real (R): Mean of all values → single scalar
+/// - Each (X): Not defined (broadcast mode, no vector structure for aggregation)
+///
+/// NaN values are skipped and each mean divides by its own valid count, so
+/// rows with different NaN counts are averaged correctly. An all-NaN vector
+/// yields NaN. Non-float columns are ignored (NaN under ColwiseLike).
+pub fn mean(view: &TableView) -> Column {
+    mean_with_policy(view, NaPolicy::Skip)
+}
 
-    // Copy temporal columns as-is
-    for (i, col) in table.columns.iter().enumerate() {
-        if matches!(col, Column::Date(_) | Column::Timestamp(_)) {
-            new_columns[i] = col.clone();
+/// `mean` with an explicit NaN policy
+pub fn mean_with_policy(view: &TableView, policy: NaPolicy) -> Column {
+    let result = match view.ori_class() {
+        OriClass::ColwiseLike => reduce_vectors_colwise(&view.table, mean_slice),
+        OriClass::RowwiseLike => mean_rowwise_tiled(&view.table),
+        OriClass::Real => mean_scalar(&view.table),
+        OriClass::Each => {
+            panic!("mean not defined for Each (X) orientation - use for broadcast context only")
         }
+    };
+    apply_na_policy(view, policy, result)
+}
+
+/// NaN-skipping mean of a slice (NaN if no valid values)
+#[inline]
+fn mean_slice(data: &[f64]) -> f64 {
+    let (sum, count) = sum_count_slice(data);
+    if count == 0 {
+        f64::NAN
+    } else {
+        sum / count as f64
     }
+}
 
-    // Process each row
-    for row in 0..nrows {
-        // Collect this row's values from F64 columns
-        let mut row_values: Vec<f64> = Vec::with_capacity(f64_indices.len());
-        for &col_idx in &f64_indices {
-            if let Column::F64(data) = &table.columns[col_idx] {
-                row_values.push(data[row]);
-            }
-        }
+/// Mean of each row (RowwiseLike mode) with tiling
+///
+/// Same 128-row tiling and NaN skipping as `sum_rowwise_tiled`; each row
+/// divides by its own valid count.
+fn mean_rowwise_tiled(table: &Table) -> Column {
+    let nrows = table.row_count();
+    let floats = float_columns(table);
+    let f64_cols: Vec<&[f64]> = floats.iter().map(|c| c.as_ref()).collect();
 
-        // Compute dlog for this row
-        let dlog_values = compute_dlog_sequence(&row_values);
+    let mut result = vec![f64::NAN; nrows];
+
+    for tile_start in (0..nrows).step_by(TILE_SIZE) {
+        let tile_end = (tile_start + TILE_SIZE).min(nrows);
 
-        // Write back to result
-        for (result_idx, &col_idx) in f64_indices.iter().enumerate() {
-            if let Column::F64(data) = &mut new_columns[col_idx] {
-                data[row] = dlog_values[result_idx];
+        for row in tile_start..tile_end {
+            let mut sum = 0.0;
+            let mut count = 0;
+            for col_data in &f64_cols {
+                let val = col_data[row];
+                if !val.is_nan() {
+                    sum += val;
+                    count += 1;
+                }
+            }
+            if count > 0 {
+                result[row] = sum / count as f64;
             }
         }
     }
 
-    Table::new(table.names.clone(), new_columns)
+    Column::F64(result)
 }
 
-/// Compute dlog for a sequence: dlog[i] = log(x[i] / x[i-1])
-///
-/// First element is NaN (no previous value).
-fn compute_dlog_sequence(values: &[f64]) -> Vec<f64> {
-    let mut result = vec![f64::NAN; values.len()];
-
-    if values.is_empty() {
-        return result;
+/// Mean of all float values (Real mode)
+fn mean_scalar(table: &Table) -> Column {
+    let (mut sum, mut count) = (0.0, 0);
+    for data in table.columns.iter().filter_map(float_values) {
+        let (s, c) = sum_count_slice(&data);
+        sum += s;
+        count += c;
     }
 
-    for i in 1..values.len() {
-        let curr = values[i];
-        let prev = values[i - 1];
+    let result = if count == 0 {
+        f64::NAN
+    } else {
+        sum / count as f64
+    };
+    Column::F64(vec![result])
+}
 
-        if !curr.is_nan() && !prev.is_nan() && prev > 0.0 {
-            result[i] = (curr / prev).ln();
+/// Non-null count with orientation-aware dispatch
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Non-null values in each column → ncols values
+/// - RowwiseLike (Z, S, _Z, _S): Non-null cells in each row → nrows values
+/// - Real (R): Non-null cells in the table → single scalar
+/// - Each (X): Not defined (broadcast mode, no vector structure for aggregation)
+///
+/// Unlike the numeric reducers, every column type counts: F64/F32 cells are
+/// null when NaN, Date/Timestamp/Ts/I64 cells when equal to their sentinel,
+/// and Bool cells never. Counts are returned as F64.
+pub fn count(view: &TableView) -> Column {
+    let table = &view.table;
+    match view.ori_class() {
+        OriClass::ColwiseLike => Column::F64(
+            table
+                .columns
+                .iter()
+                .map(|col| (col.len() - col.null_count()) as f64)
+                .collect(),
+        ),
+        OriClass::RowwiseLike => {
+            let mut counts = vec![0.0; table.row_count()];
+            for col in &table.columns {
+                for (slot, valid) in counts.iter_mut().zip(valid_cells(col)) {
+                    if valid {
+                        *slot += 1.0;
+                    }
+                }
+            }
+            Column::F64(counts)
+        }
+        OriClass::Real => {
+            let total: usize = table
+                .columns
+                .iter()
+                .map(|col| col.len() - col.null_count())
+                .sum();
+            Column::F64(vec![total as f64])
+        }
+        OriClass::Each => {
+            panic!("count not defined for Each (X) orientation - use for broadcast context only")
         }
     }
+}
 
-    result
+/// Per-cell validity of a column (false at its null sentinel)
+fn valid_cells(col: &Column) -> Box<dyn Iterator<Item = bool> + '_> {
+    match col {
+        Column::F64(data) => Box::new(data.iter().map(|x| !x.is_nan())),
+        Column::F32(data) => Box::new(data.iter().map(|x| !x.is_nan())),
+        Column::Date(data) => Box::new(data.iter().map(|&x| x != NULL_DATE)),
+        Column::Timestamp(data) => Box::new(data.iter().map(|&x| x != NULL_TIMESTAMP)),
+        Column::Ts(data) => Box::new(data.iter().map(|&x| x != NULL_TS)),
+        Column::I64(data) => Box::new(data.iter().map(|&x| x != NULL_I64)),
+        Column::Bool(data) => Box::new(data.iter().map(|_| true)),
+        Column::Sym { codes, .. } => Box::new(codes.iter().map(|&c| c != NULL_SYM)),
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => Box::new(m.as_slice().iter().map(|x| !x.is_nan())),
+    }
 }
 
-/// Rolling 5-period window mean (w5) with orientation-aware dispatch
-///
-/// Computes: w5(x[i]) = mean(x[i-4], x[i-3], x[i-2], x[i-1], x[i])
+/// Min operation with orientation-aware dispatch
 ///
 /// # Behavior by orientation:
-/// - ColwiseLike (H, N, _N, _H): Apply w5 down each column (vector is along i)
-/// - RowwiseLike (Z, S, _Z, _S): Apply w5 across each row (vector is along j)
-/// - Real (R): Not defined (panic) - w5 requires sequence
-/// - Each (X): Not defined (panic) - w5 requires sequence
-///
-/// # Window Semantics:
-/// - First 4 values are NaN (not enough history)
-/// - NaN values in window are skipped (0-fill semantics)
-/// - If entire window is NaN, output is NaN
-///
-/// # Example:
-/// ```no_run
-/// use blawktrust::{Table, TableView, Column, ORI_H, ORI_Z};
-/// use blawktrust::builtins::ori_ops::w5;
-///
-/// let table = Table::new(
-///     vec!["prices".to_string()],
-///     vec![Column::F64(vec![100.0, 102.0, 101.0, 103.0, 105.0, 104.0])]
-/// );
+/// - ColwiseLike (H, N, _N, _H): Min down each column → output has ncols values
+/// - RowwiseLike (Z, S, _Z, _S): Min across each row → output has nrows values
+/// - Real (R): Min of all values → output is single scalar
+/// - Each (X): Not defined (broadcast mode, no vector structure for aggregation)
 ///
-/// // H orientation: w5 down the column
-/// let view_h = TableView::with_ori(table, ORI_H);
-/// let result = w5(&view_h);
-/// // result column: [NaN, NaN, NaN, NaN, mean(100..105), mean(102..104)]
-/// ```
-pub fn w5(view: &TableView) -> Table {
-    const WINDOW: usize = 5;
+/// NaN values are skipped; an all-NaN vector yields NaN.
+pub fn min(view: &TableView) -> Column {
+    min_with_policy(view, NaPolicy::Skip)
+}
 
-    match view.ori_class() {
-        OriClass::ColwiseLike => w5_colwise(&view.table, WINDOW),
-        OriClass::RowwiseLike => w5_rowwise(&view.table, WINDOW),
-        OriClass::Real => panic!("w5 not defined for Real (R) orientation - requires sequence"),
-        OriClass::Each => panic!("w5 not defined for Each (X) orientation - requires sequence"),
-    }
+/// `min` with an explicit NaN policy
+pub fn min_with_policy(view: &TableView, policy: NaPolicy) -> Column {
+    let result = match view.ori_class() {
+        OriClass::ColwiseLike => fold_colwise(&view.table, f64::min),
+        OriClass::RowwiseLike => fold_rowwise_tiled(&view.table, f64::min),
+        OriClass::Real => fold_scalar(&view.table, f64::min),
+        OriClass::Each => {
+            panic!("min not defined for Each (X) orientation - use for broadcast context only")
+        }
+    };
+    apply_na_policy(view, policy, result)
 }
 
-/// Apply w5 down each column (ColwiseLike mode)
+/// Max operation with orientation-aware dispatch
 ///
-/// Each column is a time series; compute rolling window within each column.
-fn w5_colwise(table: &Table, window: usize) -> Table {
-    let mut new_columns = Vec::with_capacity(table.columns.len());
+/// Same dispatch and NaN handling as `min`.
+pub fn max(view: &TableView) -> Column {
+    max_with_policy(view, NaPolicy::Skip)
+}
 
-    for col in &table.columns {
-        let new_col = match col {
-            Column::F64(_) => wmean0(col, window),
-            Column::Date(_) | Column::Timestamp(_) | Column::Ts(_) => col.clone(),
-        };
-        new_columns.push(new_col);
-    }
+/// `max` with an explicit NaN policy
+pub fn max_with_policy(view: &TableView, policy: NaPolicy) -> Column {
+    let result = match view.ori_class() {
+        OriClass::ColwiseLike => fold_colwise(&view.table, f64::max),
+        OriClass::RowwiseLike => fold_rowwise_tiled(&view.table, f64::max),
+        OriClass::Real => fold_scalar(&view.table, f64::max),
+        OriClass::Each => {
+            panic!("max not defined for Each (X) orientation - use for broadcast context only")
+        }
+    };
+    apply_na_policy(view, policy, result)
+}
 
-    Table::new(table.names.clone(), new_columns)
+/// NaN-skipping fold of a slice: None if no valid values
+#[inline]
+fn fold_skip_nan<F: Fn(f64, f64) -> f64>(acc: Option<f64>, val: f64, f: &F) -> Option<f64> {
+    if val.is_nan() {
+        acc
+    } else {
+        Some(acc.map_or(val, |a| f(a, val)))
+    }
 }
 
-/// Apply w5 across each row (RowwiseLike mode)
+/// Fold each column (ColwiseLike mode), skipping NaN
 ///
-/// Each row is a sequence; compute rolling window within each row.
-/// Output has same shape as input.
-fn w5_rowwise(table: &Table, window: usize) -> Table {
-    let nrows = table.row_count();
-    let ncols = table.col_count();
-
-    if nrows == 0 || ncols == 0 {
-        return Table::new(table.names.clone(), table.columns.clone());
-    }
-
-    // Extract F64 columns (preserve temporal as-is)
-    let f64_indices: Vec<usize> = table
+/// Non-numeric columns produce NaN, matching `sum_colwise`.
+fn fold_colwise<F: Fn(f64, f64) -> f64>(table: &Table, f: F) -> Column {
+    let result = table
         .columns
         .iter()
-        .enumerate()
-        .filter_map(|(i, col)| match col {
-            Column::F64(_) => Some(i),
-            _ => None,
+        .map(|col| match float_values(col) {
+            Some(data) => data
+                .iter()
+                .fold(None, |acc, &val| fold_skip_nan(acc, val, &f))
+                .unwrap_or(f64::NAN),
+            None => f64::NAN,
         })
         .collect();
 
-    // Build result columns
-    let mut new_columns = vec![Column::F64(vec![f64::NAN; nrows]); ncols];
+    Column::F64(result)
+}
+
+/// Fold each row (RowwiseLike mode) with tiling, skipping NaN
+///
+/// Same 128-row tiling as `sum_rowwise_tiled`.
+fn fold_rowwise_tiled<F: Fn(f64, f64) -> f64>(table: &Table, f: F) -> Column {
+    let nrows = table.row_count();
+    let floats = float_columns(table);
+    let f64_cols: Vec<&[f64]> = floats.iter().map(|c| c.as_ref()).collect();
+
+    let mut result = vec![f64::NAN; nrows];
+
+    for tile_start in (0..nrows).step_by(TILE_SIZE) {
+        let tile_end = (tile_start + TILE_SIZE).min(nrows);
 
-    // Copy temporal columns as-is
-    for (i, col) in table.columns.iter().enumerate() {
-        if matches!(col, Column::Date(_) | Column::Timestamp(_)) {
-            new_columns[i] = col.clone();
+        for row in tile_start..tile_end {
+            let mut acc = None;
+            for col_data in &f64_cols {
+                acc = fold_skip_nan(acc, col_data[row], &f);
+            }
+            result[row] = acc.unwrap_or(f64::NAN);
         }
     }
 
-    // Process each row
-    for row in 0..nrows {
-        // Collect this row's values from F64 columns
-        let mut row_values: Vec<f64> = Vec::with_capacity(f64_indices.len());
-        for &col_idx in &f64_indices {
-            if let Column::F64(data) = &table.columns[col_idx] {
-                row_values.push(data[row]);
-            }
+    Column::F64(result)
+}
+
+/// Fold all values (Real mode), skipping NaN and non-numeric columns
+fn fold_scalar<F: Fn(f64, f64) -> f64>(table: &Table, f: F) -> Column {
+    let mut acc = None;
+
+    for data in table.columns.iter().filter_map(float_values) {
+        for &val in data.iter() {
+            acc = fold_skip_nan(acc, val, &f);
         }
+    }
 
-        // Compute w5 for this row sequence
-        let w5_values = compute_wmean_sequence(&row_values, window);
+    Column::F64(vec![acc.unwrap_or(f64::NAN)])
+}
 
-        // Write back to result
-        for (result_idx, &col_idx) in f64_indices.iter().enumerate() {
-            if let Column::F64(data) = &mut new_columns[col_idx] {
-                data[row] = w5_values[result_idx];
-            }
+/// Sample variance (ddof=1) with orientation-aware dispatch
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Variance of each column → ncols values
+/// - RowwiseLike (Z, S, _Z, _S): Variance of each row → nrows values
+/// - Real (R): Variance of all values → single scalar
+/// - Each (X): Not defined (broadcast mode, no vector structure for aggregation)
+///
+/// NaN values are skipped; fewer than 2 valid values yields NaN.
+pub fn var(view: &TableView) -> Column {
+    match view.ori_class() {
+        OriClass::ColwiseLike => reduce_vectors_colwise(&view.table, var0_slice),
+        OriClass::RowwiseLike => reduce_vectors_rowwise(&view.table, var0_slice),
+        OriClass::Real => reduce_vectors_scalar(&view.table, var0_slice),
+        OriClass::Each => {
+            panic!("var not defined for Each (X) orientation - use for broadcast context only")
         }
     }
+}
+
+/// Sample standard deviation (ddof=1) with orientation-aware dispatch
+///
+/// Same dispatch and NaN handling as `var`.
+pub fn std(view: &TableView) -> Column {
+    let std_slice = |data: &[f64]| var0_slice(data).sqrt();
+    match view.ori_class() {
+        OriClass::ColwiseLike => reduce_vectors_colwise(&view.table, std_slice),
+        OriClass::RowwiseLike => reduce_vectors_rowwise(&view.table, std_slice),
+        OriClass::Real => reduce_vectors_scalar(&view.table, std_slice),
+        OriClass::Each => {
+            panic!("std not defined for Each (X) orientation - use for broadcast context only")
+        }
+    }
+}
+
+/// Reduce each float column as a whole vector (non-numeric columns → NaN)
+fn reduce_vectors_colwise<F: Fn(&[f64]) -> f64>(table: &Table, f: F) -> Column {
+    let result = table
+        .columns
+        .iter()
+        .map(|col| match float_values(col) {
+            Some(data) => f(&data),
+            None => f64::NAN,
+        })
+        .collect();
+
+    Column::F64(result)
+}
+
+/// Reduce each row as a whole vector, gathering float cells into a reused buffer
+fn reduce_vectors_rowwise<F: Fn(&[f64]) -> f64>(table: &Table, f: F) -> Column {
+    let nrows = table.row_count();
+    let floats = float_columns(table);
+    let f64_cols: Vec<&[f64]> = floats.iter().map(|c| c.as_ref()).collect();
+
+    let mut row_buf = Vec::with_capacity(f64_cols.len());
+    let mut result = Vec::with_capacity(nrows);
+    for row in 0..nrows {
+        row_buf.clear();
+        row_buf.extend(f64_cols.iter().map(|col| col[row]));
+        result.push(f(&row_buf));
+    }
+
+    Column::F64(result)
+}
+
+/// Reduce all float values in the table as one vector
+fn reduce_vectors_scalar<F: Fn(&[f64]) -> f64>(table: &Table, f: F) -> Column {
+    let all: Vec<f64> = float_columns(table).concat();
+
+    Column::F64(vec![f(&all)])
+}
+
+/// Daily log returns (dlog) with orientation-aware dispatch
+///
+/// Computes: dlog(x[i]) = log(x[i] / x[i-1])
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Apply dlog down each column (vector is along i)
+/// - RowwiseLike (Z, S, _Z, _S): Apply dlog across each row (vector is along j)
+/// - Real (R): Not defined (panic) - dlog requires sequence
+/// - Each (X): Not defined (panic) - dlog requires sequence
+///
+/// # Example:
+/// ```
+/// use blawktrust::{Table, TableView, Column, ORI_H, ORI_Z};
+/// use blawktrust::builtins::ori_ops::dlog;
+///
+/// let table = Table::new(
+///     vec!["a".to_string(), "b".to_string()],
+///     vec![
+///         Column::F64(vec![100.0, 110.0, 105.0]),
+///         Column::F64(vec![50.0, 52.0, 51.0]),
+///     ]
+/// );
+///
+/// // H orientation: dlog down each column
+/// let view_h = TableView::with_ori(table.clone(), ORI_H);
+/// let result = dlog(&view_h);
+/// // Each column transformed independently
+///
+/// // Z orientation: dlog across each row
+/// let view_z = TableView::with_ori(table, ORI_Z);
+/// let result = dlog(&view_z);
+/// // Each row transformed independently
+/// ```
+pub fn dlog(view: &TableView) -> Table {
+    try_dlog(view).unwrap_or_else(|e| panic!("{} - requires sequence", e))
+}
+
+/// `dlog` that returns `OriError::Undefined` under Real (R) or Each (X)
+/// instead of panicking
+pub fn try_dlog(view: &TableView) -> Result<Table, OriError> {
+    match view.ori_class() {
+        OriClass::ColwiseLike => Ok(dlog_colwise(&view.table)),
+        OriClass::RowwiseLike => Ok(dlog_rowwise(&view.table)),
+        OriClass::Real | OriClass::Each => undefined("dlog", view),
+    }
+}
+
+/// Apply dlog down each column (ColwiseLike mode)
+///
+/// Each column is a time series; compute dlog within each column.
+fn dlog_colwise(table: &Table) -> Table {
+    let mut new_columns = Vec::with_capacity(table.columns.len());
+
+    for col in &table.columns {
+        let new_col = match col {
+            // lag=1 for daily returns; F32 is computed in f64 and stays F32
+            Column::F64(_) | Column::F32(_) => dlog_column(col, 1),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(_) => dlog_column(col, 1),
+            Column::Date(_)
+            | Column::Timestamp(_)
+            | Column::Ts(_)
+            | Column::Bool(_)
+            | Column::I64(_)
+            | Column::Sym { .. } => col.clone(),
+        };
+        new_columns.push(new_col);
+    }
 
     Table::new(table.names.clone(), new_columns)
 }
 
+/// Apply dlog across each row (RowwiseLike mode)
+///
+/// Each row is a sequence; compute dlog within each row.
+/// Output has same shape as input.
+fn dlog_rowwise(table: &Table) -> Table {
+    map_rows(table, compute_dlog_sequence)
+}
+
+/// Compute dlog for a sequence: dlog[i] = ln(x[i]) - ln(x[i-1])
+///
+/// First element is NaN (no previous value); non-positive inputs yield NaN
+/// (see `kernels_masked::dlog_value`).
+fn compute_dlog_sequence(values: &[f64]) -> Vec<f64> {
+    let mut result = vec![f64::NAN; values.len()];
+
+    if values.is_empty() {
+        return result;
+    }
+
+    for i in 1..values.len() {
+        result[i] = dlog_value(values[i], values[i - 1]);
+    }
+
+    result
+}
+
+/// Rolling window mean (wmean) with orientation-aware dispatch
+///
+/// Computes: wmean(x[i]) = mean(x[i-window+1], ..., x[i])
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Apply down each column (vector is along i)
+/// - RowwiseLike (Z, S, _Z, _S): Apply across each row (vector is along j)
+/// - Real (R): Not defined (panic) - wmean requires sequence
+/// - Each (X): Not defined (panic) - wmean requires sequence
+///
+/// # Window Semantics:
+/// - First window-1 values are NaN (not enough history)
+/// - NaN values in window are skipped (0-fill semantics)
+/// - If entire window is NaN, output is NaN
+/// - window=1 is identity (NaN passes through); window=0 yields all NaN
+///
+/// # Example:
+/// ```
+/// use blawktrust::{Table, TableView, Column, ORI_H};
+/// use blawktrust::builtins::ori_ops::wmean;
+///
+/// let table = Table::new(
+///     vec!["prices".to_string()],
+///     vec![Column::F64(vec![1.0, 2.0, 3.0, 4.0])]
+/// );
+///
+/// let result = wmean(&TableView::with_ori(table, ORI_H), 3);
+/// // result column: [NaN, NaN, 2.0, 3.0]
+/// ```
+pub fn wmean(view: &TableView, window: usize) -> Table {
+    match view.ori_class() {
+        OriClass::ColwiseLike => wmean_colwise(&view.table, window),
+        OriClass::RowwiseLike => wmean_rowwise(&view.table, window),
+        OriClass::Real => panic!("wmean not defined for Real (R) orientation - requires sequence"),
+        OriClass::Each => panic!("wmean not defined for Each (X) orientation - requires sequence"),
+    }
+}
+
+/// Rolling 5-period window mean (w5) with orientation-aware dispatch
+///
+/// Computes: w5(x[i]) = mean(x[i-4], x[i-3], x[i-2], x[i-1], x[i])
+///
+/// Equivalent to `wmean(view, 5)`; see `wmean` for window semantics.
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Apply w5 down each column (vector is along i)
+/// - RowwiseLike (Z, S, _Z, _S): Apply w5 across each row (vector is along j)
+/// - Real (R): Not defined (panic) - w5 requires sequence
+/// - Each (X): Not defined (panic) - w5 requires sequence
+///
+/// # Example:
+/// ```
+/// use blawktrust::{Table, TableView, Column, ORI_H, ORI_Z};
+/// use blawktrust::builtins::ori_ops::w5;
+///
+/// let table = Table::new(
+///     vec!["prices".to_string()],
+///     vec![Column::F64(vec![100.0, 102.0, 101.0, 103.0, 105.0, 104.0])]
+/// );
+///
+/// // H orientation: w5 down the column
+/// let view_h = TableView::with_ori(table, ORI_H);
+/// let result = w5(&view_h);
+/// // result column: [NaN, NaN, NaN, NaN, mean(100..105), mean(102..104)]
+/// ```
+pub fn w5(view: &TableView) -> Table {
+    try_w5(view).unwrap_or_else(|e| panic!("{} - requires sequence", e))
+}
+
+/// `w5` that returns `OriError::Undefined` under Real (R) or Each (X)
+/// instead of panicking
+pub fn try_w5(view: &TableView) -> Result<Table, OriError> {
+    match view.ori_class() {
+        OriClass::Real | OriClass::Each => undefined("w5", view),
+        _ => Ok(wmean(view, 5)),
+    }
+}
+
+/// Apply wmean down each column (ColwiseLike mode)
+///
+/// Each column is a time series; compute rolling window within each column.
+fn wmean_colwise(table: &Table, window: usize) -> Table {
+    map_columns(table, |data| compute_wmean_sequence(data, window))
+}
+
+/// Apply wmean across each row (RowwiseLike mode)
+///
+/// Each row is a sequence; compute rolling window within each row.
+/// Output has same shape as input.
+fn wmean_rowwise(table: &Table, window: usize) -> Table {
+    map_rows(table, |row| compute_wmean_sequence(row, window))
+}
+
 /// Compute rolling window mean for a sequence
 ///
 /// For each position i, compute mean of window [i-w+1, i]
@@ -465,9 +948,159 @@ fn compute_wmean_sequence(values: &[f64], window: usize) -> Vec<f64> {
     result
 }
 
+/// Demean (neutralize) with orientation-aware dispatch
+///
+/// Subtracts the NaN-skipping mean of each vector from its elements.
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Subtract each column's mean
+/// - RowwiseLike (Z, S, _Z, _S): Subtract each row's mean (cross-sectional
+///   demeaning across assets at each timestamp)
+/// - Real (R): Not defined (panic) - demean requires a vector
+/// - Each (X): Not defined (panic) - demean requires a vector
+///
+/// Shape is preserved; NaN stays NaN and non-float columns are unchanged
+/// (F32 is computed in f64 and stays F32).
+///
+/// # Example:
+/// ```
+/// use blawktrust::{Table, TableView, Column, ORI_Z};
+/// use blawktrust::builtins::ori_ops::demean;
+///
+/// let table = Table::new(
+///     vec!["a".to_string(), "b".to_string()],
+///     vec![Column::F64(vec![1.0, 5.0]), Column::F64(vec![3.0, 5.0])]
+/// );
+///
+/// let result = demean(&TableView::with_ori(table, ORI_Z));
+/// assert_eq!(result.columns[0].f64_data(), &[-1.0, 0.0]);
+/// assert_eq!(result.columns[1].f64_data(), &[1.0, 0.0]);
+/// ```
+pub fn demean(view: &TableView) -> Table {
+    match view.ori_class() {
+        OriClass::ColwiseLike => demean_colwise(&view.table),
+        OriClass::RowwiseLike => demean_rowwise(&view.table),
+        OriClass::Real => panic!("demean not defined for Real (R) orientation - requires vector"),
+        OriClass::Each => panic!("demean not defined for Each (X) orientation - requires vector"),
+    }
+}
+
+/// Subtract each float column's mean (ColwiseLike mode)
+fn demean_colwise(table: &Table) -> Table {
+    map_columns(table, demean_sequence)
+}
+
+/// Subtract each row's mean over the float columns (RowwiseLike mode)
+fn demean_rowwise(table: &Table) -> Table {
+    map_rows(table, demean_sequence)
+}
+
+/// x - mean(x), mean skipping NaN
+fn demean_sequence(values: &[f64]) -> Vec<f64> {
+    let mean = mean_slice(values);
+    values.iter().map(|&x| x - mean).collect()
+}
+
+/// Rank with orientation-aware dispatch (1-based, see `rank_column`)
+///
+/// # Behavior by orientation:
+/// - ColwiseLike (H, N, _N, _H): Rank down each column (time-series rank)
+/// - RowwiseLike (Z, S, _Z, _S): Rank across each row (cross-sectional rank
+///   of assets at a timestamp)
+/// - Real (R): Not defined (panic) - rank requires sequence
+/// - Each (X): Not defined (panic) - rank requires sequence
+///
+/// Output has the same shape as input. NaN cells get NaN rank and are
+/// excluded from the ranking; non-float columns are passed through.
+///
+/// # Example:
+/// ```
+/// use blawktrust::{Table, TableView, Column, ORI_Z};
+/// use blawktrust::builtins::ori_ops::rank;
+/// use blawktrust::builtins::RankMethod;
+///
+/// let table = Table::new(
+///     vec!["a".to_string(), "b".to_string()],
+///     vec![Column::F64(vec![0.1, 0.5]), Column::F64(vec![0.3, 0.2])]
+/// );
+///
+/// let result = rank(&TableView::with_ori(table, ORI_Z), RankMethod::Ordinal);
+/// assert_eq!(result.columns[0].f64_data(), &[1.0, 2.0]);
+/// assert_eq!(result.columns[1].f64_data(), &[2.0, 1.0]);
+/// ```
+pub fn rank(view: &TableView, method: RankMethod) -> Table {
+    match view.ori_class() {
+        OriClass::ColwiseLike => rank_colwise(&view.table, method),
+        OriClass::RowwiseLike => rank_rowwise(&view.table, method),
+        OriClass::Real => panic!("rank not defined for Real (R) orientation - requires sequence"),
+        OriClass::Each => panic!("rank not defined for Each (X) orientation - requires sequence"),
+    }
+}
+
+/// Rank down each column (ColwiseLike mode)
+fn rank_colwise(table: &Table, method: RankMethod) -> Table {
+    map_columns(table, |data| rank_slice(data, method))
+}
+
+/// Rank across each row (RowwiseLike mode)
+///
+/// Each row's float cells are ranked against each other.
+/// Output has same shape as input.
+fn rank_rowwise(table: &Table, method: RankMethod) -> Table {
+    map_rows(table, |row| rank_slice(row, method))
+}
+
+/// Elementwise `x + c` with orientation-aware dispatch
+///
+/// # Behavior by orientation:
+/// - Each (X): Broadcast `c` to every float cell of the table
+/// - ColwiseLike / RowwiseLike / Real: Same result - a scalar op has no
+///   vector structure, so every orientation visits each cell exactly once
+///
+/// Non-float columns are passed through unchanged; NaN stays NaN. F32
+/// cells are computed in f64 and stay F32.
+///
+/// # Example:
+/// ```
+/// use blawktrust::{Table, TableView, Column, ORI_X};
+/// use blawktrust::builtins::ori_ops::add;
+///
+/// let table = Table::new(
+///     vec!["a".to_string()],
+///     vec![Column::F64(vec![1.0, 2.0])]
+/// );
+///
+/// let result = add(&TableView::with_ori(table, ORI_X), 5.0);
+/// assert_eq!(result.columns[0].f64_data(), &[6.0, 7.0]);
+/// ```
+pub fn add(view: &TableView, c: f64) -> Table {
+    map_elementwise(view, |x| x + c)
+}
+
+/// Elementwise `x - c` (see `add` for orientation behavior)
+pub fn sub(view: &TableView, c: f64) -> Table {
+    map_elementwise(view, |x| x - c)
+}
+
+/// Elementwise `x * c` (see `add` for orientation behavior)
+pub fn mul(view: &TableView, c: f64) -> Table {
+    map_elementwise(view, |x| x * c)
+}
+
+/// Elementwise `x / c` (see `add` for orientation behavior)
+pub fn div(view: &TableView, c: f64) -> Table {
+    map_elementwise(view, |x| x / c)
+}
+
+/// Apply `f` to every float cell; layout is irrelevant for elementwise ops
+fn map_elementwise(view: &TableView, f: impl Fn(f64) -> f64) -> Table {
+    map_columns(&view.table, |data| data.iter().map(|&x| f(x)).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::builtins::test_util::assert_f64_close;
     use crate::table::{ORI_H, ORI_R, ORI_X, ORI_Z};
 
     fn make_test_table() -> Table {
@@ -567,7 +1200,379 @@ mod tests {
     }
 
     #[test]
-    fn test_sum_rowwise_with_nan() {
+    fn test_sum_rowwise_with_nan() {
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 3.0]),
+                Column::F64(vec![4.0, 5.0, f64::NAN]),
+            ],
+        );
+
+        let view = TableView::with_ori(table, ORI_Z);
+        let result = sum(&view);
+
+        match result {
+            Column::F64(data) => {
+                assert_eq!(data[0], 5.0); // 1 + 4
+                assert_eq!(data[1], 5.0); // NaN + 5 = 5
+                assert_eq!(data[2], 3.0); // 3 + NaN = 3
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sum_all_nan() {
+        let table = Table::new(
+            vec!["a".to_string()],
+            vec![Column::F64(vec![f64::NAN, f64::NAN])],
+        );
+
+        // Colwise
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        let result = sum(&view);
+        match result {
+            Column::F64(data) => assert!(data[0].is_nan()),
+            _ => panic!(),
+        }
+
+        // Scalar
+        let view = TableView::with_ori(table, ORI_R);
+        let result = sum(&view);
+        match result {
+            Column::F64(data) => assert!(data[0].is_nan()),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sum_empty_table() {
+        let table = Table::new(vec![], vec![]);
+
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        let result = sum(&view);
+        match result {
+            Column::F64(data) => assert_eq!(data.len(), 0),
+            _ => panic!(),
+        }
+
+        let view = TableView::with_ori(table, ORI_R);
+        let result = sum(&view);
+        match result {
+            Column::F64(data) => {
+                assert_eq!(data.len(), 1);
+                assert!(data[0].is_nan());
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sum_temporal_columns() {
+        use crate::table::{NULL_DATE, NULL_TIMESTAMP};
+
+        let table = Table::new(
+            vec!["date".to_string(), "value".to_string(), "ts".to_string()],
+            vec![
+                Column::Date(vec![18628, 18629, NULL_DATE]),
+                Column::F64(vec![1.0, 2.0, 3.0]),
+                Column::Timestamp(vec![0, 1_000_000_000, NULL_TIMESTAMP]),
+            ],
+        );
+
+        // Colwise: date and timestamp columns should produce NaN
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        let result = sum(&view);
+        match result {
+            Column::F64(data) => {
+                assert_eq!(data.len(), 3);
+                assert!(data[0].is_nan()); // date → NaN
+                assert_eq!(data[1], 6.0); // value → 6.0
+                assert!(data[2].is_nan()); // timestamp → NaN
+            }
+            _ => panic!(),
+        }
+
+        // Scalar: only sum numeric column
+        let view = TableView::with_ori(table, ORI_R);
+        let result = sum(&view);
+        match result {
+            Column::F64(data) => {
+                assert_eq!(data.len(), 1);
+                assert_eq!(data[0], 6.0); // Only value column contributes
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_mean_all_orientations() {
+        let table = make_test_table();
+
+        let result = mean(&TableView::with_ori(table.clone(), ORI_H));
+        assert_eq!(result.f64_data(), &[2.0, 5.0]);
+
+        let result = mean(&TableView::with_ori(table.clone(), ORI_Z));
+        assert_eq!(result.f64_data(), &[2.5, 3.5, 4.5]);
+
+        let result = mean(&TableView::with_ori(table, ORI_R));
+        assert_eq!(result.f64_data(), &[3.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mean not defined for Each")]
+    fn test_mean_each_panics() {
+        mean(&TableView::with_ori(make_test_table(), ORI_X));
+    }
+
+    #[test]
+    fn test_mean_rowwise_with_nan() {
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 3.0, f64::NAN]),
+                Column::F64(vec![4.0, 5.0, f64::NAN, f64::NAN]),
+                Column::F64(vec![7.0, 9.0, f64::NAN, f64::NAN]),
+            ],
+        );
+
+        let result = mean(&TableView::with_ori(table.clone(), ORI_Z));
+        let data = result.f64_data();
+        assert_eq!(data[0], 4.0); // (1 + 4 + 7) / 3
+        assert_eq!(data[1], 7.0); // (5 + 9) / 2
+        assert_eq!(data[2], 3.0); // 3 / 1
+        assert!(data[3].is_nan()); // no valid values
+
+        // Colwise and scalar also divide by valid counts
+        let result = mean(&TableView::with_ori(table.clone(), ORI_H));
+        assert_eq!(result.f64_data(), &[2.0, 4.5, 8.0]);
+        let result = mean(&TableView::with_ori(table, ORI_R));
+        assert_eq!(result.f64_data(), &[29.0 / 6.0]);
+    }
+
+    #[test]
+    fn test_mean_temporal_and_empty() {
+        use crate::table::NULL_DATE;
+
+        let table = Table::new(
+            vec!["date".to_string(), "value".to_string()],
+            vec![
+                Column::Date(vec![18628, 18629, NULL_DATE]),
+                Column::F64(vec![1.0, 2.0, 6.0]),
+            ],
+        );
+        let result = mean(&TableView::with_ori(table.clone(), ORI_H));
+        assert!(result.f64_data()[0].is_nan());
+        assert_eq!(result.f64_data()[1], 3.0);
+        let result = mean(&TableView::with_ori(table, ORI_Z));
+        assert_eq!(result.f64_data(), &[1.0, 2.0, 6.0]);
+
+        let empty = Table::new(vec![], vec![]);
+        assert_eq!(mean(&TableView::with_ori(empty.clone(), ORI_H)).len(), 0);
+        assert!(mean(&TableView::with_ori(empty, ORI_R)).f64_data()[0].is_nan());
+    }
+
+    #[test]
+    fn test_count_mixed_completeness() {
+        use crate::table::{NULL_DATE, NULL_TIMESTAMP};
+
+        let table = Table::new(
+            vec![
+                "date".to_string(),
+                "a".to_string(),
+                "b".to_string(),
+                "ts".to_string(),
+            ],
+            vec![
+                Column::Date(vec![18628, NULL_DATE, 18630, 18631]),
+                Column::F64(vec![1.0, f64::NAN, f64::NAN, 4.0]),
+                Column::F64(vec![f64::NAN, f64::NAN, f64::NAN, 2.0]),
+                Column::Timestamp(vec![0, 1, NULL_TIMESTAMP, 3]),
+            ],
+        );
+
+        let result = count(&TableView::with_ori(table.clone(), ORI_H));
+        assert_eq!(result.f64_data(), &[3.0, 2.0, 1.0, 3.0]);
+
+        // Ragged NaNs: each row counts its own non-null cells
+        let result = count(&TableView::with_ori(table.clone(), ORI_Z));
+        assert_eq!(result.f64_data(), &[3.0, 1.0, 1.0, 4.0]);
+
+        let result = count(&TableView::with_ori(table, ORI_R));
+        assert_eq!(result.f64_data(), &[9.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "count not defined for Each")]
+    fn test_count_each_panics() {
+        count(&TableView::with_ori(make_test_table(), ORI_X));
+    }
+
+    #[test]
+    fn test_sum_policy_interior_nan() {
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 3.0]),
+                Column::F64(vec![4.0, 5.0, 6.0]),
+            ],
+        );
+
+        // Colwise
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        assert_eq!(
+            sum_with_policy(&view, NaPolicy::Skip).f64_data(),
+            &[4.0, 15.0]
+        );
+        let strict = sum_with_policy(&view, NaPolicy::Propagate);
+        assert!(strict.f64_data()[0].is_nan());
+        assert_eq!(strict.f64_data()[1], 15.0);
+        assert_eq!(sum(&view).f64_data(), &[4.0, 15.0]);
+
+        // Rowwise
+        let view = TableView::with_ori(table.clone(), ORI_Z);
+        assert_eq!(
+            sum_with_policy(&view, NaPolicy::Skip).f64_data(),
+            &[5.0, 5.0, 9.0]
+        );
+        let strict = sum_with_policy(&view, NaPolicy::Propagate);
+        assert_eq!(strict.f64_data()[0], 5.0);
+        assert!(strict.f64_data()[1].is_nan());
+        assert_eq!(strict.f64_data()[2], 9.0);
+
+        // Real
+        let view = TableView::with_ori(table, ORI_R);
+        assert!(sum_with_policy(&view, NaPolicy::Propagate).f64_data()[0].is_nan());
+    }
+
+    #[test]
+    fn test_policy_sees_nan_in_f32_columns() {
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                Column::F32(vec![1.0, f32::NAN, 3.0]),
+                Column::F64(vec![4.0, 5.0, 6.0]),
+            ],
+        );
+
+        let sums = |ori, policy| sum_with_policy(&TableView::with_ori(table.clone(), ori), policy);
+        let nan = f64::NAN;
+
+        assert_f64_close(sums(ORI_H, NaPolicy::Skip).f64_data(), &[4.0, 15.0], 0.0);
+        assert_f64_close(
+            sums(ORI_H, NaPolicy::Propagate).f64_data(),
+            &[nan, 15.0],
+            0.0,
+        );
+
+        assert_f64_close(
+            sums(ORI_Z, NaPolicy::Skip).f64_data(),
+            &[5.0, 5.0, 9.0],
+            0.0,
+        );
+        assert_f64_close(
+            sums(ORI_Z, NaPolicy::Propagate).f64_data(),
+            &[5.0, nan, 9.0],
+            0.0,
+        );
+
+        assert_f64_close(sums(ORI_R, NaPolicy::Skip).f64_data(), &[19.0], 0.0);
+        assert_f64_close(sums(ORI_R, NaPolicy::Propagate).f64_data(), &[nan], 0.0);
+    }
+
+    #[test]
+    fn test_mean_min_max_policy() {
+        let table = Table::new(
+            vec!["a".to_string(), "d".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 3.0]),
+                Column::Date(vec![1, 2, 3]),
+                Column::F64(vec![4.0, 5.0, 6.0]),
+            ],
+        );
+
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        let m = mean_with_policy(&view, NaPolicy::Propagate);
+        assert!(m.f64_data()[0].is_nan());
+        assert_eq!(m.f64_data()[2], 5.0);
+        assert_eq!(min_with_policy(&view, NaPolicy::Skip).f64_data()[0], 1.0);
+
+        // Temporal columns never poison a row
+        let view = TableView::with_ori(table, ORI_Z);
+        let hi = max_with_policy(&view, NaPolicy::Propagate);
+        assert_eq!(hi.f64_data()[0], 4.0);
+        assert!(hi.f64_data()[1].is_nan());
+        assert_eq!(hi.f64_data()[2], 6.0);
+    }
+
+    #[test]
+    fn test_sum_rowwise_large() {
+        // Test tiling with > 128 rows
+        let nrows = 300;
+        let data_a: Vec<f64> = (0..nrows).map(|i| i as f64).collect();
+        let data_b: Vec<f64> = (0..nrows).map(|i| (i * 10) as f64).collect();
+
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![Column::F64(data_a.clone()), Column::F64(data_b.clone())],
+        );
+
+        let view = TableView::with_ori(table, ORI_Z);
+        let result = sum(&view);
+
+        match result {
+            Column::F64(data) => {
+                assert_eq!(data.len(), nrows);
+                for i in 0..nrows {
+                    let expected = (i + i * 10) as f64;
+                    assert_eq!(data[i], expected);
+                }
+            }
+            _ => panic!(),
+        }
+    }
+
+    // ============ min/max tests ============
+
+    #[test]
+    fn test_min_max_colwise() {
+        let view = TableView::with_ori(make_test_table(), ORI_H);
+
+        assert_eq!(min(&view).f64_data(), &[1.0, 4.0]);
+        assert_eq!(max(&view).f64_data(), &[3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_min_max_rowwise() {
+        let view = TableView::with_ori(make_test_table(), ORI_Z);
+
+        assert_eq!(min(&view).f64_data(), &[1.0, 2.0, 3.0]);
+        assert_eq!(max(&view).f64_data(), &[4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_min_max_scalar() {
+        let view = TableView::with_ori(make_test_table(), ORI_R);
+
+        assert_eq!(min(&view).f64_data(), &[1.0]);
+        assert_eq!(max(&view).f64_data(), &[6.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "min not defined for Each")]
+    fn test_min_each_panics() {
+        min(&TableView::with_ori(make_test_table(), ORI_X));
+    }
+
+    #[test]
+    #[should_panic(expected = "max not defined for Each")]
+    fn test_max_each_panics() {
+        max(&TableView::with_ori(make_test_table(), ORI_X));
+    }
+
+    #[test]
+    fn test_min_max_with_nan() {
         let table = Table::new(
             vec!["a".to_string(), "b".to_string()],
             vec![
@@ -576,67 +1581,45 @@ mod tests {
             ],
         );
 
-        let view = TableView::with_ori(table, ORI_Z);
-        let result = sum(&view);
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        assert_eq!(min(&view).f64_data(), &[1.0, 4.0]);
+        assert_eq!(max(&view).f64_data(), &[3.0, 5.0]);
 
-        match result {
-            Column::F64(data) => {
-                assert_eq!(data[0], 5.0); // 1 + 4
-                assert_eq!(data[1], 5.0); // NaN + 5 = 5
-                assert_eq!(data[2], 3.0); // 3 + NaN = 3
-            }
-            _ => panic!(),
-        }
+        let view = TableView::with_ori(table.clone(), ORI_Z);
+        assert_eq!(min(&view).f64_data(), &[1.0, 5.0, 3.0]);
+        assert_eq!(max(&view).f64_data(), &[4.0, 5.0, 3.0]);
+
+        let view = TableView::with_ori(table, ORI_R);
+        assert_eq!(min(&view).f64_data(), &[1.0]);
+        assert_eq!(max(&view).f64_data(), &[5.0]);
     }
 
     #[test]
-    fn test_sum_all_nan() {
+    fn test_min_max_all_nan() {
         let table = Table::new(
             vec!["a".to_string()],
             vec![Column::F64(vec![f64::NAN, f64::NAN])],
         );
 
-        // Colwise
-        let view = TableView::with_ori(table.clone(), ORI_H);
-        let result = sum(&view);
-        match result {
-            Column::F64(data) => assert!(data[0].is_nan()),
-            _ => panic!(),
-        }
-
-        // Scalar
-        let view = TableView::with_ori(table, ORI_R);
-        let result = sum(&view);
-        match result {
-            Column::F64(data) => assert!(data[0].is_nan()),
-            _ => panic!(),
+        for ori in [ORI_H, ORI_Z, ORI_R] {
+            let view = TableView::with_ori(table.clone(), ori);
+            assert!(min(&view).f64_data().iter().all(|x| x.is_nan()));
+            assert!(max(&view).f64_data().iter().all(|x| x.is_nan()));
         }
     }
 
     #[test]
-    fn test_sum_empty_table() {
+    fn test_min_max_empty_table() {
         let table = Table::new(vec![], vec![]);
 
-        let view = TableView::with_ori(table.clone(), ORI_H);
-        let result = sum(&view);
-        match result {
-            Column::F64(data) => assert_eq!(data.len(), 0),
-            _ => panic!(),
-        }
-
-        let view = TableView::with_ori(table, ORI_R);
-        let result = sum(&view);
-        match result {
-            Column::F64(data) => {
-                assert_eq!(data.len(), 1);
-                assert!(data[0].is_nan());
-            }
-            _ => panic!(),
-        }
+        assert_eq!(min(&TableView::with_ori(table.clone(), ORI_H)).len(), 0);
+        let scalar = max(&TableView::with_ori(table, ORI_R));
+        assert_eq!(scalar.len(), 1);
+        assert!(scalar.f64_data()[0].is_nan());
     }
 
     #[test]
-    fn test_sum_temporal_columns() {
+    fn test_min_max_temporal_columns() {
         use crate::table::{NULL_DATE, NULL_TIMESTAMP};
 
         let table = Table::new(
@@ -648,58 +1631,78 @@ mod tests {
             ],
         );
 
-        // Colwise: date and timestamp columns should produce NaN
-        let view = TableView::with_ori(table.clone(), ORI_H);
-        let result = sum(&view);
-        match result {
-            Column::F64(data) => {
-                assert_eq!(data.len(), 3);
-                assert!(data[0].is_nan()); // date → NaN
-                assert_eq!(data[1], 6.0); // value → 6.0
-                assert!(data[2].is_nan()); // timestamp → NaN
-            }
-            _ => panic!(),
-        }
+        let result = min(&TableView::with_ori(table.clone(), ORI_H));
+        let data = result.f64_data();
+        assert!(data[0].is_nan());
+        assert_eq!(data[1], 1.0);
+        assert!(data[2].is_nan());
 
-        // Scalar: only sum numeric column
-        let view = TableView::with_ori(table, ORI_R);
-        let result = sum(&view);
-        match result {
-            Column::F64(data) => {
-                assert_eq!(data.len(), 1);
-                assert_eq!(data[0], 6.0); // Only value column contributes
-            }
-            _ => panic!(),
-        }
+        let result = max(&TableView::with_ori(table, ORI_R));
+        assert_eq!(result.f64_data(), &[3.0]);
     }
 
     #[test]
-    fn test_sum_rowwise_large() {
+    fn test_min_max_rowwise_large() {
         // Test tiling with > 128 rows
         let nrows = 300;
         let data_a: Vec<f64> = (0..nrows).map(|i| i as f64).collect();
-        let data_b: Vec<f64> = (0..nrows).map(|i| (i * 10) as f64).collect();
+        let data_b: Vec<f64> = (0..nrows).map(|i| 150.0 - i as f64).collect();
 
         let table = Table::new(
             vec!["a".to_string(), "b".to_string()],
             vec![Column::F64(data_a.clone()), Column::F64(data_b.clone())],
         );
-
         let view = TableView::with_ori(table, ORI_Z);
-        let result = sum(&view);
 
-        match result {
-            Column::F64(data) => {
-                assert_eq!(data.len(), nrows);
-                for i in 0..nrows {
-                    let expected = (i + i * 10) as f64;
-                    assert_eq!(data[i], expected);
-                }
-            }
-            _ => panic!(),
+        let mins = min(&view);
+        let maxs = max(&view);
+        for i in 0..nrows {
+            assert_eq!(mins.f64_data()[i], data_a[i].min(data_b[i]));
+            assert_eq!(maxs.f64_data()[i], data_a[i].max(data_b[i]));
         }
     }
 
+    // ============ var/std tests ============
+
+    #[test]
+    fn test_var_std_by_orientation() {
+        let table = make_test_table(); // a: [1,2,3], b: [4,5,6]
+
+        let view = TableView::with_ori(table.clone(), ORI_H);
+        assert_eq!(var(&view).f64_data(), &[1.0, 1.0]);
+        assert_eq!(std(&view).f64_data(), &[1.0, 1.0]);
+
+        // Rows: [1,4], [2,5], [3,6] → var 4.5 each
+        let view = TableView::with_ori(table.clone(), ORI_Z);
+        assert_eq!(var(&view).f64_data(), &[4.5, 4.5, 4.5]);
+
+        // All: [1..6] → var 3.5
+        let view = TableView::with_ori(table, ORI_R);
+        assert!((var(&view).f64_data()[0] - 3.5).abs() < 1e-12);
+        assert!((std(&view).f64_data()[0] - 3.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_var_skips_nan_and_temporal() {
+        let table = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![1, 2, 3]),
+                Column::F64(vec![1.0, f64::NAN, 3.0]),
+            ],
+        );
+
+        let result = var(&TableView::with_ori(table, ORI_H));
+        assert!(result.f64_data()[0].is_nan());
+        assert_eq!(result.f64_data()[1], 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "std not defined for Each")]
+    fn test_std_each_panics() {
+        std(&TableView::with_ori(make_test_table(), ORI_X));
+    }
+
     // ============ dlog tests ============
 
     #[test]
@@ -801,6 +1804,104 @@ mod tests {
         dlog(&view);
     }
 
+    #[test]
+    fn test_demean_selects_float_columns_in_both_orientations() {
+        let table = Table::new(
+            vec!["a".to_string(), "d".to_string(), "b".to_string()],
+            vec![
+                Column::F32(vec![1.0, 4.0]),
+                Column::Date(vec![1, 2]),
+                Column::F64(vec![3.0, 8.0]),
+            ],
+        );
+
+        let h = demean(&TableView::with_ori(table.clone(), ORI_H));
+        assert_eq!(h.columns[0].f32_data(), &[-1.5, 1.5]);
+        assert_eq!(h.columns[2].f64_data(), &[-2.5, 2.5]);
+
+        // The F32 column enters each row mean; Date passes through
+        let z = demean(&TableView::with_ori(table, ORI_Z));
+        assert_eq!(z.columns[0].f32_data(), &[-1.0, -2.0]);
+        assert_eq!(z.columns[1].date_data(), &[1, 2]);
+        assert_eq!(z.columns[2].f64_data(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_reducers_treat_f32_like_f64_in_every_orientation() {
+        let names = vec!["a".to_string(), "b".to_string()];
+        let b = Column::F64(vec![10.0, 20.0, 30.0]);
+        let f32_table = Table::new(
+            names.clone(),
+            vec![Column::F32(vec![1.0, f32::NAN, 3.0]), b.clone()],
+        );
+        let f64_table = Table::new(names, vec![Column::F64(vec![1.0, f64::NAN, 3.0]), b]);
+
+        type Reduce = fn(&TableView) -> Column;
+        let reducers: [(&str, Reduce); 4] =
+            [("sum", sum), ("mean", mean), ("max", max), ("std", std)];
+        for ori in [ORI_H, ORI_Z, ORI_R] {
+            for (name, reduce) in reducers {
+                let got = reduce(&TableView::with_ori(f32_table.clone(), ori));
+                let want = reduce(&TableView::with_ori(f64_table.clone(), ori));
+                assert!(
+                    got.approx_eq(&want, 0.0),
+                    "{} {:?}: {:?} vs {:?}",
+                    name,
+                    ori,
+                    got,
+                    want
+                );
+            }
+        }
+
+        // Spot values: the F32 column takes part everywhere
+        let view = |ori| TableView::with_ori(f32_table.clone(), ori);
+        assert_eq!(sum(&view(ORI_Z)).f64_data(), &[11.0, 20.0, 33.0]);
+        assert_eq!(mean(&view(ORI_H)).f64_data(), &[2.0, 20.0]);
+        assert_eq!(mean(&view(ORI_R)).f64_data(), &[64.0 / 5.0]);
+    }
+
+    #[test]
+    fn test_f32_columns_are_computed_not_passed_through() {
+        let table = Table::new(
+            vec!["px32".to_string(), "px".to_string()],
+            vec![
+                Column::F32(vec![100.0, 110.0, 121.0]),
+                Column::F64(vec![100.0, 110.0, 121.0]),
+            ],
+        );
+        let h = TableView::with_ori(table.clone(), ORI_H);
+
+        // dlog returns returns, in the input's storage type
+        let out = dlog(&h);
+        let (r32, r64) = (out.columns[0].f32_data(), out.columns[1].f64_data());
+        assert!(r32[0].is_nan());
+        for i in 1..3 {
+            assert_eq!(r32[i], r64[i] as f32);
+        }
+
+        let ranked = rank(&h, RankMethod::Average);
+        assert_eq!(ranked.columns[0].f32_data(), &[1.0, 2.0, 3.0]);
+        let shifted = add(&h, 1.0);
+        assert_eq!(shifted.columns[0].f32_data(), &[101.0, 111.0, 122.0]);
+        let w = wmean(&h, 2);
+        assert_eq!(w.columns[0].f32_data()[2], 115.5);
+        assert_eq!(sum(&h).f64_data(), &[331.0, 331.0]);
+        assert_eq!(
+            sum(&TableView::with_ori(table.clone(), ORI_R)).f64_data(),
+            &[662.0]
+        );
+
+        // Rowwise: the F32 cell takes part in each row's sequence
+        let z = TableView::with_ori(table, ORI_Z);
+        let out = demean(&z);
+        assert_eq!(out.columns[0].f32_data(), &[0.0, 0.0, 0.0]);
+        assert_eq!(out.columns[1].f64_data(), &[0.0, 0.0, 0.0]);
+        let out = dlog(&z);
+        assert!(out.columns[0].f32_data().iter().all(|x| x.is_nan()));
+        assert_eq!(out.columns[1].f64_data(), &[0.0, 0.0, 0.0]);
+    }
+
     #[test]
     fn test_dlog_with_nan() {
         let table = Table::new(
@@ -856,7 +1957,6 @@ mod tests {
     // ============ w5 tests ============
 
     #[test]
-    #[ignore] // TODO: implement wmean0 before enabling
     fn test_w5_colwise() {
         // Column with 7 values: [10, 20, 30, 40, 50, 60, 70]
         let table = Table::new(
@@ -945,7 +2045,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // TODO: implement wmean0 before enabling
     fn test_w5_with_nan() {
         // Test NaN handling: [10, NaN, 30, 40, 50, 60]
         let table = Table::new(
@@ -974,7 +2073,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // TODO: implement wmean0 before enabling
     fn test_w5_all_nan_window() {
         // Window with all NaN should produce NaN
         let table = Table::new(
@@ -1024,7 +2122,32 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // TODO: implement wmean0 before enabling
+    fn test_try_ops_undefined_orientations() {
+        let err = |op, orientation| OriError::Undefined { op, orientation };
+
+        let x = TableView::with_ori(make_test_table(), ORI_X);
+        let r = TableView::with_ori(make_test_table(), ORI_R);
+        assert_eq!(try_sum(&x).unwrap_err(), err("sum", ORI_X));
+        assert_eq!(try_dlog(&r).unwrap_err(), err("dlog", ORI_R));
+        assert_eq!(try_dlog(&x).unwrap_err(), err("dlog", ORI_X));
+        assert_eq!(try_w5(&r).unwrap_err(), err("w5", ORI_R));
+        assert_eq!(try_w5(&x).unwrap_err(), err("w5", ORI_X));
+
+        let err = try_dlog(&x).unwrap_err();
+        assert_eq!(err.to_string(), "dlog not defined for Each (X) orientation");
+    }
+
+    #[test]
+    fn test_try_ops_match_panicking_ops() {
+        let h = TableView::with_ori(make_test_table(), ORI_H);
+        let r = TableView::with_ori(make_test_table(), ORI_R);
+        assert!(try_sum(&h).unwrap().approx_eq(&sum(&h), 0.0));
+        assert!(try_sum(&r).unwrap().approx_eq(&sum(&r), 0.0));
+        assert!(try_dlog(&h).unwrap().approx_eq(&dlog(&h), 0.0));
+        assert!(try_w5(&h).unwrap().approx_eq(&w5(&h), 0.0));
+    }
+
+    #[test]
     fn test_w5_preserves_temporal() {
         let table = Table::new(
             vec!["date".to_string(), "value".to_string()],
@@ -1052,7 +2175,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // TODO: implement wmean0 before enabling
     fn test_w5_short_sequence() {
         // Sequence with only 3 elements (< window size)
         let table = Table::new(
@@ -1083,4 +2205,292 @@ mod tests {
         assert_eq!(result.col_count(), 0);
         assert_eq!(result.row_count(), 0);
     }
+
+    // ============ wmean tests ============
+
+    fn make_ramp_column(n: usize) -> Vec<f64> {
+        (1..=n).map(|i| i as f64).collect()
+    }
+
+    #[test]
+    fn test_wmean_window3_colwise() {
+        let table = Table::new(
+            vec!["a".to_string()],
+            vec![Column::F64(make_ramp_column(6))],
+        );
+
+        let result = wmean(&TableView::with_ori(table, ORI_H), 3);
+        let data = result.columns[0].f64_data();
+
+        assert!(data[0].is_nan());
+        assert!(data[1].is_nan());
+        assert_eq!(&data[2..], &[2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_wmean_window10_colwise() {
+        let table = Table::new(
+            vec!["a".to_string()],
+            vec![Column::F64(make_ramp_column(12))],
+        );
+
+        let result = wmean(&TableView::with_ori(table, ORI_H), 10);
+        let data = result.columns[0].f64_data();
+
+        assert!(data[..9].iter().all(|x| x.is_nan()));
+        assert_eq!(data[9], 5.5); // mean(1..=10)
+        assert_eq!(data[10], 6.5);
+        assert_eq!(data[11], 7.5);
+    }
+
+    #[test]
+    fn test_wmean_window3_and_10_rowwise() {
+        // One row laid out across 12 columns: [1, 2, ..., 12]
+        let names = (0..12).map(|j| format!("c{}", j)).collect();
+        let columns = (1..=12).map(|v| Column::F64(vec![v as f64])).collect();
+        let table = Table::new(names, columns);
+        let view = TableView::with_ori(table, ORI_Z);
+
+        let r3 = wmean(&view, 3);
+        assert!(r3.columns[1].f64_data()[0].is_nan());
+        assert_eq!(r3.columns[2].f64_data()[0], 2.0);
+        assert_eq!(r3.columns[11].f64_data()[0], 11.0);
+
+        let r10 = wmean(&view, 10);
+        assert!(r10.columns[8].f64_data()[0].is_nan());
+        assert_eq!(r10.columns[9].f64_data()[0], 5.5);
+        assert_eq!(r10.columns[11].f64_data()[0], 7.5);
+    }
+
+    #[test]
+    fn test_wmean_window_0_and_1() {
+        let table = Table::new(
+            vec!["a".to_string()],
+            vec![Column::F64(vec![1.0, f64::NAN, 3.0])],
+        );
+        let view = TableView::with_ori(table, ORI_H);
+
+        // window=1: identity with NaN passthrough
+        let r1 = wmean(&view, 1);
+        let d = r1.columns[0].f64_data();
+        assert_eq!(d[0], 1.0);
+        assert!(d[1].is_nan());
+        assert_eq!(d[2], 3.0);
+
+        // window=0: all NaN
+        let r0 = wmean(&view, 0);
+        assert!(r0.columns[0].f64_data().iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_w5_matches_wmean5() {
+        let table = Table::new(
+            vec!["a".to_string()],
+            vec![Column::F64(make_ramp_column(8))],
+        );
+        let view = TableView::with_ori(table, ORI_H);
+
+        let a = w5(&view);
+        let b = wmean(&view, 5);
+        assert_eq!(a.columns[0].f64_data()[4..], b.columns[0].f64_data()[4..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "wmean not defined for Real")]
+    fn test_wmean_real_panics() {
+        let table = make_test_table();
+        wmean(&TableView::with_ori(table, ORI_R), 3);
+    }
+
+    #[test]
+    fn test_add_each_transforms_every_cell() {
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string(), "d".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 3.0]),
+                Column::F64(vec![4.0, 5.0, 6.0]),
+                Column::Date(vec![1, 2, 3]),
+            ],
+        );
+        let view = TableView::with_ori(table, ORI_X);
+
+        let result = add(&view, 5.0);
+        let a = result.columns[0].f64_data();
+        assert_eq!(a[0], 6.0);
+        assert!(a[1].is_nan());
+        assert_eq!(a[2], 8.0);
+        assert_eq!(result.columns[1].f64_data(), &[9.0, 10.0, 11.0]);
+        assert_eq!(result.columns[2].date_data(), &[1, 2, 3]);
+
+        let result = mul(&view, 2.0);
+        assert_eq!(result.columns[1].f64_data(), &[8.0, 10.0, 12.0]);
+    }
+
+    #[test]
+    fn test_elementwise_orientation_independent() {
+        let table = make_test_table();
+        for ori in [ORI_H, ORI_Z, ORI_X, ORI_R] {
+            let view = TableView::with_ori(table.clone(), ori);
+            let shifted = TableView::with_ori(sub(&view, 1.0), ori);
+            let result = div(&shifted, 2.0);
+            assert_eq!(result.columns[0].f64_data(), &[0.0, 0.5, 1.0]);
+            assert_eq!(result.columns[1].f64_data(), &[1.5, 2.0, 2.5]);
+        }
+    }
+
+    fn make_rank_table() -> Table {
+        // 3x3 table with a tie in row 0 and a NaN in row 1:
+        // row 0: [2, 2, 1]
+        // row 1: [NaN, 3, 5]
+        // row 2: [4, 1, 1]
+        Table::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vec![
+                Column::F64(vec![2.0, f64::NAN, 4.0]),
+                Column::F64(vec![2.0, 3.0, 1.0]),
+                Column::F64(vec![1.0, 5.0, 1.0]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_rank_rowwise_cross_section() {
+        let view = TableView::with_ori(make_rank_table(), ORI_Z);
+        let result = rank(&view, RankMethod::Average);
+
+        assert_eq!((result.row_count(), result.col_count()), (3, 3));
+        let nan = f64::NAN;
+        assert_f64_close(result.columns[0].f64_data(), &[2.5, nan, 3.0], 0.0);
+        assert_f64_close(result.columns[1].f64_data(), &[2.5, 1.0, 1.5], 0.0);
+        assert_f64_close(result.columns[2].f64_data(), &[1.0, 2.0, 1.5], 0.0);
+
+        let dense = rank(&view, RankMethod::Dense);
+        assert_f64_close(dense.columns[0].f64_data(), &[2.0, nan, 2.0], 0.0);
+    }
+
+    #[test]
+    fn test_rank_colwise_time_series() {
+        let view = TableView::with_ori(make_rank_table(), ORI_H);
+        let result = rank(&view, RankMethod::Ordinal);
+
+        assert_eq!((result.row_count(), result.col_count()), (3, 3));
+        let nan = f64::NAN;
+        assert_f64_close(result.columns[0].f64_data(), &[1.0, nan, 2.0], 0.0);
+        assert_f64_close(result.columns[1].f64_data(), &[2.0, 3.0, 1.0], 0.0);
+        assert_f64_close(result.columns[2].f64_data(), &[1.0, 3.0, 2.0], 0.0);
+    }
+
+    #[test]
+    fn test_rank_rowwise_preserves_temporal() {
+        let table = Table::new(
+            vec!["date".to_string(), "x".to_string(), "y".to_string()],
+            vec![
+                Column::Date(vec![1, 2]),
+                Column::F64(vec![9.0, 1.0]),
+                Column::F64(vec![3.0, 2.0]),
+            ],
+        );
+        let result = rank(&TableView::with_ori(table, ORI_Z), RankMethod::Ordinal);
+        assert_eq!(result.columns[0].date_data(), &[1, 2]);
+        assert_eq!(result.columns[1].f64_data(), &[2.0, 1.0]);
+        assert_eq!(result.columns[2].f64_data(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rank not defined for Real")]
+    fn test_rank_real_panics() {
+        rank(
+            &TableView::with_ori(make_test_table(), ORI_R),
+            RankMethod::Dense,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rank not defined for Each")]
+    fn test_rank_each_panics() {
+        rank(
+            &TableView::with_ori(make_test_table(), ORI_X),
+            RankMethod::Dense,
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_sum_rowwise_parallel_matches_serial() {
+        let nrows = 300;
+        let cols: Vec<Vec<f64>> = (0..20)
+            .map(|j| {
+                (0..nrows)
+                    .map(|i| {
+                        if (i + j) % 17 == 0 {
+                            f64::NAN
+                        } else {
+                            (i * 31 + j * 7) as f64 * 0.01
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let col_refs: Vec<&[f64]> = cols.iter().map(|c| c.as_slice()).collect();
+
+        let mut serial = vec![0.0; nrows];
+        let mut parallel = vec![0.0; nrows];
+        sum_tiles_serial(&col_refs, &mut serial);
+        sum_tiles_parallel(&col_refs, &mut parallel);
+
+        for (a, b) in serial.iter().zip(&parallel) {
+            assert_eq!(a.to_bits(), b.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_demean_rowwise_cross_section() {
+        let nan = f64::NAN;
+        // Rows: [1, 2, 3] / all-equal [4, 4, 4] / [2, NaN, 6]
+        let table = Table::new(
+            vec![
+                "d".to_string(),
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+            ],
+            vec![
+                Column::Date(vec![1, 2, 3]),
+                Column::F64(vec![1.0, 4.0, 2.0]),
+                Column::F64(vec![2.0, 4.0, nan]),
+                Column::F64(vec![3.0, 4.0, 6.0]),
+            ],
+        );
+        let out = demean(&TableView::with_ori(table, ORI_Z));
+
+        assert_eq!(out.columns[0].date_data(), &[1, 2, 3]);
+        assert_eq!(out.columns[1].f64_data(), &[-1.0, 0.0, -2.0]);
+        let b = out.columns[2].f64_data();
+        assert_eq!(&b[..2], &[0.0, 0.0]);
+        assert!(b[2].is_nan());
+        assert_eq!(out.columns[3].f64_data(), &[1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_demean_colwise() {
+        let table = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 5.0]),
+                Column::F64(vec![7.0, 7.0, 7.0]),
+            ],
+        );
+        let out = demean(&TableView::with_ori(table, ORI_H));
+
+        let a = out.columns[0].f64_data();
+        assert_eq!((a[0], a[2]), (-2.0, 2.0));
+        assert!(a[1].is_nan());
+        assert_eq!(out.columns[1].f64_data(), &[0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "demean not defined for Real")]
+    fn test_demean_real_panics() {
+        demean(&TableView::with_ori(make_test_table(), ORI_R));
+    }
 }
@@ -0,0 +1,99 @@
+//! Ranking kernels
+//!
+//! Ranks are 1-based and returned as F64. NaN inputs are excluded from the
+//! ranking and map to NaN ranks.
+
+use crate::table::Column;
+
+/// Tie-breaking rule for `rank_column`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankMethod {
+    /// Distinct ranks 1..=n; ties ordered by position (first seen ranks lower)
+    Ordinal,
+    /// Ties share a rank; next distinct value gets the next integer
+    Dense,
+    /// Ties share the mean of their ordinal ranks
+    Average,
+}
+
+/// Rank an F64 column in ascending order
+///
+/// Example with x = [3, 1, 3, NaN, 2]:
+/// - Ordinal: [3, 1, 4, NaN, 2]
+/// - Dense:   [3, 1, 3, NaN, 2]
+/// - Average: [3.5, 1, 3.5, NaN, 2]
+pub fn rank_column(x: &Column, method: RankMethod) -> Column {
+    let Ok(data) = x.as_f64_slice() else {
+        panic!("rank_column: expected F64 column");
+    };
+
+    Column::F64(rank_slice(data, method))
+}
+
+/// Rank a slice (see `rank_column`)
+pub(crate) fn rank_slice(data: &[f64], method: RankMethod) -> Vec<f64> {
+    let mut out = vec![f64::NAN; data.len()];
+
+    // Stable sort keeps position order within ties (Ordinal tie-break)
+    let mut order: Vec<usize> = (0..data.len()).filter(|&i| !data[i].is_nan()).collect();
+    order.sort_by(|&a, &b| data[a].total_cmp(&data[b]));
+
+    let mut dense = 0.0;
+    let mut start = 0;
+    while start < order.len() {
+        // [start, end) is a run of tied values
+        let value = data[order[start]];
+        let mut end = start + 1;
+        while end < order.len() && data[order[end]] == value {
+            end += 1;
+        }
+        dense += 1.0;
+
+        for (k, &idx) in order[start..end].iter().enumerate() {
+            out[idx] = match method {
+                RankMethod::Ordinal => (start + k + 1) as f64,
+                RankMethod::Dense => dense,
+                RankMethod::Average => (start + 1 + end) as f64 / 2.0,
+            };
+        }
+        start = end;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtins::test_util::assert_f64_close;
+
+    fn ranks(x: &[f64], method: RankMethod) -> Vec<f64> {
+        rank_column(&Column::F64(x.to_vec()), method)
+            .f64_data()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_rank_ties_all_methods() {
+        let x = [3.0, 1.0, 3.0, 2.0, 3.0];
+        let check = |m, want: &[f64]| assert_f64_close(&ranks(&x, m), want, 0.0);
+        check(RankMethod::Ordinal, &[3.0, 1.0, 4.0, 2.0, 5.0]);
+        check(RankMethod::Dense, &[3.0, 1.0, 3.0, 2.0, 3.0]);
+        check(RankMethod::Average, &[4.0, 1.0, 4.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_rank_excludes_nan() {
+        let nan = f64::NAN;
+        let x = [nan, 5.0, -1.0, nan, 5.0];
+        let check = |m, want: &[f64]| assert_f64_close(&ranks(&x, m), want, 0.0);
+        check(RankMethod::Ordinal, &[nan, 2.0, 1.0, nan, 3.0]);
+        check(RankMethod::Dense, &[nan, 2.0, 1.0, nan, 2.0]);
+        check(RankMethod::Average, &[nan, 2.5, 1.0, nan, 2.5]);
+
+        assert!(ranks(&[nan, nan], RankMethod::Dense)
+            .iter()
+            .all(|r| r.is_nan()));
+        assert!(ranks(&[], RankMethod::Average).is_empty());
+    }
+}
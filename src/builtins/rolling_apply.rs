@@ -0,0 +1,90 @@
+//! Ft-measurable rolling apply with a caller-supplied window function
+//!
+//! Escape hatch for window statistics without a dedicated kernel: the
+//! closure sees each past-only window [i-window, i-1] and returns one value.
+//! Cost is O(n * window) plus whatever the closure does, so prefer
+//! `rolling_moments` / `rolling_quantile` when they cover the statistic.
+
+/// Rolling apply of `f` over the valid values of each past-only window
+///
+/// # Arguments
+/// * `x` - Input data
+/// * `window` - Window size
+/// * `min_periods` - Minimum valid observations required (default: window)
+/// * `f` - Window function
+///
+/// # Returns
+/// `f(values)` where `values` holds the non-NaN entries of [i-window, i-1]
+/// in their original order. NaN where the window is incomplete or has
+/// fewer than `min_periods` valid observations (`f` is not called there).
+///
+/// The slice is a dense copy in a reused scratch buffer, not the raw window:
+/// `f` never sees NaN and `values.len()` is the valid count.
+pub fn rolling_apply_past_only<F>(
+    x: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+    f: F,
+) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let n = x.len();
+    let min_periods = min_periods.unwrap_or(window);
+    let mut out = vec![f64::NAN; n];
+
+    if window == 0 {
+        return out;
+    }
+
+    let mut scratch = Vec::with_capacity(window);
+    for i in window..n {
+        scratch.clear();
+        scratch.extend(x[i - window..i].iter().copied().filter(|v| !v.is_nan()));
+        if !scratch.is_empty() && scratch.len() >= min_periods {
+            out[i] = f(&scratch);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mean after dropping the smallest and largest value
+    fn trimmed_mean(values: &[f64]) -> f64 {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let inner = &sorted[1..sorted.len() - 1];
+        inner.iter().sum::<f64>() / inner.len() as f64
+    }
+
+    #[test]
+    fn test_rolling_trimmed_mean_matches_manual() {
+        let x = vec![4.0, 100.0, 2.0, 3.0, f64::NAN, -50.0, 6.0, 5.0];
+        let out = rolling_apply_past_only(&x, 4, Some(3), trimmed_mean);
+
+        assert!(out[..4].iter().all(|v| v.is_nan()));
+        assert_eq!(out[4], (3.0 + 4.0) / 2.0); // [4, 100, 2, 3]
+        assert_eq!(out[5], 3.0); // [100, 2, 3] (NaN dropped)
+        assert_eq!(out[6], 2.0); // [2, 3, -50]
+        assert_eq!(out[7], 3.0); // [3, -50, 6]
+    }
+
+    #[test]
+    fn test_rolling_apply_never_sees_nan() {
+        let x = vec![1.0, f64::NAN, f64::NAN, 2.0, f64::NAN];
+        let out = rolling_apply_past_only(&x, 3, Some(1), |w| {
+            assert!(w.iter().all(|v| !v.is_nan()));
+            w.len() as f64
+        });
+
+        assert_eq!(&out[3..], &[1.0, 1.0]);
+
+        // Default min_periods = window: every window has a NaN
+        let out = rolling_apply_past_only(&x, 3, None, |w| w.len() as f64);
+        assert!(out.iter().all(|v| v.is_nan()));
+    }
+}
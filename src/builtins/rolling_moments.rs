@@ -7,7 +7,10 @@
 #![allow(clippy::collapsible_if)]
 #![allow(clippy::manual_unwrap_or_default)]
 
+use super::ori_ops::float_values;
 use crate::table::bitmap::Bitmap;
+use crate::table::Table;
+use std::collections::VecDeque;
 
 /// Bitmask for selecting which moments to compute
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -165,103 +168,161 @@ pub fn rolling_moments_past_only_f64(
     output
 }
 
-/// Fast path: all values valid
-fn rolling_moments_all_valid(
-    x: &[f64],
+/// Rolling moments for every column of a table
+///
+/// # Layout
+/// One `RollingMomentsOutput` per column, in table order, so `out[k]`
+/// belongs to `table.columns[k]` and each requested moment vector has
+/// `table.row_count()` entries. Float columns (F64, F32, F64Mmap) are
+/// computed, F32 widened to f64 first. Other types (Date, I64, Bool, ...)
+/// have no moments and get all-NaN outputs to keep that alignment.
+///
+/// Each column runs through `rolling_moments_past_only_f64` (no validity
+/// bitmap; NaN marks missing), so results equal per-column calls.
+pub fn rolling_moments_table(
+    table: &Table,
     window: usize,
-    min_periods: usize,
+    min_periods: Option<usize>,
     mask: MomentsMask,
-    max_moment: u8,
-    output: &mut RollingMomentsOutput,
-) {
-    let n = x.len();
-
-    for i in 0..n {
-        // For position i, window is [i-window, i-1]
-        // Need i >= window to have a full past window
-        if i < window {
-            continue;
-        }
-
-        let start = i - window;
-        let end = i; // Exclusive, so [start..end) = [i-window, i-1]
+) -> Vec<RollingMomentsOutput> {
+    table
+        .columns
+        .iter()
+        .map(|col| match float_values(col) {
+            Some(data) => rolling_moments_past_only_f64(&data, window, min_periods, mask, None),
+            None => RollingMomentsOutput::new(col.len(), mask),
+        })
+        .collect()
+}
 
-        // Compute raw sums over past window
-        let mut s1 = 0.0;
-        let mut s2 = 0.0;
-        let mut s3 = 0.0;
-        let mut s4 = 0.0;
-        let mut count = 0;
+/// Raw power sums S1..S4 and count over the valid values of a window
+///
+/// `peak` is the largest |value| added since the sums were built (values
+/// that left the window included); it bounds their rounding error.
+#[derive(Clone, Copy, Debug, Default)]
+struct RawSums {
+    s1: f64,
+    s2: f64,
+    s3: f64,
+    s4: f64,
+    count: usize,
+    peak: f64,
+}
 
-        for j in start..end {
-            let val = x[j];
+impl RawSums {
+    /// Sums over the non-NaN values of `window` (from scratch)
+    fn over(window: &[f64], max_moment: u8) -> Self {
+        let mut sums = RawSums::default();
+        for &val in window {
             if !val.is_nan() {
-                s1 += val;
-                if max_moment >= 2 {
-                    s2 += val * val;
-                }
-                if max_moment >= 3 {
-                    s3 += val * val * val;
-                }
-                if max_moment >= 4 {
-                    s4 += val * val * val * val;
-                }
-                count += 1;
+                sums.add(val, max_moment);
             }
         }
+        sums
+    }
 
-        // Compute moments for position i using window [i-window, i-1]
-        if i >= window && count >= min_periods {
-            let nc = count as f64;
+    #[inline]
+    fn add(&mut self, val: f64, max_moment: u8) {
+        self.s1 += val;
+        if max_moment >= 2 {
+            self.s2 += val * val;
+        }
+        if max_moment >= 3 {
+            self.s3 += val * val * val;
+        }
+        if max_moment >= 4 {
+            self.s4 += val * val * val * val;
+        }
+        self.count += 1;
+        self.peak = self.peak.max(val.abs());
+    }
 
-            // Mean
-            let mean = s1 / nc;
-            if let Some(ref mut mean_vec) = output.mean {
-                mean_vec[i] = mean;
-            }
+    #[inline]
+    fn sub(&mut self, val: f64, max_moment: u8) {
+        self.s1 -= val;
+        if max_moment >= 2 {
+            self.s2 -= val * val;
+        }
+        if max_moment >= 3 {
+            self.s3 -= val * val * val;
+        }
+        if max_moment >= 4 {
+            self.s4 -= val * val * val * val;
+        }
+        self.count -= 1;
+    }
 
-            // Count
-            if let Some(ref mut count_vec) = output.count {
-                count_vec[i] = nc;
-            }
+    /// Write the requested moments at position `i` (if count >= min_periods)
+    fn write(
+        &self,
+        i: usize,
+        min_periods: usize,
+        mask: MomentsMask,
+        output: &mut RollingMomentsOutput,
+    ) {
+        let RawSums {
+            s1,
+            s2,
+            s3,
+            s4,
+            count,
+            peak,
+        } = *self;
+        if count < min_periods {
+            return;
+        }
+        let nc = count as f64;
 
-            // Variance and standard deviation
-            if mask.has(MomentsMask::STD)
-                || mask.has(MomentsMask::SKEW)
-                || mask.has(MomentsMask::KURT)
-            {
-                if count >= 2 {
-                    // Sample variance (ddof=1)
-                    let var = (s2 - s1 * s1 / nc) / (nc - 1.0);
-                    let var = var.max(0.0); // Clamp tiny negative values to 0
-
-                    if let Some(ref mut std_vec) = output.std {
-                        std_vec[i] = var.sqrt();
-                    }
+        // Mean
+        let mean = s1 / nc;
+        if let Some(ref mut mean_vec) = output.mean {
+            mean_vec[i] = mean;
+        }
+
+        // Count
+        if let Some(ref mut count_vec) = output.count {
+            count_vec[i] = nc;
+        }
 
-                    // Higher moments require more data and non-zero variance
-                    if var > 1e-14 {
-                        // Population variance for central moments
-                        let mu2 = var * (nc - 1.0) / nc;
-
-                        // Skewness
-                        if mask.has(MomentsMask::SKEW) && count >= 3 {
-                            let mu3 = (s3 - 3.0 * mean * s2 + 2.0 * mean * mean * mean * nc) / nc;
-                            let skew = mu3 / mu2.powf(1.5);
-                            if let Some(ref mut skew_vec) = output.skew {
-                                skew_vec[i] = skew;
-                            }
+        // Variance and standard deviation
+        if mask.has(MomentsMask::STD) || mask.has(MomentsMask::SKEW) || mask.has(MomentsMask::KURT)
+        {
+            if count >= 2 {
+                // Sample variance (ddof=1)
+                let var = (s2 - s1 * s1 / nc) / (nc - 1.0);
+                let var = var.max(0.0); // Clamp tiny negative values to 0
+
+                // Within the rounding error of S1/S2 the window is constant;
+                // report exactly 0 rather than amplified noise
+                let noise = 4.0 * nc * f64::EPSILON * peak * peak;
+                let var = if var <= noise { 0.0 } else { var };
+
+                if let Some(ref mut std_vec) = output.std {
+                    std_vec[i] = var.sqrt();
+                }
+
+                // Higher moments require more data and non-zero variance
+                if var > 1e-14 {
+                    // Population variance for central moments
+                    let mu2 = var * (nc - 1.0) / nc;
+
+                    // Skewness
+                    if mask.has(MomentsMask::SKEW) && count >= 3 {
+                        let mu3 = (s3 - 3.0 * mean * s2 + 2.0 * mean * mean * mean * nc) / nc;
+                        let skew = mu3 / mu2.powf(1.5);
+                        if let Some(ref mut skew_vec) = output.skew {
+                            skew_vec[i] = skew;
                         }
+                    }
 
-                        // Kurtosis (excess)
-                        if mask.has(MomentsMask::KURT) && count >= 4 {
-                            let mu4 = (s4 - 4.0 * mean * s3 + 6.0 * mean * mean * s2
-                                - 3.0 * mean * mean * mean * mean * nc)
-                                / nc;
-                            let kurt = mu4 / (mu2 * mu2) - 3.0; // Excess kurtosis
-                            if let Some(ref mut kurt_vec) = output.kurt {
-                                kurt_vec[i] = kurt;
-                            }
+                    // Kurtosis (excess)
+                    if mask.has(MomentsMask::KURT) && count >= 4 {
+                        let mu4 = (s4 - 4.0 * mean * s3 + 6.0 * mean * mean * s2
+                            - 3.0 * mean * mean * mean * mean * nc)
+                            / nc;
+                        let kurt = mu4 / (mu2 * mu2) - 3.0; // Excess kurtosis
+                        if let Some(ref mut kurt_vec) = output.kurt {
+                            kurt_vec[i] = kurt;
                         }
                     }
                 }
@@ -270,6 +331,74 @@ fn rolling_moments_all_valid(
     }
 }
 
+/// Fast path: all values valid (NaN still marks missing)
+///
+/// Incremental: the entering value is added to S1..S4 and the leaving value
+/// subtracted, so the kernel is O(n) regardless of window. NaN never enters
+/// the sums, so it needs no special handling. The sums are rebuilt from the
+/// window when subtraction cannot undo an addition (a leaving ±inf would
+/// leave NaN behind) and every `window` positions to bound rounding drift,
+/// which keeps the amortized cost O(n). Between rebuilds the drift is at
+/// most a few ulps of the largest value seen, which `RawSums::write`
+/// treats as zero variance, so a window that turns constant reads as
+/// constant.
+fn rolling_moments_all_valid(
+    x: &[f64],
+    window: usize,
+    min_periods: usize,
+    mask: MomentsMask,
+    max_moment: u8,
+    output: &mut RollingMomentsOutput,
+) {
+    let n = x.len();
+    let mut sums = RawSums::default();
+    let mut dirty = false;
+    let mut since_resync = 0;
+
+    for i in 0..n {
+        // Window for position i is [i-window, i-1]
+        if i > window {
+            let leaving = x[i - window - 1];
+            if leaving.is_infinite() {
+                dirty = true;
+            } else if !leaving.is_nan() {
+                sums.sub(leaving, max_moment);
+            }
+        }
+
+        if i >= window {
+            if dirty || since_resync == window {
+                sums = RawSums::over(&x[i - window..i], max_moment);
+                dirty = false;
+                since_resync = 0;
+            }
+            since_resync += 1;
+            sums.write(i, min_periods, mask, output);
+        }
+
+        if !x[i].is_nan() {
+            sums.add(x[i], max_moment);
+        }
+    }
+}
+
+/// Reference O(n·window) path: recompute S1..S4 over every window
+///
+/// Tests check `rolling_moments_all_valid` against it.
+#[cfg(test)]
+fn rolling_moments_all_valid_recompute(
+    x: &[f64],
+    window: usize,
+    min_periods: usize,
+    mask: MomentsMask,
+    max_moment: u8,
+    output: &mut RollingMomentsOutput,
+) {
+    for i in window..x.len() {
+        RawSums::over(&x[i - window..i], max_moment).write(i, min_periods, mask, output);
+    }
+}
+
 /// Path with validity bitmap
 fn rolling_moments_with_validity(
     x: &[f64],
@@ -287,88 +416,208 @@ fn rolling_moments_with_validity(
             continue;
         }
 
-        let start = i - window;
-        let end = i;
-
-        // Compute raw sums over past window
-        let mut s1 = 0.0;
-        let mut s2 = 0.0;
-        let mut s3 = 0.0;
-        let mut s4 = 0.0;
-        let mut count = 0;
-
-        for j in start..end {
+        // Compute raw sums over past window [i-window, i-1]
+        let mut sums = RawSums::default();
+        for j in i - window..i {
             if validity.get(j) && !x[j].is_nan() {
-                let val = x[j];
-                s1 += val;
-                if max_moment >= 2 {
-                    s2 += val * val;
-                }
-                if max_moment >= 3 {
-                    s3 += val * val * val;
-                }
-                if max_moment >= 4 {
-                    s4 += val * val * val * val;
-                }
-                count += 1;
+                sums.add(x[j], max_moment);
             }
         }
 
-        // Compute moments
-        if count >= min_periods {
-            let nc = count as f64;
-            let mean = s1 / nc;
+        sums.write(i, min_periods, mask, output);
+    }
+}
 
-            if let Some(ref mut mean_vec) = output.mean {
-                mean_vec[i] = mean;
+/// Single-pass Ft-measurable rolling min/max kernel
+///
+/// Computes rolling minimum and maximum over the past-only window [i-window, i-1],
+/// matching the window semantics of `rolling_moments_past_only_f64`.
+///
+/// # Arguments
+/// * `x` - Input data
+/// * `window` - Window size
+/// * `min_periods` - Minimum valid observations required (default: window)
+/// * `validity` - Optional validity bitmap (None = all valid)
+///
+/// # Returns
+/// (rolling_min, rolling_max), NaN where the window is incomplete or has
+/// fewer than `min_periods` valid observations.
+///
+/// # Algorithm
+/// Monotonic deques of indices (increasing values for min, decreasing for max).
+/// Each index is pushed and popped at most once, so the kernel is O(n)
+/// regardless of window size. NaN/invalid values are never pushed.
+pub fn rolling_minmax_past_only_f64(
+    x: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+    validity: Option<&Bitmap>,
+) -> (Vec<f64>, Vec<f64>) {
+    let n = x.len();
+    let min_periods = min_periods.unwrap_or(window);
+
+    let mut out_min = vec![f64::NAN; n];
+    let mut out_max = vec![f64::NAN; n];
+
+    if window == 0 {
+        return (out_min, out_max);
+    }
+
+    let is_valid = |j: usize| !x[j].is_nan() && validity.is_none_or(|v| v.get(j));
+
+    let mut min_q: VecDeque<usize> = VecDeque::with_capacity(window);
+    let mut max_q: VecDeque<usize> = VecDeque::with_capacity(window);
+    let mut count = 0usize;
+
+    for i in 0..n {
+        // Window for position i is [i-window, i-1]
+        if i > window {
+            let leaving = i - window - 1;
+            if is_valid(leaving) {
+                count -= 1;
+            }
+        }
+        if i >= window {
+            let start = i - window;
+            while min_q.front().is_some_and(|&j| j < start) {
+                min_q.pop_front();
+            }
+            while max_q.front().is_some_and(|&j| j < start) {
+                max_q.pop_front();
+            }
+
+            if count >= min_periods {
+                if let (Some(&jmin), Some(&jmax)) = (min_q.front(), max_q.front()) {
+                    out_min[i] = x[jmin];
+                    out_max[i] = x[jmax];
+                }
             }
+        }
 
-            if let Some(ref mut count_vec) = output.count {
-                count_vec[i] = nc;
+        // Admit x[i] so it is visible to positions i+1..=i+window
+        if is_valid(i) {
+            let val = x[i];
+            while min_q.back().is_some_and(|&j| x[j] >= val) {
+                min_q.pop_back();
             }
+            min_q.push_back(i);
+            while max_q.back().is_some_and(|&j| x[j] <= val) {
+                max_q.pop_back();
+            }
+            max_q.push_back(i);
+            count += 1;
+        }
+    }
 
-            if mask.has(MomentsMask::STD)
-                || mask.has(MomentsMask::SKEW)
-                || mask.has(MomentsMask::KURT)
-            {
-                if count >= 2 {
-                    let var = (s2 - s1 * s1 / nc) / (nc - 1.0);
-                    let var = var.max(0.0);
+    (out_min, out_max)
+}
 
-                    if let Some(ref mut std_vec) = output.std {
-                        std_vec[i] = var.sqrt();
-                    }
+/// Single-pass Ft-measurable rolling sum kernel
+///
+/// Computes the sum over the past-only window [i-window, i-1] with arbitrary
+/// window size (e.g. volume accumulation).
+///
+/// # Arguments
+/// * `x` - Input data
+/// * `window` - Window size
+/// * `min_periods` - Minimum valid observations required (default: window)
+/// * `validity` - Optional validity bitmap (None = all valid)
+///
+/// # Algorithm
+/// Incremental add/subtract: the value entering the window is added and the
+/// value leaving is subtracted, so the kernel is O(n). A parallel valid-count
+/// is maintained so NaN/invalid values never touch the running sum.
+pub fn rolling_sum_past_only_f64(
+    x: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+    validity: Option<&Bitmap>,
+) -> Vec<f64> {
+    let n = x.len();
+    let min_periods = min_periods.unwrap_or(window);
+    let mut out = vec![f64::NAN; n];
 
-                    if var > 1e-14 {
-                        let mu2 = var * (nc - 1.0) / nc;
+    if window == 0 {
+        return out;
+    }
 
-                        if mask.has(MomentsMask::SKEW) && count >= 3 {
-                            let mu3 = (s3 - 3.0 * mean * s2 + 2.0 * mean * mean * mean * nc) / nc;
-                            let skew = mu3 / mu2.powf(1.5);
-                            if let Some(ref mut skew_vec) = output.skew {
-                                skew_vec[i] = skew;
-                            }
-                        }
+    let is_valid = |j: usize| !x[j].is_nan() && validity.is_none_or(|v| v.get(j));
 
-                        if mask.has(MomentsMask::KURT) && count >= 4 {
-                            let mu4 = (s4 - 4.0 * mean * s3 + 6.0 * mean * mean * s2
-                                - 3.0 * mean * mean * mean * mean * nc)
-                                / nc;
-                            let kurt = mu4 / (mu2 * mu2) - 3.0;
-                            if let Some(ref mut kurt_vec) = output.kurt {
-                                kurt_vec[i] = kurt;
-                            }
-                        }
-                    }
-                }
+    let mut sum = 0.0;
+    let mut count = 0usize;
+
+    for i in 0..n {
+        // Window for position i is [i-window, i-1]
+        if i > window {
+            let leaving = i - window - 1;
+            if is_valid(leaving) {
+                sum -= x[leaving];
+                count -= 1;
             }
         }
+        if i >= window && count >= min_periods && count > 0 {
+            out[i] = sum;
+        }
+
+        if is_valid(i) {
+            sum += x[i];
+            count += 1;
+        }
     }
+
+    out
+}
+
+/// Ft-measurable rolling z-score
+///
+/// Standardizes each point against its past-only window [i-window, i-1]:
+/// `z[i] = (x[i] - mean) / std` with mean/std (ddof=1) from
+/// `rolling_moments_past_only_f64`, so x[i] itself never enters its own
+/// statistics.
+///
+/// # Arguments
+/// * `x` - Input data
+/// * `window` - Window size
+/// * `min_periods` - Minimum valid observations required (default: window)
+/// * `validity` - Optional validity bitmap (None = all valid)
+/// * `zero_std` - Output where the window std is exactly 0 (constant
+///   window), typically `0.0` or `f64::NAN`
+///
+/// # Returns
+/// NaN where x[i] is NaN/invalid or the window statistics are undefined.
+pub fn rolling_zscore_past_only_f64(
+    x: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+    validity: Option<&Bitmap>,
+    zero_std: f64,
+) -> Vec<f64> {
+    let mask = MomentsMask::new(MomentsMask::MEAN | MomentsMask::STD);
+    let moments = rolling_moments_past_only_f64(x, window, min_periods, mask, validity);
+    let (Some(mean), Some(std)) = (moments.mean, moments.std) else {
+        unreachable!("mean and std requested");
+    };
+
+    let mut out = vec![f64::NAN; x.len()];
+    for i in 0..x.len() {
+        if x[i].is_nan() || validity.is_some_and(|v| !v.get(i)) || std[i].is_nan() {
+            continue;
+        }
+        out[i] = if std[i] == 0.0 {
+            zero_std
+        } else {
+            (x[i] - mean[i]) / std[i]
+        };
+    }
+
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::builtins::test_util::assert_f64_close;
+    use crate::table::Column;
 
     #[test]
     fn test_past_only_window() {
@@ -520,4 +769,322 @@ mod tests {
         // Let me check position 4 only
         assert!((means[4] - 2.5).abs() < 1e-10); // mean([1,2,3,4]) = 2.5
     }
+
+    #[test]
+    fn test_moments_table_matches_per_column() {
+        let a: Vec<f64> = (0..40).map(|i| ((i * 7) % 13) as f64).collect();
+        let mut b: Vec<f64> = (0..40).map(|i| (i as f64).sin() * 3.0).collect();
+        b[5] = f64::NAN;
+        b[17] = f64::NAN;
+        let table = Table::new(
+            vec![
+                "a".to_string(),
+                "d".to_string(),
+                "b".to_string(),
+                "f".to_string(),
+            ],
+            vec![
+                Column::F64(a.clone()),
+                Column::Date((0..40).collect()),
+                Column::F64(b.clone()),
+                Column::F32(b.iter().map(|&x| x as f32).collect()),
+            ],
+        );
+
+        let mask = MomentsMask::all();
+        let out = rolling_moments_table(&table, 6, Some(4), mask);
+        assert_eq!(out.len(), 4);
+
+        let same = |x: &Option<Vec<f64>>, y: &Option<Vec<f64>>| {
+            assert_f64_close(x.as_ref().unwrap(), y.as_ref().unwrap(), 0.0)
+        };
+        for (k, data) in [(0, &a), (2, &b)] {
+            let single = rolling_moments_past_only_f64(data, 6, Some(4), mask, None);
+            same(&out[k].mean, &single.mean);
+            same(&out[k].std, &single.std);
+            same(&out[k].skew, &single.skew);
+            same(&out[k].kurt, &single.kurt);
+            same(&out[k].count, &single.count);
+        }
+
+        // F32 column: computed on the widened values, not NaN-filled
+        let widened: Vec<f64> = b.iter().map(|&x| x as f32 as f64).collect();
+        let single = rolling_moments_past_only_f64(&widened, 6, Some(4), mask, None);
+        same(&out[3].mean, &single.mean);
+        same(&out[3].std, &single.std);
+        assert!(out[3].mean.as_ref().unwrap()[10].is_finite());
+
+        // Date column: aligned, all NaN
+        let date_mean = out[1].mean.as_ref().unwrap();
+        assert_eq!(date_mean.len(), 40);
+        assert!(date_mean.iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_incremental_matches_recompute() {
+        // LCG noise around a level, with NaN gaps and an inf
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut data: Vec<f64> = (0..3000)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                2.0 + ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 4.0
+            })
+            .collect();
+        for i in (0..data.len()).step_by(17) {
+            data[i] = f64::NAN;
+        }
+        data[1000] = f64::INFINITY;
+
+        // Volatile then constant: drift must not read as variance
+        let mut flat_tail = vec![1.3, 2.7, 0.1, 9.9, 0.3];
+        flat_tail.extend([5.1; 6]);
+
+        let mask = MomentsMask::all();
+        let max_moment = mask.max_moment_needed();
+        let cases = [
+            (&data, 20, 15),
+            (&data, 250, 200),
+            (&data, 3, 1),
+            (&flat_tail, 3, 3),
+        ];
+        for (data, window, min_periods) in cases {
+            let mut fast = RollingMomentsOutput::new(data.len(), mask);
+            let mut slow = RollingMomentsOutput::new(data.len(), mask);
+            rolling_moments_all_valid(data, window, min_periods, mask, max_moment, &mut fast);
+            rolling_moments_all_valid_recompute(
+                data,
+                window,
+                min_periods,
+                mask,
+                max_moment,
+                &mut slow,
+            );
+
+            let pairs = [
+                (fast.mean, slow.mean, 1e-12),
+                (fast.std, slow.std, 1e-10),
+                (fast.skew, slow.skew, 1e-8),
+                (fast.kurt, slow.kurt, 1e-8),
+                (fast.count, slow.count, 0.0),
+            ];
+            for (a, b, tol) in pairs {
+                for (i, (p, q)) in a.unwrap().iter().zip(&b.unwrap()).enumerate() {
+                    assert!(
+                        (p.is_nan() && q.is_nan()) || p == q || (p - q).abs() <= tol,
+                        "window {} row {}: {} vs {}",
+                        window,
+                        i,
+                        p,
+                        q
+                    );
+                }
+            }
+        }
+
+        let mut fast = RollingMomentsOutput::new(flat_tail.len(), mask);
+        rolling_moments_all_valid(&flat_tail, 3, 3, mask, max_moment, &mut fast);
+        for i in 8..flat_tail.len() {
+            assert_eq!(fast.std.as_ref().unwrap()[i], 0.0, "row {}", i);
+            assert!(fast.skew.as_ref().unwrap()[i].is_nan(), "row {}", i);
+        }
+    }
+
+    #[test]
+    fn test_minmax_past_only_window() {
+        let data = vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+        let (mins, maxs) = rolling_minmax_past_only_f64(&data, 3, None, None);
+
+        // Positions 0..3: window incomplete
+        for i in 0..3 {
+            assert!(mins[i].is_nan());
+            assert!(maxs[i].is_nan());
+        }
+
+        // Position 3: window [0,2] = [3,1,4] (excludes x[3])
+        assert_eq!(mins[3], 1.0);
+        assert_eq!(maxs[3], 4.0);
+
+        // Position 5: window [2,4] = [4,1,5] (excludes x[5] = 9)
+        assert_eq!(mins[5], 1.0);
+        assert_eq!(maxs[5], 5.0);
+    }
+
+    #[test]
+    fn test_minmax_all_nan_window() {
+        let nan = f64::NAN;
+        let data = vec![nan, nan, nan, 2.0, 7.0];
+        let (mins, maxs) = rolling_minmax_past_only_f64(&data, 3, Some(1), None);
+
+        // Position 3: window [0,2] all NaN
+        assert!(mins[3].is_nan());
+        assert!(maxs[3].is_nan());
+
+        // Position 4: window [1,3] = [NaN, NaN, 2], one valid >= min_periods
+        assert_eq!(mins[4], 2.0);
+        assert_eq!(maxs[4], 2.0);
+    }
+
+    #[test]
+    fn test_minmax_min_periods_and_validity() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut valid = Bitmap::new_all_valid(5);
+        valid.set(2, false);
+
+        // Position 4: window [1,3] = [2, (3 invalid), 4], count = 2
+        let (mins, maxs) = rolling_minmax_past_only_f64(&data, 3, Some(2), Some(&valid));
+        assert_eq!(mins[4], 2.0);
+        assert_eq!(maxs[4], 4.0);
+
+        // Default min_periods = window = 3 → not enough valid values
+        let (mins, _) = rolling_minmax_past_only_f64(&data, 3, None, Some(&valid));
+        assert!(mins[3].is_nan());
+        assert!(mins[4].is_nan());
+    }
+
+    #[test]
+    fn test_minmax_matches_naive_large() {
+        let n = 5000;
+        let window = 37;
+        let data: Vec<f64> = (0..n)
+            .map(|i| {
+                if i % 11 == 0 {
+                    f64::NAN
+                } else {
+                    ((i * 7919) % 1009) as f64 - 500.0
+                }
+            })
+            .collect();
+
+        let (mins, maxs) = rolling_minmax_past_only_f64(&data, window, Some(5), None);
+
+        for i in 0..n {
+            let mut lo = f64::INFINITY;
+            let mut hi = f64::NEG_INFINITY;
+            let mut count = 0;
+            if i >= window {
+                for &v in &data[i - window..i] {
+                    if !v.is_nan() {
+                        lo = lo.min(v);
+                        hi = hi.max(v);
+                        count += 1;
+                    }
+                }
+            }
+            if i >= window && count >= 5 {
+                assert_eq!(mins[i], lo, "min mismatch at {}", i);
+                assert_eq!(maxs[i], hi, "max mismatch at {}", i);
+            } else {
+                assert!(
+                    mins[i].is_nan() && maxs[i].is_nan(),
+                    "expected NaN at {}",
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_sum_past_only() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let sums = rolling_sum_past_only_f64(&data, 2, None, None);
+
+        assert!(sums[0].is_nan());
+        assert!(sums[1].is_nan());
+        assert_eq!(sums[2], 3.0); // [1,2]
+        assert_eq!(sums[3], 5.0); // [2,3]
+        assert_eq!(sums[4], 7.0); // [3,4]
+    }
+
+    #[test]
+    fn test_rolling_sum_nan_enters_and_leaves() {
+        let nan = f64::NAN;
+        let data = vec![1.0, nan, 3.0, 4.0, nan, nan, 7.0, 8.0];
+        let sums = rolling_sum_past_only_f64(&data, 2, Some(1), None);
+
+        assert_eq!(sums[2], 1.0); // [1, NaN]
+        assert_eq!(sums[3], 3.0); // [NaN, 3]
+        assert_eq!(sums[4], 7.0); // [3, 4]
+        assert_eq!(sums[5], 4.0); // [4, NaN]
+        assert!(sums[6].is_nan()); // [NaN, NaN]
+        assert_eq!(sums[7], 7.0); // [NaN, 7]
+    }
+
+    #[test]
+    fn test_rolling_sum_matches_recompute() {
+        let n = 2000;
+        let window = 25;
+        let data: Vec<f64> = (0..n)
+            .map(|i| {
+                if i % 7 == 3 {
+                    f64::NAN
+                } else {
+                    ((i * 31) % 97) as f64 * 0.5
+                }
+            })
+            .collect();
+        let mut valid = Bitmap::new_all_valid(n);
+        for i in (0..n).step_by(13) {
+            valid.set(i, false);
+        }
+
+        let sums = rolling_sum_past_only_f64(&data, window, Some(10), Some(&valid));
+
+        for i in 0..n {
+            let mut s = 0.0;
+            let mut count = 0;
+            if i >= window {
+                for j in i - window..i {
+                    if valid.get(j) && !data[j].is_nan() {
+                        s += data[j];
+                        count += 1;
+                    }
+                }
+            }
+            if i >= window && count >= 10 {
+                assert!((sums[i] - s).abs() < 1e-9, "mismatch at {}", i);
+            } else {
+                assert!(sums[i].is_nan(), "expected NaN at {}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_zscore_matches_manual() {
+        let x: Vec<f64> = (0..40)
+            .map(|i| ((i * 13) % 7) as f64 + 0.5 * i as f64)
+            .collect();
+        let window = 6;
+        let out = rolling_zscore_past_only_f64(&x, window, None, None, f64::NAN);
+
+        for i in 0..x.len() {
+            if i < window {
+                assert!(out[i].is_nan());
+                continue;
+            }
+            let w = &x[i - window..i];
+            let m = w.iter().sum::<f64>() / window as f64;
+            let var = w.iter().map(|v| (v - m) * (v - m)).sum::<f64>() / (window - 1) as f64;
+            let expected = (x[i] - m) / var.sqrt();
+            assert!((out[i] - expected).abs() < 1e-9, "i={}", i);
+        }
+    }
+
+    #[test]
+    fn test_rolling_zscore_constant_window() {
+        let x = vec![2.0, 2.0, 2.0, 5.0, f64::NAN];
+        let zero = rolling_zscore_past_only_f64(&x, 3, None, None, 0.0);
+        assert_eq!(zero[3], 0.0);
+        assert!(zero[4].is_nan()); // x[4] is NaN
+
+        let nan = rolling_zscore_past_only_f64(&x, 3, None, None, f64::NAN);
+        assert!(nan[3].is_nan());
+
+        // A window that turns constant after volatile values is still constant
+        let mut x = vec![1.3, 2.7, 0.1, 9.9, 0.3];
+        x.extend([5.1; 6]);
+        let z = rolling_zscore_past_only_f64(&x, 3, None, None, 0.0);
+        assert_eq!(&z[8..], &[0.0; 3]);
+    }
 }
@@ -0,0 +1,174 @@
+//! Ft-measurable rolling quantile kernel
+//!
+//! Rolling q-quantile (median for q = 0.5) over the past-only window
+//! [i-window, i-1], same window semantics as `rolling_moments`.
+//!
+//! The window contents live behind the `OrderStatistics` trait. The current
+//! implementation is a sorted vector (O(window) insert/remove); an
+//! order-statistics tree can replace it without touching the kernel.
+
+use crate::builtins::ops::quantile_sorted;
+use crate::table::bitmap::Bitmap;
+
+/// Multiset of window values supporting insert, remove and quantile lookup
+pub(crate) trait OrderStatistics {
+    fn with_capacity(window: usize) -> Self;
+    fn insert(&mut self, x: f64);
+    fn remove(&mut self, x: f64);
+    fn len(&self) -> usize;
+    /// Linear-interpolated q-quantile of the current contents
+    fn quantile(&self, q: f64) -> f64;
+}
+
+/// Sorted-vector window: binary search + shift on insert/remove
+pub(crate) struct SortedWindow {
+    values: Vec<f64>,
+}
+
+impl OrderStatistics for SortedWindow {
+    fn with_capacity(window: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(window),
+        }
+    }
+
+    fn insert(&mut self, x: f64) {
+        let pos = self.values.partition_point(|&v| v < x);
+        self.values.insert(pos, x);
+    }
+
+    fn remove(&mut self, x: f64) {
+        let pos = self.values.partition_point(|&v| v < x);
+        debug_assert!(pos < self.values.len() && self.values[pos] == x);
+        self.values.remove(pos);
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn quantile(&self, q: f64) -> f64 {
+        quantile_sorted(&self.values, q)
+    }
+}
+
+/// Single-pass Ft-measurable rolling quantile kernel
+///
+/// # Arguments
+/// * `x` - Input data
+/// * `window` - Window size
+/// * `q` - Quantile in [0, 1] (0.5 = median); outside that range yields all NaN
+/// * `min_periods` - Minimum valid observations required (default: window)
+/// * `validity` - Optional validity bitmap (None = all valid)
+///
+/// # Returns
+/// Quantile of the valid values in [i-window, i-1] with linear interpolation
+/// (see `ops::quantile`), NaN where the window is incomplete or has fewer
+/// than `min_periods` valid observations.
+pub fn rolling_quantile_past_only_f64(
+    x: &[f64],
+    window: usize,
+    q: f64,
+    min_periods: Option<usize>,
+    validity: Option<&Bitmap>,
+) -> Vec<f64> {
+    rolling_quantile_with::<SortedWindow>(x, window, q, min_periods, validity)
+}
+
+fn rolling_quantile_with<W: OrderStatistics>(
+    x: &[f64],
+    window: usize,
+    q: f64,
+    min_periods: Option<usize>,
+    validity: Option<&Bitmap>,
+) -> Vec<f64> {
+    let n = x.len();
+    let min_periods = min_periods.unwrap_or(window);
+    let mut out = vec![f64::NAN; n];
+
+    if window == 0 || !(0.0..=1.0).contains(&q) {
+        return out;
+    }
+
+    let is_valid = |j: usize| !x[j].is_nan() && validity.is_none_or(|v| v.get(j));
+    let mut win = W::with_capacity(window);
+
+    for i in 0..n {
+        // Window for position i is [i-window, i-1]
+        if i > window {
+            let leaving = i - window - 1;
+            if is_valid(leaving) {
+                win.remove(x[leaving]);
+            }
+        }
+        if i >= window && win.len() >= min_periods && win.len() > 0 {
+            out[i] = win.quantile(q);
+        }
+
+        if is_valid(i) {
+            win.insert(x[i]);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_median_known_sequence() {
+        let x = vec![5.0, 1.0, 4.0, 2.0, 3.0, 9.0, 7.0];
+        let out = rolling_quantile_past_only_f64(&x, 3, 0.5, None, None);
+
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+        assert_eq!(out[3], 4.0); // [5, 1, 4]
+        assert_eq!(out[4], 2.0); // [1, 4, 2]
+        assert_eq!(out[5], 3.0); // [4, 2, 3]
+        assert_eq!(out[6], 3.0); // [2, 3, 9]
+    }
+
+    #[test]
+    fn test_rolling_quantile_interpolates() {
+        let x = vec![1.0, 2.0, 3.0, 4.0, 0.0];
+        let out = rolling_quantile_past_only_f64(&x, 4, 0.25, None, None);
+        assert_eq!(out[4], 1.75);
+
+        let out = rolling_quantile_past_only_f64(&x, 4, 1.5, None, None);
+        assert!(out.iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_rolling_quantile_min_periods_boundary() {
+        let x = vec![1.0, f64::NAN, 3.0, f64::NAN, 5.0, 6.0];
+
+        // Window 3 at i=3 is [1, NaN, 3]: 2 valid
+        let out = rolling_quantile_past_only_f64(&x, 3, 0.5, Some(2), None);
+        assert_eq!(out[3], 2.0);
+        // i=4: [NaN, 3, NaN] has 1 valid < 2
+        assert!(out[4].is_nan());
+        // i=5: [3, NaN, 5] has 2 valid
+        assert_eq!(out[5], 4.0);
+
+        let out = rolling_quantile_past_only_f64(&x, 3, 0.5, Some(1), None);
+        assert_eq!(out[4], 3.0);
+
+        // Default min_periods = window: every window here has a NaN
+        let out = rolling_quantile_past_only_f64(&x, 3, 0.5, None, None);
+        assert!(out.iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_rolling_quantile_validity_and_duplicates() {
+        let x = vec![2.0, 2.0, 2.0, 8.0, 2.0];
+        let mut validity = Bitmap::new_all_valid(x.len());
+        validity.set(3, false);
+
+        let out = rolling_quantile_past_only_f64(&x, 4, 1.0, Some(1), Some(&validity));
+        assert_eq!(out[4], 2.0);
+
+        let out = rolling_quantile_past_only_f64(&x, 4, 1.0, Some(1), None);
+        assert_eq!(out[4], 8.0);
+    }
+}
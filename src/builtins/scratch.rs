@@ -4,7 +4,6 @@
 //! After warmup, pipelines allocate ~0.
 
 use crate::table::Bitmap;
-// Removed unused import: std::mem::MaybeUninit
 
 /// Reusable buffer pool for pipeline operations
 ///
@@ -37,43 +36,61 @@ impl Scratch {
         }
     }
 
-    /// Get f64 buffer of given size (reuses if available)
+    /// Create scratch allocator pre-populated with `n` f64 buffers and `n`
+    /// bitmaps of length `len_hint` (avoids warmup allocations)
+    ///
+    /// The f64 buffers are zero-filled up front so `get_f64_uninit` can hand
+    /// them out without touching them again.
+    pub fn with_capacity(n: usize, len_hint: usize) -> Self {
+        Scratch {
+            f64_bufs: (0..n).map(|_| vec![0.0; len_hint]).collect(),
+            bitmap_bufs: (0..n).map(|_| Bitmap::new_all_null(len_hint)).collect(),
+        }
+    }
+
+    /// Take the smallest pooled f64 buffer with capacity >= len
+    ///
+    /// Buffers that are too small stay in the pool for later requests, so
+    /// pipelines alternating between column lengths reuse both sizes.
+    fn take_f64(&mut self, len: usize) -> Option<Vec<f64>> {
+        let idx = self
+            .f64_bufs
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.capacity() >= len)
+            .min_by_key(|(_, b)| b.capacity())
+            .map(|(i, _)| i)?;
+        Some(self.f64_bufs.swap_remove(idx))
+    }
+
+    /// Get f64 buffer of given size (reuses best fit if available)
     pub fn get_f64(&mut self, len: usize) -> Vec<f64> {
-        if let Some(mut buf) = self.f64_bufs.pop() {
+        if let Some(mut buf) = self.take_f64(len) {
             // Reuse existing buffer
-            if buf.capacity() >= len {
-                buf.clear();
-                buf.resize(len, 0.0);
-                return buf;
-            }
-            // Buffer too small, drop it and allocate new
+            buf.clear();
+            buf.resize(len, 0.0);
+            return buf;
         }
-        // No buffer available or too small, allocate
+        // No buffer large enough, allocate
         vec![0.0; len]
     }
 
-    /// Get UNINITIALIZED f64 buffer (for masked kernels - Step 1 optimization)
-    ///
-    /// Use this when you will write to valid indices and validity mask
-    /// tracks which indices are valid. Invalid indices can remain uninitialized.
+    /// Get f64 buffer of given size WITHOUT clearing old contents (for
+    /// masked kernels)
     ///
-    /// SAFETY: Caller must ensure they either:
-    /// 1. Write to ALL indices before reading, OR
-    /// 2. Only read from valid indices (checked via validity mask)
+    /// Use this when you will write to valid indices and the validity mask
+    /// tracks which indices are valid. Elements the buffer held before keep
+    /// their stale values; only capacity that was never written is
+    /// zero-filled, so a buffer returned at the same length is reused
+    /// without any writes. Callers must not rely on the stale values.
     pub fn get_f64_uninit(&mut self, len: usize) -> Vec<f64> {
-        if let Some(mut buf) = self.f64_bufs.pop() {
-            // Reuse existing buffer WITHOUT zeroing
-            if buf.capacity() >= len {
-                unsafe {
-                    buf.set_len(len); // Skip clear() and resize() - no zeroing!
-                }
-                return buf;
-            }
-            // Buffer too small, drop it
+        if let Some(mut buf) = self.take_f64(len) {
+            // Truncates or zero-extends; the existing prefix is left as is
+            buf.resize(len, 0.0);
+            return buf;
         }
         // No buffer available, allocate (first time only)
-        // Still needs to allocate vec, but won't zero on reuse
-        Vec::with_capacity(len)
+        vec![0.0; len]
     }
 
     /// Return f64 buffer to pool
@@ -81,14 +98,12 @@ impl Scratch {
         self.f64_bufs.push(buf);
     }
 
-    /// Get bitmap of given size (reuses if available)
+    /// Get bitmap of given size (reuses a same-length bitmap if available)
+    ///
+    /// Bitmaps of other lengths stay pooled.
     pub fn get_bitmap(&mut self, len: usize) -> Bitmap {
-        if let Some(bm) = self.bitmap_bufs.pop() {
-            // Reuse if same size
-            if bm.len() == len {
-                return bm;
-            }
-            // Wrong size, drop it
+        if let Some(idx) = self.bitmap_bufs.iter().rposition(|bm| bm.len() == len) {
+            return self.bitmap_bufs.swap_remove(idx);
         }
         // Allocate new
         Bitmap::new_all_null(len)
@@ -105,11 +120,36 @@ impl Scratch {
         self.bitmap_bufs.clear();
     }
 
+    /// Drop pooled buffers beyond `max_buffers` per pool (largest first)
+    ///
+    /// Reclaims memory after a burst; buffers currently checked out are
+    /// unaffected.
+    pub fn shrink_to(&mut self, max_buffers: usize) {
+        if self.f64_bufs.len() > max_buffers {
+            self.f64_bufs.sort_by_key(|b| b.capacity());
+            self.f64_bufs.truncate(max_buffers);
+        }
+        if self.bitmap_bufs.len() > max_buffers {
+            self.bitmap_bufs.sort_by_key(|bm| bm.words_len());
+            self.bitmap_bufs.truncate(max_buffers);
+        }
+    }
+
     /// Get statistics
     pub fn stats(&self) -> ScratchStats {
         ScratchStats {
             f64_bufs: self.f64_bufs.len(),
             bitmap_bufs: self.bitmap_bufs.len(),
+            f64_capacity_bytes: self
+                .f64_bufs
+                .iter()
+                .map(|b| b.capacity() * std::mem::size_of::<f64>())
+                .sum(),
+            bitmap_capacity_bytes: self
+                .bitmap_bufs
+                .iter()
+                .map(|bm| bm.words_len() * std::mem::size_of::<u64>())
+                .sum(),
         }
     }
 }
@@ -125,6 +165,10 @@ impl Default for Scratch {
 pub struct ScratchStats {
     pub f64_bufs: usize,
     pub bitmap_bufs: usize,
+    /// Bytes retained by pooled f64 buffers (capacity, not length)
+    pub f64_capacity_bytes: usize,
+    /// Bytes retained by pooled bitmaps (word storage)
+    pub bitmap_capacity_bytes: usize,
 }
 
 #[cfg(test)]
@@ -192,4 +236,95 @@ mod tests {
         assert_eq!(scratch.stats().f64_bufs, 0);
         assert_eq!(scratch.stats().bitmap_bufs, 0);
     }
+
+    #[test]
+    fn test_scratch_alternating_lengths() {
+        let mut scratch = Scratch::new();
+
+        for _ in 0..50 {
+            let small = scratch.get_f64(100);
+            let large = scratch.get_f64(1000);
+            assert_eq!(small.len(), 100);
+            assert_eq!(large.len(), 1000);
+            scratch.return_f64(large);
+            scratch.return_f64(small);
+
+            let bm_small = scratch.get_bitmap(100);
+            let bm_large = scratch.get_bitmap(1000);
+            scratch.return_bitmap(bm_small);
+            scratch.return_bitmap(bm_large);
+        }
+
+        // Both sizes retained, no churn
+        assert_eq!(scratch.stats().f64_bufs, 2);
+        assert_eq!(scratch.stats().bitmap_bufs, 2);
+
+        // Small request picks the best fit, leaving the large buffer pooled
+        let small = scratch.get_f64(100);
+        assert!(small.capacity() < 1000);
+        let large = scratch.get_f64(1000);
+        assert!(large.capacity() >= 1000);
+        assert_eq!(scratch.stats().f64_bufs, 0);
+    }
+
+    #[test]
+    fn test_scratch_shrink_to() {
+        let mut scratch = Scratch::new();
+        for len in [10, 1000, 100, 10_000] {
+            scratch.return_f64(Vec::with_capacity(len));
+            scratch.return_bitmap(Bitmap::new_all_null(len));
+        }
+
+        scratch.shrink_to(2);
+        let stats = scratch.stats();
+        assert_eq!(stats.f64_bufs, 2);
+        assert_eq!(stats.bitmap_bufs, 2);
+        // Largest buffers were dropped
+        assert_eq!(stats.f64_capacity_bytes, (10 + 100) * 8);
+
+        scratch.shrink_to(5);
+        assert_eq!(scratch.stats().f64_bufs, 2);
+
+        scratch.shrink_to(0);
+        assert_eq!(scratch.stats().f64_bufs, 0);
+        assert_eq!(scratch.stats().bitmap_capacity_bytes, 0);
+    }
+
+    #[test]
+    fn test_scratch_uninit_never_exposes_unwritten_capacity() {
+        // Pooled buffer with capacity but no initialized elements
+        let mut scratch = Scratch::new();
+        scratch.return_f64(Vec::with_capacity(64));
+        let buf = scratch.get_f64_uninit(64);
+        assert_eq!(buf.len(), 64);
+        assert!(buf.iter().all(|&v| v == 0.0));
+
+        // Stale contents of a returned buffer are kept, the rest zeroed
+        let mut scratch = Scratch::new();
+        let mut buf = Vec::with_capacity(8);
+        buf.extend_from_slice(&[1.0, 2.0]);
+        scratch.return_f64(buf);
+        assert_eq!(scratch.get_f64_uninit(4), vec![1.0, 2.0, 0.0, 0.0]);
+
+        assert_eq!(Scratch::new().get_f64_uninit(3).len(), 3);
+        let mut scratch = Scratch::with_capacity(1, 16);
+        assert_eq!(scratch.get_f64_uninit(16), vec![0.0; 16]);
+    }
+
+    #[test]
+    fn test_scratch_with_capacity_stats() {
+        let mut scratch = Scratch::with_capacity(3, 128);
+        let stats = scratch.stats();
+        assert_eq!(stats.f64_bufs, 3);
+        assert_eq!(stats.bitmap_bufs, 3);
+        assert_eq!(stats.f64_capacity_bytes, 3 * 128 * 8);
+        assert_eq!(stats.bitmap_capacity_bytes, 3 * 2 * 8); // 128 bits = 2 words
+
+        let buf = scratch.get_f64(128);
+        assert_eq!(buf.len(), 128);
+        assert_eq!(scratch.stats().f64_bufs, 2);
+        let bm = scratch.get_bitmap(128);
+        assert_eq!(bm.len(), 128);
+        assert_eq!(scratch.stats().bitmap_bufs, 2);
+    }
 }
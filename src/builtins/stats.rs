@@ -0,0 +1,399 @@
+//! Two-column statistics: covariance, correlation and regression beta
+//!
+//! Pairwise deletion: a position contributes only if both inputs are
+//! non-NaN there. The rolling kernel uses the same past-only window
+//! [i-window, i-1] as `rolling_moments`.
+
+use crate::table::Column;
+
+/// Single-pass co-moment accumulator (Welford)
+///
+/// Tracks the means and centered second moments of x and y plus their
+/// cross moment, avoiding the cancellation of the naive sum-of-products form.
+#[derive(Clone, Copy, Debug, Default)]
+struct CoMoments {
+    n: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+}
+
+impl CoMoments {
+    #[inline]
+    fn push(&mut self, x: f64, y: f64) {
+        self.n += 1;
+        let n = self.n as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c_xy += dx * (y - self.mean_y);
+    }
+
+    fn from_slices(x: &[f64], y: &[f64]) -> Self {
+        let mut acc = CoMoments::default();
+        for (&a, &b) in x.iter().zip(y) {
+            if !a.is_nan() && !b.is_nan() {
+                acc.push(a, b);
+            }
+        }
+        acc
+    }
+
+    /// Sample covariance (ddof=1)
+    fn cov(&self) -> f64 {
+        if self.n < 2 {
+            return f64::NAN;
+        }
+        self.c_xy / ((self.n - 1) as f64)
+    }
+
+    /// Pearson correlation; NaN if either variance is zero
+    fn corr(&self) -> f64 {
+        if self.n < 2 || self.m2_x <= 0.0 || self.m2_y <= 0.0 {
+            return f64::NAN;
+        }
+        (self.c_xy / (self.m2_x * self.m2_y).sqrt()).clamp(-1.0, 1.0)
+    }
+}
+
+/// Extract two equal-length F64 slices
+fn pair<'a>(x: &'a Column, y: &'a Column, name: &str) -> (&'a [f64], &'a [f64]) {
+    let (Ok(a), Ok(b)) = (x.as_f64_slice(), y.as_f64_slice()) else {
+        panic!("{}: expected F64 columns", name);
+    };
+    assert_eq!(a.len(), b.len(), "{}: column length mismatch", name);
+    (a, b)
+}
+
+/// cov: Sample covariance (ddof=1) over positions where both are non-NaN
+///
+/// Returns NaN for fewer than 2 valid pairs.
+///
+/// # Panics
+/// Panics if either column is not F64 or the lengths differ.
+pub fn cov(x: &Column, y: &Column) -> f64 {
+    let (a, b) = pair(x, y, "cov");
+    CoMoments::from_slices(a, b).cov()
+}
+
+/// corr: Pearson correlation over positions where both are non-NaN
+///
+/// Returns NaN for fewer than 2 valid pairs or a zero-variance input.
+///
+/// # Panics
+/// Panics if either column is not F64 or the lengths differ.
+pub fn corr(x: &Column, y: &Column) -> f64 {
+    let (a, b) = pair(x, y, "corr");
+    CoMoments::from_slices(a, b).corr()
+}
+
+/// Cancellation leaves tiny non-zero residue on constant windows
+const REL_EPS: f64 = 1e-12;
+
+/// Rolling sums of x, y, x², y², xy over the valid pairs in a window
+#[derive(Clone, Copy, Debug, Default)]
+struct PairSums {
+    n: usize,
+    sx: f64,
+    sy: f64,
+    sxx: f64,
+    syy: f64,
+    sxy: f64,
+}
+
+impl PairSums {
+    #[inline]
+    fn add(&mut self, x: f64, y: f64, sign: f64) {
+        self.sx += sign * x;
+        self.sy += sign * y;
+        self.sxx += sign * x * x;
+        self.syy += sign * y * y;
+        self.sxy += sign * x * y;
+    }
+
+    /// Correlation from the raw sums; NaN if either variance is (numerically) zero
+    fn corr(&self) -> f64 {
+        let n = self.n as f64;
+        let vx = n * self.sxx - self.sx * self.sx;
+        let vy = n * self.syy - self.sy * self.sy;
+
+        if vx <= REL_EPS * n * self.sxx || vy <= REL_EPS * n * self.syy {
+            return f64::NAN;
+        }
+        ((n * self.sxy - self.sx * self.sy) / (vx * vy).sqrt()).clamp(-1.0, 1.0)
+    }
+
+    /// OLS slope of y on x (cov/var(x)); NaN if x is (numerically) constant
+    fn beta(&self) -> f64 {
+        let n = self.n as f64;
+        let vx = n * self.sxx - self.sx * self.sx;
+        if vx <= REL_EPS * n * self.sxx {
+            return f64::NAN;
+        }
+        (n * self.sxy - self.sx * self.sy) / vx
+    }
+}
+
+/// Single-pass Ft-measurable rolling correlation kernel
+///
+/// # Arguments
+/// * `x`, `y` - Input series of equal length
+/// * `window` - Window size
+/// * `min_periods` - Minimum valid pairs required (default: window)
+///
+/// # Returns
+/// Pearson correlation of the pairs in [i-window, i-1] where both x and y
+/// are non-NaN. NaN where the window is incomplete, has fewer than
+/// `min_periods` (or 2) valid pairs, or either series is constant over it.
+///
+/// Maintains rolling sums of x, y, x², y², xy: O(n) regardless of window.
+///
+/// # Panics
+/// Panics if `x` and `y` have different lengths.
+pub fn rolling_corr_past_only_f64(
+    x: &[f64],
+    y: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+) -> Vec<f64> {
+    assert_eq!(x.len(), y.len(), "rolling_corr: length mismatch");
+    rolling_pairs(x, y, window, min_periods, PairSums::corr)
+}
+
+/// Single-pass Ft-measurable rolling beta kernel
+///
+/// # Arguments
+/// * `y` - Dependent series
+/// * `x` - Regressor, same length as `y`
+/// * `window` - Window size
+/// * `min_periods` - Minimum valid pairs required (default: window)
+///
+/// # Returns
+/// OLS slope cov(x, y) / var(x) of the pairs in [i-window, i-1] where both
+/// are non-NaN. NaN where the window is incomplete, has fewer than
+/// `min_periods` (or 2) valid pairs, or x is constant over it.
+///
+/// Shares the rolling sums of `rolling_corr_past_only_f64`: O(n).
+///
+/// # Panics
+/// Panics if `y` and `x` have different lengths.
+pub fn rolling_beta_past_only_f64(
+    y: &[f64],
+    x: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+) -> Vec<f64> {
+    assert_eq!(x.len(), y.len(), "rolling_beta: length mismatch");
+    rolling_pairs(x, y, window, min_periods, PairSums::beta)
+}
+
+/// Past-only window driver: `stat` of the pair sums at each position
+fn rolling_pairs(
+    x: &[f64],
+    y: &[f64],
+    window: usize,
+    min_periods: Option<usize>,
+    stat: impl Fn(&PairSums) -> f64,
+) -> Vec<f64> {
+    let n = x.len();
+    let min_periods = min_periods.unwrap_or(window).max(2);
+    let mut out = vec![f64::NAN; n];
+
+    if window == 0 {
+        return out;
+    }
+
+    let is_valid = |j: usize| !x[j].is_nan() && !y[j].is_nan();
+    let mut sums = PairSums::default();
+
+    for i in 0..n {
+        // Window for position i is [i-window, i-1]
+        if i > window {
+            let leaving = i - window - 1;
+            if is_valid(leaving) {
+                sums.add(x[leaving], y[leaving], -1.0);
+                sums.n -= 1;
+            }
+        }
+        if i >= window && sums.n >= min_periods {
+            out[i] = stat(&sums);
+        }
+
+        if is_valid(i) {
+            sums.add(x[i], y[i], 1.0);
+            sums.n += 1;
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtins::test_util::assert_f64_close;
+
+    fn col(v: &[f64]) -> Column {
+        Column::new_f64(v.to_vec())
+    }
+
+    #[test]
+    fn test_perfect_correlation() {
+        let x = col(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let y = col(&[3.0, 5.0, 7.0, 9.0, 11.0]); // 2x + 1
+        assert!((corr(&x, &y) - 1.0).abs() < 1e-12);
+        assert!((cov(&x, &y) - 5.0).abs() < 1e-12);
+
+        let z = col(&[10.0, 8.0, 6.0, 4.0, 2.0]);
+        assert!((corr(&x, &z) + 1.0).abs() < 1e-12);
+        assert!((cov(&x, &z) + 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weak_correlation() {
+        let x = col(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let y = col(&[2.0, -1.0, 3.0, 0.0, 1.0, 1.0]);
+        let r = corr(&x, &y);
+        assert!(r.abs() < 0.3, "{}", r);
+    }
+
+    #[test]
+    fn test_nan_reduces_sample() {
+        let x = col(&[1.0, f64::NAN, 3.0, 4.0, 100.0]);
+        let y = col(&[2.0, 7.0, 6.0, 8.0, f64::NAN]);
+        // Valid pairs: (1,2), (3,6), (4,8) -> y = 2x
+        assert!((corr(&x, &y) - 1.0).abs() < 1e-12);
+        assert!((cov(&x, &y) - cov(&col(&[1.0, 3.0, 4.0]), &col(&[2.0, 6.0, 8.0]))).abs() < 1e-12);
+
+        let one_pair = col(&[1.0, f64::NAN]);
+        assert!(cov(&one_pair, &col(&[1.0, 2.0])).is_nan());
+        assert!(corr(&col(&[1.0, 1.0, 1.0]), &col(&[1.0, 2.0, 3.0])).is_nan());
+    }
+
+    #[test]
+    fn test_large_offset_is_stable() {
+        let x: Vec<f64> = (0..100).map(|i| 1e9 + i as f64).collect();
+        let y: Vec<f64> = (0..100).map(|i| 1e9 - 2.0 * i as f64).collect();
+        assert!((corr(&col(&x), &col(&y)) + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "corr: column length mismatch")]
+    fn test_length_mismatch_panics() {
+        corr(&col(&[1.0, 2.0]), &col(&[1.0]));
+    }
+
+    /// Per-window recomputation with the pairwise `corr`
+    fn rolling_corr_baseline(x: &[f64], y: &[f64], window: usize, min_periods: usize) -> Vec<f64> {
+        (0..x.len())
+            .map(|i| {
+                if i < window {
+                    return f64::NAN;
+                }
+                let (wx, wy) = (&x[i - window..i], &y[i - window..i]);
+                let valid = wx
+                    .iter()
+                    .zip(wy)
+                    .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+                    .count();
+                if valid < min_periods.max(2) {
+                    f64::NAN
+                } else {
+                    corr(&col(wx), &col(wy))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rolling_corr_matches_baseline() {
+        let x: Vec<f64> = (0..60).map(|i| ((i * 13) % 17) as f64 - 8.0).collect();
+        let mut y: Vec<f64> = (0..60)
+            .map(|i| ((i * 7) % 11) as f64 + 0.5 * x[i])
+            .collect();
+        let mut x = x;
+        x[10] = f64::NAN;
+        y[11] = f64::NAN;
+        y[30] = f64::NAN;
+
+        for (window, min_periods) in [(8, None), (8, Some(5)), (3, Some(2))] {
+            let out = rolling_corr_past_only_f64(&x, &y, window, min_periods);
+            let expected = rolling_corr_baseline(&x, &y, window, min_periods.unwrap_or(window));
+            assert_f64_close(&out, &expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rolling_corr_zero_variance_window() {
+        let x = [1.0, 2.0, 3.0, 3.0, 3.0, 3.0, 5.0, 6.0];
+        let y = [2.0, 4.0, 6.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let out = rolling_corr_past_only_f64(&x, &y, 3, None);
+
+        assert!((out[3] - 1.0).abs() < 1e-12); // x=[1,2,3], y=2x
+        assert!(out[6].is_nan()); // x window [3,3,3] is constant
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+    }
+
+    /// Per-window OLS slope over the valid pairs
+    fn rolling_beta_baseline(y: &[f64], x: &[f64], window: usize, min_periods: usize) -> Vec<f64> {
+        (0..x.len())
+            .map(|i| {
+                if i < window {
+                    return f64::NAN;
+                }
+                let pairs: Vec<(f64, f64)> = x[i - window..i]
+                    .iter()
+                    .zip(&y[i - window..i])
+                    .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+                    .map(|(&a, &b)| (a, b))
+                    .collect();
+                if pairs.len() < min_periods.max(2) {
+                    return f64::NAN;
+                }
+                let k = pairs.len() as f64;
+                let mx = pairs.iter().map(|p| p.0).sum::<f64>() / k;
+                let my = pairs.iter().map(|p| p.1).sum::<f64>() / k;
+                let sxy: f64 = pairs.iter().map(|p| (p.0 - mx) * (p.1 - my)).sum();
+                let sxx: f64 = pairs.iter().map(|p| (p.0 - mx) * (p.0 - mx)).sum();
+                if sxx == 0.0 {
+                    f64::NAN
+                } else {
+                    sxy / sxx
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rolling_beta_matches_ols_baseline() {
+        let mut x: Vec<f64> = (0..60).map(|i| ((i * 13) % 17) as f64 - 8.0).collect();
+        let mut y: Vec<f64> = (0..60)
+            .map(|i| 1.5 * x[i] + ((i * 7) % 11) as f64 - 3.0)
+            .collect();
+        x[10] = f64::NAN;
+        y[11] = f64::NAN;
+        y[30] = f64::NAN;
+
+        for (window, min_periods) in [(8, None), (8, Some(5)), (3, Some(2))] {
+            let out = rolling_beta_past_only_f64(&y, &x, window, min_periods);
+            let expected = rolling_beta_baseline(&y, &x, window, min_periods.unwrap_or(window));
+            assert_f64_close(&out, &expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rolling_beta_constant_x_window() {
+        let x = [1.0, 2.0, 3.0, 3.0, 3.0, 3.0, 5.0];
+        let y = [3.0, 5.0, 7.0, 1.0, 2.0, 4.0, 4.0];
+        let out = rolling_beta_past_only_f64(&y, &x, 3, None);
+
+        assert!((out[3] - 2.0).abs() < 1e-12); // y = 2x + 1
+        assert!(out[6].is_nan()); // x window [3,3,3] is constant
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+    }
+}
@@ -0,0 +1,19 @@
+//! Shared assertions for kernel tests
+
+/// Assert equal lengths and `|a - e| <= tol` elementwise, NaN matching NaN
+///
+/// `tol = 0.0` asks for exact equality; equal infinities always match.
+pub(crate) fn assert_f64_close(actual: &[f64], expected: &[f64], tol: f64) {
+    assert_eq!(actual.len(), expected.len(), "length mismatch");
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        assert!(
+            (a.is_nan() && e.is_nan()) || a == e || (a - e).abs() <= tol,
+            "row {}: {} vs {}\n{:?} vs {:?}",
+            i,
+            a,
+            e,
+            actual,
+            expected
+        );
+    }
+}
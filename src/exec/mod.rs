@@ -1 +1,218 @@
+//! End-to-end execution: plan, run and unwrap in one call
+//!
+//! `run` and `run_expr` are the front door for callers that don't need
+//! `Planner`/`Executor` directly. Planner, executor and evaluator failures
+//! all surface as `ExecError`.
 
+mod view_pipeline;
+
+pub use view_pipeline::ViewPipeline;
+
+use crate::expr::{compile, eval, EvalError, Expr};
+use crate::pipeline::{ExecutionValue, Executor, PipeIR, PlanError, Planner};
+use crate::table::{Column, Table};
+use std::fmt;
+
+/// End-to-end execution error
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecError {
+    /// IR could not be planned
+    Plan(PlanError),
+    /// Expression could not be evaluated
+    Eval(EvalError),
+    /// Executor failure (unknown op, segment type mismatch, ...)
+    Execution(String),
+    /// Pipeline produced a column or scalar where a table was expected
+    NotTable(&'static str),
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecError::Plan(e) => write!(f, "plan error: {}", e),
+            ExecError::Eval(e) => write!(f, "eval error: {}", e),
+            ExecError::Execution(msg) => write!(f, "execution error: {}", msg),
+            ExecError::NotTable(kind) => {
+                write!(f, "pipeline produced a {}, expected a table", kind)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+impl From<PlanError> for ExecError {
+    fn from(e: PlanError) -> Self {
+        ExecError::Plan(e)
+    }
+}
+
+impl From<EvalError> for ExecError {
+    fn from(e: EvalError) -> Self {
+        ExecError::Eval(e)
+    }
+}
+
+/// Plan and execute `ir` on `input`, returning the output table
+///
+/// # Errors
+/// `Plan` if the IR is invalid, `Execution` if a segment fails, `NotTable`
+/// if the pipeline ends in a reducer.
+pub fn run(ir: &PipeIR, input: Table) -> Result<Table, ExecError> {
+    let plan = Planner::plan(ir)?;
+    let result = Executor::new()
+        .execute(&plan, input)
+        .map_err(ExecError::Execution)?;
+
+    match result.value {
+        ExecutionValue::Table(t) => Ok(t),
+        ExecutionValue::Column(_) => Err(ExecError::NotTable("column")),
+        ExecutionValue::Scalar(_) => Err(ExecError::NotTable("scalar")),
+    }
+}
+
+/// Evaluate `expr` against `input` into an F64 column
+///
+/// Linear single-column chains (see `expr::compile`) run fused through the
+/// pipeline on the leaf column; anything else falls back to `expr::eval`.
+/// Both paths give the same values.
+pub fn run_expr(expr: &Expr, input: Table) -> Result<Column, ExecError> {
+    let Ok(ir) = compile(expr) else {
+        return Ok(eval(expr, &input)?);
+    };
+
+    // Compiled chains have exactly one column leaf
+    let name = leaf_column(expr).expect("compiled expr has a column leaf");
+    let Some(col @ Column::F64(_)) = input.column(name) else {
+        // Missing or non-F64: let the evaluator report it
+        return Ok(eval(expr, &input)?);
+    };
+
+    let single = Table::new(vec![name.to_string()], vec![col.clone()]);
+    let mut out = run(&ir, single)?;
+    Ok(out.columns.swap_remove(0))
+}
+
+/// First column leaf of `expr`
+fn leaf_column(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Col(name) => Some(name),
+        Expr::Const(_) => None,
+        Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+            leaf_column(a).or_else(|| leaf_column(b))
+        }
+        Expr::Dlog(a, _) | Expr::Ln(a) | Expr::Abs(a) => leaf_column(a),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{OpId, Step};
+    use crate::table::{ORI_H, ORI_X, ORI_Z};
+
+    fn prices() -> Table {
+        Table::new(
+            vec!["open".to_string(), "close".to_string()],
+            vec![
+                Column::F64(vec![100.0, 101.0, 99.5, 102.0]),
+                Column::F64(vec![101.0, 99.0, 102.5, f64::NAN]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_run_errors_are_typed() {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_X));
+        ir.push(Step::OriRel(ORI_Z));
+        assert!(matches!(run(&ir, prices()), Err(ExecError::Plan(_))));
+
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Sum,
+            args: vec![],
+        });
+        assert_eq!(
+            run(&ir, prices()).unwrap_err(),
+            ExecError::NotTable("column")
+        );
+
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Generic("nope".to_string()),
+            args: vec![],
+        });
+        assert!(matches!(run(&ir, prices()), Err(ExecError::Execution(_))));
+    }
+
+    #[test]
+    fn test_run_expr_fused_and_fallback() {
+        // Linear chain on one column of a wider table
+        let e = Expr::Mul(
+            Box::new(Expr::Dlog(Box::new(Expr::col("close")), 1)),
+            Box::new(Expr::Const(2.0)),
+        );
+        let out = run_expr(&e, prices()).unwrap();
+        let expected = eval(&e, &prices()).unwrap();
+        assert_eq!(out.f64_data()[1], expected.f64_data()[1]);
+        assert_eq!(out.len(), 4);
+
+        // Two columns: tree-walked
+        let e = Expr::Sub(Box::new(Expr::col("close")), Box::new(Expr::col("open")));
+        let out = run_expr(&e, prices()).unwrap();
+        assert_eq!(&out.f64_data()[..3], &[1.0, -2.0, 3.0]);
+
+        let err = run_expr(&Expr::col("vwap"), prices()).unwrap_err();
+        assert_eq!(
+            err,
+            ExecError::Eval(EvalError::UnknownColumn("vwap".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_ln_agrees_across_paths_at_zero() {
+        use crate::builtins::ln_column;
+        use crate::builtins::test_util::assert_f64_close;
+
+        let nan = f64::NAN;
+        let data = vec![0.0, -0.0, -2.0, 1.0, nan];
+        let expected = [nan, nan, nan, 0.0, nan];
+        let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+        let e = Expr::Ln(Box::new(Expr::col("x")));
+
+        // Column kernel, tree-walking evaluator and fused run_expr
+        assert_f64_close(ln_column(&Column::F64(data)).f64_data(), &expected, 0.0);
+        assert_f64_close(eval(&e, &input).unwrap().f64_data(), &expected, 0.0);
+        assert_f64_close(
+            run_expr(&e, input.clone()).unwrap().f64_data(),
+            &expected,
+            0.0,
+        );
+
+        // Unfused executor op
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Generic("ln".to_string()),
+            args: vec![],
+        });
+        let out = run(&ir, input.clone()).unwrap();
+        assert_f64_close(out.columns[0].f64_data(), &expected, 0.0);
+
+        // Constant folding: ln(0) * x has no finite value either
+        let folded = Expr::Mul(
+            Box::new(Expr::Ln(Box::new(Expr::Const(0.0)))),
+            Box::new(Expr::col("x")),
+        );
+        let out = run_expr(&folded, input.clone()).unwrap();
+        assert!(out.f64_data().iter().all(|v| v.is_nan()));
+        assert!(eval(&folded, &input)
+            .unwrap()
+            .f64_data()
+            .iter()
+            .all(|v| v.is_nan()));
+    }
+}
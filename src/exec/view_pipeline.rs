@@ -0,0 +1,197 @@
+//! Fluent, deferred pipelines over a `TableView`
+//!
+//! `view.dlog().mul(2.0).collect()` records steps into a `PipeIR` and only
+//! plans and runs it (fused, via `run`) at `collect`. Nothing is
+//! materialized in between.
+
+use super::{run, ExecError};
+use crate::pipeline::{OpId, PipeIR, Step};
+use crate::table::{Ori, Table, TableView};
+use std::sync::Arc;
+
+/// Deferred op chain over a view; built by `TableView::pipe` or an op
+/// shortcut such as `TableView::dlog`
+#[derive(Clone, Debug)]
+pub struct ViewPipeline {
+    view: TableView,
+    ir: PipeIR,
+}
+
+// add/sub/mul/div mirror the pipeline op names; they take a constant, not
+// another pipeline, so the std::ops traits would be misleading
+#[allow(clippy::should_implement_trait)]
+impl ViewPipeline {
+    /// Empty pipeline starting in the view's orientation
+    pub fn new(view: TableView) -> Self {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(view.ori));
+        ViewPipeline { view, ir }
+    }
+
+    /// Recorded steps (starts with `OriSet` of the view's orientation)
+    pub fn ir(&self) -> &PipeIR {
+        &self.ir
+    }
+
+    fn op(mut self, name: OpId, args: Vec<f64>) -> Self {
+        self.ir.push(Step::Op { name, args });
+        self
+    }
+
+    /// Set orientation: `(o ori)`
+    pub fn o(mut self, ori: Ori) -> Self {
+        self.ir.push(Step::OriSet(ori));
+        self
+    }
+
+    /// Log returns with lag 1: `(dlog)`
+    pub fn dlog(self) -> Self {
+        self.op(OpId::Dlog, vec![])
+    }
+
+    /// Trailing rolling mean over [i-window+1, i], as `ori_ops::wmean`:
+    /// `(wmean window)`
+    ///
+    /// A window of 5 records the fusable `W5`. A window of 0 makes
+    /// `collect` fail with a plan error.
+    pub fn wmean(self, window: usize) -> Self {
+        let name = if window == 5 {
+            OpId::W5
+        } else {
+            OpId::WMean(window)
+        };
+        self.op(name, vec![])
+    }
+
+    /// Add a constant: `(x+ c)`
+    pub fn add(self, c: f64) -> Self {
+        self.op(OpId::AddConst, vec![c])
+    }
+
+    /// Subtract a constant: `(x- c)`
+    pub fn sub(self, c: f64) -> Self {
+        self.op(OpId::SubConst, vec![c])
+    }
+
+    /// Multiply by a constant: `(x* c)`
+    pub fn mul(self, c: f64) -> Self {
+        self.op(OpId::MulConst, vec![c])
+    }
+
+    /// Divide by a constant: `(x/ c)`
+    pub fn div(self, c: f64) -> Self {
+        self.op(OpId::DivConst, vec![c])
+    }
+
+    /// Plan and execute the recorded steps on the view's table
+    ///
+    /// The table is cloned only if the view's `Arc` is shared.
+    ///
+    /// # Errors
+    /// As `run`.
+    pub fn collect(self) -> Result<Table, ExecError> {
+        let table = Arc::try_unwrap(self.view.table).unwrap_or_else(|t| (*t).clone());
+        run(&self.ir, table)
+    }
+}
+
+impl TableView {
+    /// Start a deferred pipeline on this view (see `ViewPipeline`)
+    pub fn pipe(&self) -> ViewPipeline {
+        ViewPipeline::new(self.clone())
+    }
+
+    /// Shortcut for `self.pipe().dlog()`
+    pub fn dlog(&self) -> ViewPipeline {
+        self.pipe().dlog()
+    }
+
+    /// Shortcut for `self.pipe().wmean(window)`
+    pub fn wmean(&self, window: usize) -> ViewPipeline {
+        self.pipe().wmean(window)
+    }
+
+    /// Shortcut for `self.pipe().add(c)`
+    pub fn add(&self, c: f64) -> ViewPipeline {
+        self.pipe().add(c)
+    }
+
+    /// Shortcut for `self.pipe().mul(c)`
+    pub fn mul(&self, c: f64) -> ViewPipeline {
+        self.pipe().mul(c)
+    }
+
+    /// Shortcut for `self.pipe().o(ori)`
+    pub fn o(&self, ori: Ori) -> ViewPipeline {
+        self.pipe().o(ori)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtins::ori_ops;
+    use crate::pipeline::PlanError;
+    use crate::table::{Column, ORI_H};
+
+    fn view() -> TableView {
+        TableView::new(Table::new(
+            vec!["x".to_string()],
+            vec![Column::F64(vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0])],
+        ))
+    }
+
+    #[test]
+    fn test_steps_recorded_in_order() {
+        let p = view().o(ORI_H).add(1.0).wmean(5);
+        let steps = &p.ir().steps;
+        assert_eq!(steps.len(), 4);
+        assert!(matches!(steps[0], Step::OriSet(o) if o == ORI_H));
+        assert!(matches!(&steps[2], Step::Op { name: OpId::AddConst, args } if args == &[1.0]));
+        assert!(matches!(&steps[3], Step::Op { name: OpId::W5, .. }));
+        assert!(matches!(
+            &view().wmean(3).ir().steps[1],
+            Step::Op {
+                name: OpId::WMean(3),
+                ..
+            }
+        ));
+
+        assert_eq!(p.collect().unwrap().row_count(), 6);
+    }
+
+    #[test]
+    fn test_wmean_any_window() {
+        // Mean of rows [i-2, i], NaN until 3 are available
+        let out = view().wmean(3).collect().unwrap();
+        let x = out.columns[0].f64_data();
+        assert!(x[..2].iter().all(|v| v.is_nan()));
+        assert_eq!(&x[2..], &[7.0 / 3.0, 14.0 / 3.0, 28.0 / 3.0, 56.0 / 3.0]);
+    }
+
+    #[test]
+    fn test_wmean_matches_ori_ops() {
+        let v = TableView::new(Table::new(
+            vec!["x".to_string(), "y".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0, f64::NAN, 8.0, 16.0, 32.0, 64.0]),
+                Column::F64(vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0]),
+            ],
+        ));
+        for window in [1, 2, 3, 5, 7] {
+            let fluent = v.wmean(window).collect().unwrap();
+            let direct = ori_ops::wmean(&v, window);
+            for (a, b) in fluent.columns.iter().zip(&direct.columns) {
+                for (x, y) in a.f64_data().iter().zip(b.f64_data()) {
+                    assert!(x == y || (x.is_nan() && y.is_nan()), "window {}", window);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_collect_errors() {
+        let err = view().wmean(0).collect().unwrap_err();
+        assert!(matches!(err, ExecError::Plan(PlanError::InvalidOp { .. })));
+    }
+}
@@ -0,0 +1,41 @@
+//! Expression AST over table columns
+
+/// Column formula: leaves are columns and constants, nodes are kernels
+///
+/// Example: `(close - open) * 2`
+/// ```
+/// use blawktrust::expr::Expr;
+///
+/// let e = Expr::Mul(
+///     Box::new(Expr::Sub(Box::new(Expr::col("close")), Box::new(Expr::col("open")))),
+///     Box::new(Expr::Const(2.0)),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    /// Column reference by name (must be F64)
+    Col(String),
+    /// Scalar constant, broadcast against columns
+    Const(f64),
+    /// Elementwise a + b
+    Add(Box<Expr>, Box<Expr>),
+    /// Elementwise a - b
+    Sub(Box<Expr>, Box<Expr>),
+    /// Elementwise a * b
+    Mul(Box<Expr>, Box<Expr>),
+    /// Elementwise a / b
+    Div(Box<Expr>, Box<Expr>),
+    /// Log return with lag: ln(x[i]) - ln(x[i-lag])
+    Dlog(Box<Expr>, usize),
+    /// Natural logarithm
+    Ln(Box<Expr>),
+    /// Absolute value
+    Abs(Box<Expr>),
+}
+
+impl Expr {
+    /// Column reference
+    pub fn col(name: &str) -> Self {
+        Expr::Col(name.to_string())
+    }
+}
@@ -0,0 +1,280 @@
+//! Lowering of linear `Expr` chains to a `PipeIR`
+//!
+//! A chain is one column leaf threaded through unary kernels and binary ops
+//! against constants, e.g. `(dlog(x) * 2) + 1`. It lowers to
+//! `OriSet(H)`, a `Select` of the leaf column, then one `Op` per node, so
+//! the planner can fuse the chain.
+//! Constant-only subtrees are folded first.
+
+use super::ast::Expr;
+use crate::builtins::kernels_masked::ln_value;
+use crate::pipeline::{OpId, PipeIR, Step};
+use crate::table::ORI_H;
+use std::fmt;
+
+/// Expression that cannot be lowered to a single-column pipeline
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompileError {
+    /// Binary node with a column on both sides (e.g. `close - open`)
+    NonLinear { node: Expr },
+    /// Node with no pipeline op (e.g. `c / x`)
+    Unsupported { node: Expr, reason: &'static str },
+    /// Expression has no column leaf
+    NoColumn,
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompileError::NonLinear { node } => {
+                write!(
+                    f,
+                    "non-linear expression: both operands of {:?} read columns",
+                    node
+                )
+            }
+            CompileError::Unsupported { node, reason } => {
+                write!(f, "unsupported expression {:?}: {}", node, reason)
+            }
+            CompileError::NoColumn => write!(f, "expression has no column to transform"),
+        }
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+/// Compile a single-column chain to `(o H)`, a selection of the leaf
+/// column, and one op per node
+///
+/// The IR narrows its input to the leaf column, so it gives the same
+/// single-column result as `eval` on any table holding that column.
+///
+/// Example: `Mul(Dlog(Col("x"), 1), Const(2))` lowers to
+/// `OriSet(H), Op(Select("x")), Op(Dlog, [1]), Op(MulConst, [2])`.
+pub fn compile(expr: &Expr) -> Result<PipeIR, CompileError> {
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    lower(expr, &mut ir)?;
+    Ok(ir)
+}
+
+/// Value of a constant-only subtree
+fn fold(expr: &Expr) -> Option<f64> {
+    let both = |a: &Expr, b: &Expr| Some((fold(a)?, fold(b)?));
+    match expr {
+        Expr::Col(_) | Expr::Dlog(..) => None,
+        Expr::Const(c) => Some(*c),
+        Expr::Add(a, b) => both(a, b).map(|(x, y)| x + y),
+        Expr::Sub(a, b) => both(a, b).map(|(x, y)| x - y),
+        Expr::Mul(a, b) => both(a, b).map(|(x, y)| x * y),
+        Expr::Div(a, b) => both(a, b).map(|(x, y)| x / y),
+        Expr::Ln(a) => fold(a).map(ln_value),
+        Expr::Abs(a) => fold(a).map(f64::abs),
+    }
+}
+
+fn op(ir: &mut PipeIR, name: OpId, args: Vec<f64>) {
+    ir.push(Step::Op { name, args });
+}
+
+/// Emit the ops for `expr` after its column operand's ops
+fn lower(expr: &Expr, ir: &mut PipeIR) -> Result<(), CompileError> {
+    if fold(expr).is_some() {
+        return Err(CompileError::NoColumn);
+    }
+
+    match expr {
+        Expr::Col(name) => {
+            op(ir, OpId::Select(name.clone()), vec![]);
+            Ok(())
+        }
+        Expr::Const(_) => unreachable!("constants fold"),
+        Expr::Dlog(a, lag) => {
+            lower(a, ir)?;
+            op(ir, OpId::Dlog, vec![*lag as f64]);
+            Ok(())
+        }
+        Expr::Ln(a) => {
+            lower(a, ir)?;
+            op(ir, OpId::Ln, vec![]);
+            Ok(())
+        }
+        Expr::Abs(a) => {
+            lower(a, ir)?;
+            op(ir, OpId::Abs, vec![]);
+            Ok(())
+        }
+        Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+            match (fold(a), fold(b)) {
+                (None, Some(c)) => {
+                    lower(a, ir)?;
+                    let name = match expr {
+                        Expr::Add(..) => OpId::AddConst,
+                        Expr::Sub(..) => OpId::SubConst,
+                        Expr::Mul(..) => OpId::MulConst,
+                        _ => OpId::DivConst,
+                    };
+                    op(ir, name, vec![c]);
+                }
+                (Some(c), None) => {
+                    lower(b, ir)?;
+                    match expr {
+                        Expr::Add(..) => op(ir, OpId::AddConst, vec![c]),
+                        Expr::Mul(..) => op(ir, OpId::MulConst, vec![c]),
+                        // c - x = -x + c
+                        Expr::Sub(..) => {
+                            op(ir, OpId::MulConst, vec![-1.0]);
+                            op(ir, OpId::AddConst, vec![c]);
+                        }
+                        _ => {
+                            return Err(CompileError::Unsupported {
+                                node: expr.clone(),
+                                reason: "constant divided by a column",
+                            })
+                        }
+                    }
+                }
+                _ => return Err(CompileError::NonLinear { node: expr.clone() }),
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::eval;
+    use crate::pipeline::{ExecutionValue, Executor, Planner};
+    use crate::table::{Column, Table};
+
+    fn x() -> Box<Expr> {
+        Box::new(Expr::col("x"))
+    }
+
+    fn c(v: f64) -> Box<Expr> {
+        Box::new(Expr::Const(v))
+    }
+
+    fn input() -> Table {
+        Table::new(
+            vec!["x".to_string()],
+            vec![Column::F64(vec![
+                100.0,
+                101.5,
+                99.0,
+                f64::NAN,
+                102.0,
+                104.5,
+                103.0,
+            ])],
+        )
+    }
+
+    fn assert_compiled_matches_eval(e: &Expr) {
+        let ir = compile(e).unwrap();
+        let plan = Planner::plan(&ir).unwrap();
+        let out = match Executor::new().execute(&plan, input()).unwrap().value {
+            ExecutionValue::Table(t) => t,
+            other => panic!("Expected Table result, got {:?}", other),
+        };
+        let expected = eval(e, &input()).unwrap();
+
+        let (a, b) = (out.columns[0].f64_data(), expected.f64_data());
+        assert_eq!(a.len(), b.len());
+        for (i, (u, v)) in a.iter().zip(b).enumerate() {
+            assert!(
+                (u.is_nan() && v.is_nan()) || (u - v).abs() < 1e-12,
+                "{:?} row {}: {} vs {}",
+                e,
+                i,
+                u,
+                v
+            );
+        }
+    }
+
+    #[test]
+    fn test_compile_lowers_to_steps() {
+        let e = Expr::Mul(Box::new(Expr::Dlog(x(), 1)), c(2.0));
+        let ir = compile(&e).unwrap();
+        assert_eq!(
+            ir.steps,
+            vec![
+                Step::OriSet(ORI_H),
+                Step::Op {
+                    name: OpId::Select("x".to_string()),
+                    args: vec![]
+                },
+                Step::Op {
+                    name: OpId::Dlog,
+                    args: vec![1.0]
+                },
+                Step::Op {
+                    name: OpId::MulConst,
+                    args: vec![2.0]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compiled_plan_matches_eval() {
+        let exprs = [
+            Expr::Mul(Box::new(Expr::Dlog(x(), 1)), c(2.0)),
+            Expr::Add(c(1.0), Box::new(Expr::Div(x(), c(100.0)))),
+            Expr::Sub(c(100.0), x()),
+            Expr::Abs(Box::new(Expr::Dlog(x(), 2))),
+            Expr::Ln(Box::new(Expr::Mul(
+                x(),
+                Box::new(Expr::Add(c(1.0), c(1.0))),
+            ))),
+        ];
+        for e in &exprs {
+            assert_compiled_matches_eval(e);
+        }
+    }
+
+    #[test]
+    fn test_compiled_plan_selects_leaf_column() {
+        let table = Table::new(
+            vec!["x".to_string(), "y".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0, 4.0]),
+                Column::F64(vec![10.0, 20.0, 40.0]),
+            ],
+        );
+        let e = Expr::Mul(Box::new(Expr::col("y")), c(2.0));
+        let plan = Planner::plan(&compile(&e).unwrap()).unwrap();
+        let out = match Executor::new().execute(&plan, table.clone()).unwrap().value {
+            ExecutionValue::Table(t) => t,
+            other => panic!("Expected Table result, got {:?}", other),
+        };
+
+        assert_eq!(out.names, vec!["y"]);
+        assert_eq!(out.columns[0].f64_data(), &[20.0, 40.0, 80.0]);
+        assert_eq!(eval(&e, &table).unwrap().f64_data(), &[20.0, 40.0, 80.0]);
+
+        let missing = Planner::plan(&compile(&Expr::col("z")).unwrap()).unwrap();
+        let err = Executor::new().execute(&missing, table).err().unwrap();
+        assert_eq!(err, "select: column 'z' not found");
+    }
+
+    #[test]
+    fn test_compile_errors_point_at_node() {
+        let node = Expr::Sub(Box::new(Expr::col("close")), Box::new(Expr::col("open")));
+        let e = Expr::Mul(Box::new(node.clone()), c(2.0));
+        assert_eq!(compile(&e).unwrap_err(), CompileError::NonLinear { node });
+
+        let node = Expr::Div(c(1.0), x());
+        assert!(matches!(
+            compile(&Expr::Ln(Box::new(node.clone()))),
+            Err(CompileError::Unsupported { node: n, .. }) if n == node
+        ));
+
+        assert_eq!(
+            compile(&Expr::Add(c(1.0), c(2.0))).unwrap_err(),
+            CompileError::NoColumn
+        );
+    }
+}
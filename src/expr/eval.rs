@@ -0,0 +1,191 @@
+//! Tree-walking evaluator for `Expr`
+
+use super::ast::Expr;
+use crate::builtins::kernels_masked::ln_value;
+use crate::builtins::{abs_column, dlog_column, ln_column};
+use crate::table::{Column, Table};
+use std::fmt;
+
+/// Expression evaluation error
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalError {
+    /// `Col(name)` does not exist in the table
+    UnknownColumn(String),
+    /// `Col(name)` is not an F64 column
+    NotF64 { name: String, actual: &'static str },
+    /// Binary op on two columns of different lengths
+    LengthMismatch { left: usize, right: usize },
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UnknownColumn(name) => write!(f, "unknown column '{}'", name),
+            EvalError::NotF64 { name, actual } => {
+                write!(f, "column '{}' is {}, expected F64", name, actual)
+            }
+            EvalError::LengthMismatch { left, right } => {
+                write!(f, "column length mismatch: {} vs {}", left, right)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// Intermediate value: scalars stay scalar until they meet a column
+enum Value {
+    Scalar(f64),
+    Column(Vec<f64>),
+}
+
+impl Value {
+    fn into_column(self, len: usize) -> Vec<f64> {
+        match self {
+            Value::Scalar(c) => vec![c; len],
+            Value::Column(data) => data,
+        }
+    }
+}
+
+/// Evaluate `expr` against `table` into an F64 column
+///
+/// Binary ops broadcast a scalar against a column or combine two
+/// equal-length columns elementwise. A pure-constant expression yields a
+/// column of `table.row_count()` copies. NaN propagates per IEEE 754.
+pub fn eval(expr: &Expr, table: &Table) -> Result<Column, EvalError> {
+    let data = eval_value(expr, table)?.into_column(table.row_count());
+    Ok(Column::F64(data))
+}
+
+fn eval_value(expr: &Expr, table: &Table) -> Result<Value, EvalError> {
+    match expr {
+        Expr::Col(name) => {
+            let col = table
+                .column(name)
+                .ok_or_else(|| EvalError::UnknownColumn(name.clone()))?;
+            let data = col.as_f64_slice().map_err(|_| EvalError::NotF64 {
+                name: name.clone(),
+                actual: col.type_name(),
+            })?;
+            Ok(Value::Column(data.to_vec()))
+        }
+        Expr::Const(c) => Ok(Value::Scalar(*c)),
+        Expr::Add(a, b) => binary(a, b, table, |x, y| x + y),
+        Expr::Sub(a, b) => binary(a, b, table, |x, y| x - y),
+        Expr::Mul(a, b) => binary(a, b, table, |x, y| x * y),
+        Expr::Div(a, b) => binary(a, b, table, |x, y| x / y),
+        Expr::Dlog(a, lag) => {
+            let data = eval_value(a, table)?.into_column(table.row_count());
+            Ok(Value::Column(
+                dlog_column(&Column::F64(data), *lag).f64_data().to_vec(),
+            ))
+        }
+        Expr::Ln(a) => unary(a, table, ln_value, ln_column),
+        Expr::Abs(a) => unary(a, table, f64::abs, abs_column),
+    }
+}
+
+fn unary(
+    a: &Expr,
+    table: &Table,
+    scalar: fn(f64) -> f64,
+    kernel: fn(&Column) -> Column,
+) -> Result<Value, EvalError> {
+    Ok(match eval_value(a, table)? {
+        Value::Scalar(c) => Value::Scalar(scalar(c)),
+        Value::Column(data) => Value::Column(kernel(&Column::F64(data)).f64_data().to_vec()),
+    })
+}
+
+fn binary(
+    a: &Expr,
+    b: &Expr,
+    table: &Table,
+    f: impl Fn(f64, f64) -> f64,
+) -> Result<Value, EvalError> {
+    let (a, b) = (eval_value(a, table)?, eval_value(b, table)?);
+    Ok(match (a, b) {
+        (Value::Scalar(x), Value::Scalar(y)) => Value::Scalar(f(x, y)),
+        (Value::Column(xs), Value::Scalar(y)) => {
+            Value::Column(xs.into_iter().map(|x| f(x, y)).collect())
+        }
+        (Value::Scalar(x), Value::Column(ys)) => {
+            Value::Column(ys.into_iter().map(|y| f(x, y)).collect())
+        }
+        (Value::Column(xs), Value::Column(ys)) => {
+            if xs.len() != ys.len() {
+                return Err(EvalError::LengthMismatch {
+                    left: xs.len(),
+                    right: ys.len(),
+                });
+            }
+            Value::Column(xs.iter().zip(&ys).map(|(&x, &y)| f(x, y)).collect())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bars() -> Table {
+        Table::new(
+            vec!["open".to_string(), "close".to_string(), "d".to_string()],
+            vec![
+                Column::F64(vec![10.0, 11.0, 12.0]),
+                Column::F64(vec![11.0, 10.5, f64::NAN]),
+                Column::Date(vec![1, 2, 3]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_eval_close_minus_open_times_two() {
+        let e = Expr::Mul(
+            Box::new(Expr::Sub(
+                Box::new(Expr::col("close")),
+                Box::new(Expr::col("open")),
+            )),
+            Box::new(Expr::Const(2.0)),
+        );
+        let out = eval(&e, &bars()).unwrap();
+        let data = out.f64_data();
+        assert_eq!(&data[..2], &[2.0, -1.0]);
+        assert!(data[2].is_nan());
+    }
+
+    #[test]
+    fn test_eval_dlog_close() {
+        let e = Expr::Dlog(Box::new(Expr::col("close")), 1);
+        let out = eval(&e, &bars()).unwrap();
+        let expected = dlog_column(bars().column("close").unwrap(), 1);
+        let (a, b) = (out.f64_data(), expected.f64_data());
+        assert!(a[0].is_nan() && b[0].is_nan());
+        assert_eq!(a[1], b[1]);
+        assert!(a[2].is_nan());
+    }
+
+    #[test]
+    fn test_eval_scalar_and_unary() {
+        let e = Expr::Abs(Box::new(Expr::Sub(
+            Box::new(Expr::Const(10.0)),
+            Box::new(Expr::col("close")),
+        )));
+        let out = eval(&e, &bars()).unwrap();
+        assert_eq!(&out.f64_data()[..2], &[1.0, 0.5]);
+
+        let out = eval(&Expr::Ln(Box::new(Expr::Const(1.0))), &bars()).unwrap();
+        assert_eq!(out.f64_data(), &[0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let err = eval(&Expr::col("vwap"), &bars()).unwrap_err();
+        assert_eq!(err, EvalError::UnknownColumn("vwap".to_string()));
+        assert_eq!(err.to_string(), "unknown column 'vwap'");
+
+        let err = eval(&Expr::col("d"), &bars()).unwrap_err();
+        assert!(matches!(err, EvalError::NotF64 { actual: "Date", .. }));
+    }
+}
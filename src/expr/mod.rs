@@ -1 +1,14 @@
+//! Column expressions: a typed formula AST and its evaluator
+//!
+//! `Expr` trees are evaluated recursively against a `Table` by `eval`,
+//! each node dispatching to the column kernels in `builtins`. Linear
+//! single-column chains can instead be lowered by `compile` to a `PipeIR`
+//! and run fused through the planner and executor.
 
+pub mod ast;
+pub mod compile;
+pub mod eval;
+
+pub use ast::Expr;
+pub use compile::{compile, CompileError};
+pub use eval::{eval, EvalError};
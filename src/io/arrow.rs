@@ -0,0 +1,357 @@
+//! Arrow IPC export and import (feature `arrow`)
+//!
+//! Columns store nulls as embedded sentinels; Arrow uses validity bitmaps.
+//! Export scans each column for its sentinel and builds the null buffer,
+//! leaving the value buffer as-is (nulls keep their sentinel bits). Import
+//! does the reverse, writing the sentinel wherever the bitmap marks a null.
+
+use super::IoError;
+use crate::table::{
+    Column, SymBuilder, Table, NULL_DATE, NULL_I64, NULL_SYM, NULL_TIMESTAMP, NULL_TS,
+};
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Date32Array, DictionaryArray, Float32Array, Float64Array,
+    Int64Array, StringArray, TimestampNanosecondArray, UInt32Array,
+};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit, UInt32Type};
+use arrow::ipc::reader::StreamReader;
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatch;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+/// Write a Table as a single-batch Arrow IPC stream
+///
+/// Type mapping: F64 → Float64, F32 → Float32, Date → Date32, Timestamp/Ts →
+/// Timestamp(Nanosecond), I64 → Int64, Bool → Boolean, Sym →
+/// Dictionary(UInt32, Utf8). Sentinel values
+/// (NaN, NULL_DATE, NULL_TIMESTAMP, ...) become Arrow nulls.
+pub fn to_arrow_ipc(table: &Table, writer: &mut impl Write) -> Result<(), IoError> {
+    let batch = to_record_batch(table)?;
+    let mut stream = StreamWriter::try_new(writer, &batch.schema())?;
+    stream.write(&batch)?;
+    stream.finish()?;
+    Ok(())
+}
+
+/// Convert a Table to an Arrow RecordBatch (sentinels → validity bitmap)
+pub fn to_record_batch(table: &Table) -> Result<RecordBatch, IoError> {
+    let mut fields = Vec::with_capacity(table.col_count());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(table.col_count());
+
+    for (name, col) in table.names.iter().zip(&table.columns) {
+        let array: ArrayRef = match col {
+            Column::F64(data) => Arc::new(Float64Array::new(
+                data.clone().into(),
+                nulls_from(data, |x| x.is_nan()),
+            )),
+            Column::Date(data) => Arc::new(Date32Array::new(
+                data.clone().into(),
+                nulls_from(data, |&x| x == NULL_DATE),
+            )),
+            Column::Timestamp(data) => Arc::new(TimestampNanosecondArray::new(
+                data.clone().into(),
+                nulls_from(data, |&x| x == NULL_TIMESTAMP),
+            )),
+            Column::Ts(data) => Arc::new(TimestampNanosecondArray::new(
+                data.clone().into(),
+                nulls_from(data, |&x| x == NULL_TS),
+            )),
+            Column::I64(data) => Arc::new(Int64Array::new(
+                data.clone().into(),
+                nulls_from(data, |&x| x == NULL_I64),
+            )),
+            Column::F32(data) => Arc::new(Float32Array::new(
+                data.clone().into(),
+                nulls_from(data, |x| x.is_nan()),
+            )),
+            Column::Bool(data) => Arc::new(BooleanArray::from(data.clone())),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => Arc::new(Float64Array::new(
+                m.as_slice().to_vec().into(),
+                nulls_from(m.as_slice(), |x| x.is_nan()),
+            )),
+            Column::Sym { codes, dict } => Arc::new(DictionaryArray::<UInt32Type>::try_new(
+                UInt32Array::new(codes.clone().into(), nulls_from(codes, |&c| c == NULL_SYM)),
+                Arc::new(StringArray::from(dict.to_vec())),
+            )?),
+        };
+        fields.push(Field::new(
+            name,
+            array.data_type().clone(),
+            array.null_count() > 0,
+        ));
+        arrays.push(array);
+    }
+
+    let schema = Arc::new(Schema::new(fields));
+    Ok(RecordBatch::try_new(schema, arrays)?)
+}
+
+/// Validity buffer for a sentinel-encoded slice (None when there are no nulls)
+fn nulls_from<T>(data: &[T], is_null: impl Fn(&T) -> bool) -> Option<NullBuffer> {
+    if !data.iter().any(&is_null) {
+        return None;
+    }
+    Some(NullBuffer::from_iter(data.iter().map(|x| !is_null(x))))
+}
+
+/// Read an Arrow IPC stream into a Table
+///
+/// Type mapping: Float64 → F64, Float32 → F32, Date32 → Date,
+/// Timestamp(Nanosecond, any tz) → Timestamp, Int64 → I64, Boolean → Bool,
+/// Utf8 and Dictionary(UInt32, Utf8) → Sym.
+/// Arrow nulls become the column's sentinel (NaN, NULL_DATE,
+/// NULL_TIMESTAMP, NULL_I64, NULL_SYM); Boolean nulls become `false`. Multiple
+/// batches are concatenated in order.
+///
+/// # Errors
+/// `IoError::Schema` naming the column for any other Arrow type;
+/// `IoError::Arrow` if the stream is malformed.
+pub fn from_arrow_ipc(reader: &mut impl Read) -> Result<Table, IoError> {
+    let stream = StreamReader::try_new(reader, None)?;
+    let schema = stream.schema();
+
+    let mut tables = Vec::new();
+    for batch in stream {
+        tables.push(from_record_batch(&batch?)?);
+    }
+
+    if tables.is_empty() {
+        // Schema only: empty columns of the declared types
+        let empty = RecordBatch::new_empty(schema);
+        return from_record_batch(&empty);
+    }
+    let refs: Vec<&Table> = tables.iter().collect();
+    Table::concat_rows(&refs).map_err(IoError::Schema)
+}
+
+/// Convert an Arrow RecordBatch to a Table (validity bitmap → sentinels)
+pub fn from_record_batch(batch: &RecordBatch) -> Result<Table, IoError> {
+    let schema = batch.schema();
+    let mut names = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+
+    for (field, array) in schema.fields().iter().zip(batch.columns()) {
+        let any = array.as_any();
+        let unsupported = || {
+            IoError::Schema(format!(
+                "column '{}': unsupported Arrow type {}",
+                field.name(),
+                array.data_type()
+            ))
+        };
+
+        let column = match array.data_type() {
+            DataType::Float64 => {
+                let a = any.downcast_ref::<Float64Array>().ok_or_else(unsupported)?;
+                Column::F64(a.iter().map(|v| v.unwrap_or(f64::NAN)).collect())
+            }
+            DataType::Float32 => {
+                let a = any.downcast_ref::<Float32Array>().ok_or_else(unsupported)?;
+                Column::F32(a.iter().map(|v| v.unwrap_or(f32::NAN)).collect())
+            }
+            DataType::Date32 => {
+                let a = any.downcast_ref::<Date32Array>().ok_or_else(unsupported)?;
+                Column::Date(a.iter().map(|v| v.unwrap_or(NULL_DATE)).collect())
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                let a = any
+                    .downcast_ref::<TimestampNanosecondArray>()
+                    .ok_or_else(unsupported)?;
+                Column::Timestamp(a.iter().map(|v| v.unwrap_or(NULL_TIMESTAMP)).collect())
+            }
+            DataType::Int64 => {
+                let a = any.downcast_ref::<Int64Array>().ok_or_else(unsupported)?;
+                Column::I64(a.iter().map(|v| v.unwrap_or(NULL_I64)).collect())
+            }
+            DataType::Boolean => {
+                let a = any.downcast_ref::<BooleanArray>().ok_or_else(unsupported)?;
+                Column::Bool(a.iter().map(|v| v.unwrap_or(false)).collect())
+            }
+            DataType::Utf8 => {
+                let a = any.downcast_ref::<StringArray>().ok_or_else(unsupported)?;
+                a.iter().collect::<SymBuilder>().finish()
+            }
+            DataType::Dictionary(key, value)
+                if **key == DataType::UInt32 && **value == DataType::Utf8 =>
+            {
+                let a = any
+                    .downcast_ref::<DictionaryArray<UInt32Type>>()
+                    .ok_or_else(unsupported)?;
+                let values = a
+                    .values()
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .ok_or_else(unsupported)?;
+                // Re-intern: an Arrow dictionary may repeat or null its values
+                a.keys()
+                    .iter()
+                    .map(|k| {
+                        let k = k? as usize;
+                        values.is_valid(k).then(|| values.value(k))
+                    })
+                    .collect::<SymBuilder>()
+                    .finish()
+            }
+            _ => return Err(unsupported()),
+        };
+
+        names.push(field.name().clone());
+        columns.push(column);
+    }
+
+    Ok(Table::new(names, columns))
+}
+
+/// Arrow type produced for a column (see `to_arrow_ipc`)
+pub fn arrow_type(col: &Column) -> DataType {
+    match col {
+        Column::F64(_) => DataType::Float64,
+        Column::Date(_) => DataType::Date32,
+        Column::Timestamp(_) | Column::Ts(_) => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        Column::I64(_) => DataType::Int64,
+        Column::F32(_) => DataType::Float32,
+        Column::Bool(_) => DataType::Boolean,
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(_) => DataType::Float64,
+        Column::Sym { .. } => {
+            DataType::Dictionary(Box::new(DataType::UInt32), Box::new(DataType::Utf8))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int32Array;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_arrow_ipc_round_trip_schema_and_nulls() {
+        let table = Table::new(
+            vec![
+                "px".to_string(),
+                "date".to_string(),
+                "ts".to_string(),
+                "n".to_string(),
+            ],
+            vec![
+                Column::F64(vec![1.5, f64::NAN, 3.0]),
+                Column::Date(vec![18628, 18629, NULL_DATE]),
+                Column::Timestamp(vec![NULL_TIMESTAMP, 1, 2]),
+                Column::I64(vec![7, 8, 9]),
+            ],
+        );
+
+        let mut buf = Vec::new();
+        to_arrow_ipc(&table, &mut buf).unwrap();
+
+        let reader = StreamReader::try_new(Cursor::new(buf), None).unwrap();
+        let schema = reader.schema();
+        let types: Vec<DataType> = schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        let expected: Vec<DataType> = table.columns.iter().map(arrow_type).collect();
+        assert_eq!(types, expected);
+        assert_eq!(schema.field(0).name(), "px");
+
+        let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+
+        let px = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert!(px.is_valid(0) && px.is_null(1) && px.is_valid(2));
+        assert_eq!(px.value(2), 3.0);
+
+        let date = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(date.value(1), 18629);
+        assert!(date.is_null(2));
+
+        let ts = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert!(ts.is_null(0));
+        assert_eq!(ts.value(2), 2);
+
+        assert_eq!(batch.column(3).null_count(), 0);
+    }
+
+    #[test]
+    fn test_from_arrow_ipc_round_trip() {
+        let table = Table::new(
+            vec![
+                "px".to_string(),
+                "date".to_string(),
+                "ts".to_string(),
+                "n".to_string(),
+                "w".to_string(),
+                "flag".to_string(),
+                "sym".to_string(),
+            ],
+            vec![
+                Column::F64(vec![1.5, f64::NAN, 3.0]),
+                Column::Date(vec![18628, 18629, NULL_DATE]),
+                Column::Timestamp(vec![NULL_TIMESTAMP, 1, 2]),
+                Column::I64(vec![7, NULL_I64, 9]),
+                Column::F32(vec![0.5, 1.0, f32::NAN]),
+                Column::Bool(vec![true, false, true]),
+                [Some("AAPL"), None, Some("AAPL")]
+                    .into_iter()
+                    .collect::<SymBuilder>()
+                    .finish(),
+            ],
+        );
+
+        let mut buf = Vec::new();
+        to_arrow_ipc(&table, &mut buf).unwrap();
+        let back = from_arrow_ipc(&mut Cursor::new(buf)).unwrap();
+
+        assert!(table.approx_eq(&back, 0.0));
+        assert!(back.columns[0].f64_data()[1].is_nan());
+        assert_eq!(back.columns[3].i64_data()[1], NULL_I64);
+        assert_eq!(back.columns[6].sym_code(1), NULL_SYM);
+        assert_eq!(back.columns[6].sym_str(2), Some("AAPL"));
+    }
+
+    #[test]
+    fn test_from_arrow_ipc_unsupported_type() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("px", DataType::Float64, false),
+            Field::new("qty", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0])),
+                Arc::new(Int32Array::from(vec![3])),
+            ],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = StreamWriter::try_new(&mut buf, &schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let err = from_arrow_ipc(&mut Cursor::new(buf)).unwrap_err();
+        assert!(
+            matches!(&err, IoError::Schema(msg) if msg.contains("'qty'") && msg.contains("Int32"))
+        );
+    }
+}
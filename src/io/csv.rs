@@ -0,0 +1,670 @@
+//! CSV reader and writer
+//!
+//! The header row supplies column names; the caller supplies the type of
+//! each column as a `ColumnKind` schema. Empty fields and the NA token map
+//! to the column's null sentinel (NaN, NULL_DATE, NULL_TIMESTAMP, NULL_TS,
+//! NULL_I64, NULL_SYM); Bool has no null, so NA is a parse error there.
+//! The writer does the reverse: sentinels are written as the NA token.
+
+use super::IoError;
+pub use crate::table::ColumnKind;
+use crate::table::{
+    Column, SymBuilder, Table, NULL_DATE, NULL_I64, NULL_SYM, NULL_TIMESTAMP, NULL_TS,
+};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+
+/// Default NA token used by `read_csv`
+pub const DEFAULT_NA: &str = "NA";
+
+/// Read a CSV file into a Table using `schema` for column types
+///
+/// Empty fields and "NA" are read as nulls.
+pub fn read_csv(path: &Path, schema: &[ColumnKind]) -> Result<Table, IoError> {
+    let file = File::open(path)?;
+    read_csv_from(file, schema, DEFAULT_NA)
+}
+
+/// Read CSV from any reader into a Table using `schema` for column types
+///
+/// Fields equal to `na_token` (and empty fields) become the column's null
+/// sentinel (a Bool column has none, so NA there is `IoError::Parse`).
+/// Blank lines are skipped.
+pub fn read_csv_from<R: Read>(
+    reader: R,
+    schema: &[ColumnKind],
+    na_token: &str,
+) -> Result<Table, IoError> {
+    let mut lines = BufReader::new(reader).lines();
+
+    let header = match lines.next() {
+        Some(line) => line?,
+        None => return Err(IoError::Schema("missing header row".to_string())),
+    };
+    let names = split_fields(trim_eol(&header));
+    if names.len() != schema.len() {
+        return Err(IoError::Schema(format!(
+            "header has {} columns, schema has {}",
+            names.len(),
+            schema.len()
+        )));
+    }
+
+    let mut builders: Vec<Builder> = schema.iter().map(|&kind| Builder::new(kind)).collect();
+
+    for (idx, line) in lines.enumerate() {
+        let line = line?;
+        let line = trim_eol(&line);
+        if line.is_empty() {
+            continue;
+        }
+        let line_no = idx + 2; // 1-based, after header
+
+        let fields = split_fields(line);
+        if fields.len() != schema.len() {
+            return Err(IoError::Schema(format!(
+                "line {}: expected {} fields, got {}",
+                line_no,
+                schema.len(),
+                fields.len()
+            )));
+        }
+
+        for ((builder, field), name) in builders.iter_mut().zip(&fields).zip(&names) {
+            let field = field.trim();
+            let is_na = field.is_empty() || field == na_token;
+            if !builder.push(field, is_na) {
+                return Err(IoError::Parse {
+                    line: line_no,
+                    column: name.clone(),
+                    value: field.to_string(),
+                    kind: builder.kind(),
+                });
+            }
+        }
+    }
+
+    let columns = builders.into_iter().map(Builder::finish).collect();
+    Ok(Table::new(names, columns))
+}
+
+/// Write a Table as CSV, F64 values at full (round-trip) precision
+///
+/// Dates are written as `YYYY-MM-DD`, Timestamp/Ts as ISO-8601 with
+/// nanoseconds, and null sentinels as `na_token`.
+pub fn write_csv(table: &Table, writer: &mut impl Write, na_token: &str) -> Result<(), IoError> {
+    write_csv_with_precision(table, writer, na_token, None)
+}
+
+/// Write a Table as CSV with F64 values rounded to `precision` decimals
+///
+/// `None` writes the shortest representation that reads back exactly.
+pub fn write_csv_with_precision(
+    table: &Table,
+    writer: &mut impl Write,
+    na_token: &str,
+    precision: Option<usize>,
+) -> Result<(), IoError> {
+    let header: Vec<String> = table.names.iter().map(|n| quote_field(n)).collect();
+    writeln!(writer, "{}", header.join(","))?;
+
+    let mut line = String::new();
+    for row in 0..table.row_count() {
+        line.clear();
+        for (j, col) in table.columns.iter().enumerate() {
+            if j > 0 {
+                line.push(',');
+            }
+            format_cell(&mut line, col, row, na_token, precision);
+        }
+        writeln!(writer, "{}", line)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn format_cell(
+    out: &mut String,
+    col: &Column,
+    row: usize,
+    na_token: &str,
+    precision: Option<usize>,
+) {
+    use std::fmt::Write as _;
+
+    match col {
+        Column::F64(data) => {
+            let x = data[row];
+            if x.is_nan() {
+                out.push_str(na_token);
+            } else if let Some(p) = precision {
+                let _ = write!(out, "{:.*}", p, x);
+            } else {
+                let _ = write!(out, "{}", x);
+            }
+        }
+        Column::F32(data) => {
+            let x = data[row];
+            if x.is_nan() {
+                out.push_str(na_token);
+            } else if let Some(p) = precision {
+                let _ = write!(out, "{:.*}", p, x);
+            } else {
+                let _ = write!(out, "{}", x);
+            }
+        }
+        Column::Date(data) => match data[row] {
+            NULL_DATE => out.push_str(na_token),
+            d => out.push_str(&format_date(d as i64)),
+        },
+        Column::Timestamp(data) => match data[row] {
+            NULL_TIMESTAMP => out.push_str(na_token),
+            ts => out.push_str(&format_timestamp(ts)),
+        },
+        Column::Ts(data) => match data[row] {
+            NULL_TS => out.push_str(na_token),
+            ts => out.push_str(&format_timestamp(ts)),
+        },
+        Column::I64(data) => match data[row] {
+            NULL_I64 => out.push_str(na_token),
+            v => {
+                let _ = write!(out, "{}", v);
+            }
+        },
+        Column::Bool(data) => out.push_str(if data[row] { "true" } else { "false" }),
+        Column::Sym { codes, dict } => match codes[row] {
+            NULL_SYM => out.push_str(na_token),
+            c => out.push_str(&quote_field(&dict[c as usize])),
+        },
+        #[cfg(feature = "mmap")]
+        Column::F64Mmap(m) => {
+            let x = m.as_slice()[row];
+            if x.is_nan() {
+                out.push_str(na_token);
+            } else if let Some(p) = precision {
+                let _ = write!(out, "{:.*}", p, x);
+            } else {
+                let _ = write!(out, "{}", x);
+            }
+        }
+    }
+}
+
+/// Quote a field if it contains a comma, quote, or line break
+fn quote_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Per-column accumulator
+enum Builder {
+    F64(Vec<f64>),
+    F32(Vec<f32>),
+    Date(Vec<i32>),
+    Timestamp(Vec<i64>),
+    Ts(Vec<i64>),
+    I64(Vec<i64>),
+    Bool(Vec<bool>),
+    Sym(SymBuilder),
+}
+
+impl Builder {
+    fn new(kind: ColumnKind) -> Self {
+        match kind {
+            ColumnKind::F64 => Builder::F64(Vec::new()),
+            ColumnKind::F32 => Builder::F32(Vec::new()),
+            ColumnKind::Date => Builder::Date(Vec::new()),
+            ColumnKind::Timestamp => Builder::Timestamp(Vec::new()),
+            ColumnKind::Ts => Builder::Ts(Vec::new()),
+            ColumnKind::I64 => Builder::I64(Vec::new()),
+            ColumnKind::Bool => Builder::Bool(Vec::new()),
+            ColumnKind::Sym => Builder::Sym(SymBuilder::new()),
+        }
+    }
+
+    fn kind(&self) -> ColumnKind {
+        match self {
+            Builder::F64(_) => ColumnKind::F64,
+            Builder::F32(_) => ColumnKind::F32,
+            Builder::Date(_) => ColumnKind::Date,
+            Builder::Timestamp(_) => ColumnKind::Timestamp,
+            Builder::Ts(_) => ColumnKind::Ts,
+            Builder::I64(_) => ColumnKind::I64,
+            Builder::Bool(_) => ColumnKind::Bool,
+            Builder::Sym(_) => ColumnKind::Sym,
+        }
+    }
+
+    /// Parse and append one field; returns false if it doesn't parse
+    fn push(&mut self, field: &str, is_na: bool) -> bool {
+        match self {
+            Builder::F64(v) => {
+                let x = if is_na {
+                    Some(f64::NAN)
+                } else {
+                    field.parse().ok()
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::F32(v) => {
+                let x = if is_na {
+                    Some(f32::NAN)
+                } else {
+                    field.parse().ok()
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::Date(v) => {
+                let x = if is_na {
+                    Some(NULL_DATE)
+                } else {
+                    parse_date(field)
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::Timestamp(v) => {
+                let x = if is_na {
+                    Some(NULL_TIMESTAMP)
+                } else {
+                    parse_timestamp(field)
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::Ts(v) => {
+                let x = if is_na {
+                    Some(NULL_TS)
+                } else {
+                    parse_timestamp(field)
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::I64(v) => {
+                let x = if is_na {
+                    Some(NULL_I64)
+                } else {
+                    field.parse().ok()
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::Bool(v) => {
+                let x = match field {
+                    "true" => Some(true),
+                    "false" => Some(false),
+                    _ => None,
+                };
+                x.map(|x| v.push(x)).is_some()
+            }
+            Builder::Sym(b) => {
+                b.push((!is_na).then_some(field));
+                true
+            }
+        }
+    }
+
+    fn finish(self) -> Column {
+        match self {
+            Builder::F64(v) => Column::F64(v),
+            Builder::F32(v) => Column::F32(v),
+            Builder::Date(v) => Column::Date(v),
+            Builder::Timestamp(v) => Column::Timestamp(v),
+            Builder::Ts(v) => Column::Ts(v),
+            Builder::I64(v) => Column::I64(v),
+            Builder::Bool(v) => Column::Bool(v),
+            Builder::Sym(b) => b.finish(),
+        }
+    }
+}
+
+fn trim_eol(line: &str) -> &str {
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Split a CSV record on commas, honouring double-quoted fields ("" escapes a quote)
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut cur = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                cur.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut cur)),
+            _ => cur.push(c),
+        }
+    }
+    fields.push(cur);
+    fields
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian civil date
+pub(crate) fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (m as i64 + 9) % 12; // March = 0
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Civil date (year, month, day) for days since 1970-01-01
+pub(crate) fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    (y, m, d)
+}
+
+fn format_date(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// ISO-8601 `YYYY-MM-DDTHH:MM:SS.nnnnnnnnnZ`
+fn format_timestamp(ns: i64) -> String {
+    let secs = ns.div_euclid(1_000_000_000);
+    let nanos = ns.rem_euclid(1_000_000_000);
+    let days = secs.div_euclid(86_400);
+    let sod = secs.rem_euclid(86_400);
+    format!(
+        "{}T{:02}:{:02}:{:02}.{:09}Z",
+        format_date(days),
+        sod / 3600,
+        (sod % 3600) / 60,
+        sod % 60,
+        nanos
+    )
+}
+
+fn days_in_month(y: i64, m: u32) -> u32 {
+    match m {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ if (y % 4 == 0 && y % 100 != 0) || y % 400 == 0 => 29,
+        _ => 28,
+    }
+}
+
+/// Parse `YYYY-MM-DD` to days since epoch
+fn parse_date_days(s: &str) -> Option<i64> {
+    let mut parts = s.splitn(3, '-');
+    let y_str = parts.next()?;
+    let m_str = parts.next()?;
+    let d_str = parts.next()?;
+    if y_str.len() != 4 || m_str.len() != 2 || d_str.len() != 2 {
+        return None;
+    }
+    let y: i64 = y_str.parse().ok()?;
+    let m: u32 = m_str.parse().ok()?;
+    let d: u32 = d_str.parse().ok()?;
+    if !(1..=12).contains(&m) || d == 0 || d > days_in_month(y, m) {
+        return None;
+    }
+    Some(days_from_civil(y, m, d))
+}
+
+fn parse_date(s: &str) -> Option<i32> {
+    parse_date_days(s).and_then(|d| i32::try_from(d).ok())
+}
+
+/// Parse ISO-8601 `YYYY-MM-DD[(T| )HH:MM:SS[.f{1,9}]][Z]` to ns since epoch
+fn parse_timestamp(s: &str) -> Option<i64> {
+    let s = s.strip_suffix('Z').unwrap_or(s);
+    if s.len() < 10 || !s.is_char_boundary(10) {
+        return None;
+    }
+    let (date, rest) = s.split_at(10);
+    let days = parse_date_days(date)?;
+
+    let mut secs_of_day = 0i64;
+    let mut nanos = 0i64;
+    if !rest.is_empty() {
+        let time = rest.strip_prefix(['T', ' '])?;
+        let (hms, frac) = match time.split_once('.') {
+            Some((hms, frac)) => (hms, Some(frac)),
+            None => (time, None),
+        };
+        let mut hms_parts = hms.split(':');
+        let h: i64 = parse_two_digits(hms_parts.next()?)?;
+        let mi: i64 = parse_two_digits(hms_parts.next()?)?;
+        let sec: i64 = parse_two_digits(hms_parts.next()?)?;
+        if hms_parts.next().is_some() || h > 23 || mi > 59 || sec > 59 {
+            return None;
+        }
+        secs_of_day = h * 3600 + mi * 60 + sec;
+
+        if let Some(frac) = frac {
+            if frac.is_empty() || frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let digits: i64 = frac.parse().ok()?;
+            nanos = digits * 10i64.pow(9 - frac.len() as u32);
+        }
+    }
+
+    days.checked_mul(86_400)?
+        .checked_add(secs_of_day)?
+        .checked_mul(1_000_000_000)?
+        .checked_add(nanos)
+}
+
+fn parse_two_digits(s: &str) -> Option<i64> {
+    if s.len() != 2 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRICES: &str = "\
+date,close,volume,ts
+2021-01-04,100.5,1200,2021-01-04T14:30:00Z
+2021-01-05,NA,,2021-01-05T14:30:00.5
+,101.25,900,
+";
+
+    #[test]
+    fn test_read_csv_nulls_and_dates() {
+        let schema = [
+            ColumnKind::Date,
+            ColumnKind::F64,
+            ColumnKind::I64,
+            ColumnKind::Timestamp,
+        ];
+        let t = read_csv_from(PRICES.as_bytes(), &schema, "NA").unwrap();
+
+        assert_eq!(t.names, vec!["date", "close", "volume", "ts"]);
+        assert_eq!(t.row_count(), 3);
+
+        assert_eq!(t.columns[0].date_data(), &[18631, 18632, NULL_DATE]);
+
+        let close = t.columns[1].f64_data();
+        assert_eq!(close[0], 100.5);
+        assert!(close[1].is_nan());
+        assert_eq!(close[2], 101.25);
+
+        assert_eq!(t.columns[2].i64_data(), &[1200, NULL_I64, 900]);
+
+        let day_ns = 18631i64 * 86_400 * 1_000_000_000;
+        let hm_ns = (14 * 3600 + 30 * 60) * 1_000_000_000i64;
+        assert_eq!(
+            t.columns[3].timestamp_data(),
+            &[
+                day_ns + hm_ns,
+                day_ns + 86_400_000_000_000 + hm_ns + 500_000_000,
+                NULL_TIMESTAMP
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_csv_custom_na_and_quotes() {
+        let csv = "x,\"y,z\"\r\n-,\"2.5\"\r\n3,-\r\n";
+        let t = read_csv_from(csv.as_bytes(), &[ColumnKind::F64, ColumnKind::F64], "-").unwrap();
+        assert_eq!(t.names, vec!["x", "y,z"]);
+        let x = t.columns[0].f64_data();
+        let y = t.columns[1].f64_data();
+        assert!(x[0].is_nan());
+        assert_eq!(x[1], 3.0);
+        assert_eq!(y[0], 2.5);
+        assert!(y[1].is_nan());
+    }
+
+    #[test]
+    fn test_read_csv_malformed_number() {
+        let csv = "a,b\n1.0,2.0\n3.0,abc\n";
+        let err =
+            read_csv_from(csv.as_bytes(), &[ColumnKind::F64, ColumnKind::F64], "NA").unwrap_err();
+        match err {
+            IoError::Parse {
+                line,
+                column,
+                value,
+                kind,
+            } => {
+                assert_eq!(line, 3);
+                assert_eq!(column, "b");
+                assert_eq!(value, "abc");
+                assert_eq!(kind, ColumnKind::F64);
+            }
+            other => panic!("expected Parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_csv_schema_errors() {
+        let err = read_csv_from("a,b\n1,2\n".as_bytes(), &[ColumnKind::F64], "NA").unwrap_err();
+        assert!(matches!(err, IoError::Schema(_)));
+
+        let err = read_csv_from("a,b\n1\n".as_bytes(), &[ColumnKind::F64; 2], "NA").unwrap_err();
+        assert!(matches!(err, IoError::Schema(_)));
+
+        let err = read_csv_from("".as_bytes(), &[], "NA").unwrap_err();
+        assert!(matches!(err, IoError::Schema(_)));
+
+        let err =
+            read_csv_from("d\n2021-02-30\n".as_bytes(), &[ColumnKind::Date], "NA").unwrap_err();
+        assert!(matches!(err, IoError::Parse { .. }));
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let schema = [
+            ColumnKind::Date,
+            ColumnKind::F64,
+            ColumnKind::I64,
+            ColumnKind::Timestamp,
+        ];
+        let t = read_csv_from(PRICES.as_bytes(), &schema, "NA").unwrap();
+
+        let mut buf = Vec::new();
+        write_csv(&t, &mut buf, "NA").unwrap();
+        let text = String::from_utf8(buf.clone()).unwrap();
+        assert!(text.starts_with("date,close,volume,ts\n2021-01-04,100.5,1200,"));
+        assert!(text.contains("2021-01-05T14:30:00.500000000Z"));
+
+        let back = read_csv_from(buf.as_slice(), &schema, "NA").unwrap();
+        assert!(t.approx_eq(&back, 0.0));
+    }
+
+    #[test]
+    fn test_csv_round_trip_sym() {
+        let t = Table::new(
+            vec!["sym".to_string()],
+            vec![[Some("BRK,B"), None]
+                .into_iter()
+                .collect::<SymBuilder>()
+                .finish()],
+        );
+
+        let mut buf = Vec::new();
+        write_csv(&t, &mut buf, "NA").unwrap();
+        let back = read_csv_from(buf.as_slice(), &[ColumnKind::Sym], "NA").unwrap();
+        assert!(t.approx_eq(&back, 0.0));
+    }
+
+    #[test]
+    fn test_csv_round_trip_f32_ts_bool() {
+        let t = Table::new(
+            vec!["x".to_string(), "ts".to_string(), "flag".to_string()],
+            vec![
+                Column::F32(vec![1.5, f32::NAN, -0.25]),
+                Column::Ts(vec![1_609_459_200_000_000_000, NULL_TS, 0]),
+                Column::Bool(vec![true, false, true]),
+            ],
+        );
+        let schema = [ColumnKind::F32, ColumnKind::Ts, ColumnKind::Bool];
+
+        let mut buf = Vec::new();
+        write_csv(&t, &mut buf, "NA").unwrap();
+        let back = read_csv_from(buf.as_slice(), &schema, "NA").unwrap();
+        assert!(t.approx_eq(&back, 0.0));
+        let kinds: Vec<ColumnKind> = back.columns.iter().map(Column::kind).collect();
+        assert_eq!(kinds, schema);
+
+        // Bool has no null sentinel
+        let err = read_csv_from("flag\nNA\n".as_bytes(), &[ColumnKind::Bool], "NA").unwrap_err();
+        assert!(matches!(
+            err,
+            IoError::Parse {
+                kind: ColumnKind::Bool,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_write_csv_precision_and_na() {
+        let t = Table::new(
+            vec!["x".to_string(), "d".to_string()],
+            vec![
+                Column::F64(vec![1.0 / 3.0, f64::NAN]),
+                Column::Date(vec![-1, NULL_DATE]),
+            ],
+        );
+        let mut buf = Vec::new();
+        write_csv_with_precision(&t, &mut buf, "", Some(3)).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "x,d\n0.333,1969-12-31\n,\n"
+        );
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(format_timestamp(-1), "1969-12-31T23:59:59.999999999Z");
+        assert_eq!(parse_timestamp(&format_timestamp(-1)), Some(-1));
+    }
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(parse_date("2021-01-01"), Some(18628));
+        assert_eq!(parse_date("2021-1-1"), None);
+        for z in [-719468, -1, 0, 59, 11016, 18628, 2932896] {
+            let (y, m, d) = civil_from_days(z);
+            assert_eq!(days_from_civil(y, m, d), z);
+        }
+    }
+}
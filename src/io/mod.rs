@@ -1 +1,75 @@
+//! Table I/O (CSV, Arrow IPC behind the `arrow` feature)
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod csv;
+
+#[cfg(feature = "arrow")]
+pub use self::arrow::{from_arrow_ipc, from_record_batch, to_arrow_ipc, to_record_batch};
+pub use csv::{read_csv, read_csv_from, write_csv, write_csv_with_precision, ColumnKind};
+
+use std::fmt;
+
+/// I/O error: underlying read/write failure, bad layout, or unparseable field
+#[derive(Debug)]
+pub enum IoError {
+    /// Underlying reader/writer failure
+    Io(std::io::Error),
+    /// Arrow conversion or IPC encoding failure
+    #[cfg(feature = "arrow")]
+    Arrow(::arrow::error::ArrowError),
+    /// Header/schema/row shape problem
+    Schema(String),
+    /// Field could not be parsed as its declared column kind
+    Parse {
+        line: usize,
+        column: String,
+        value: String,
+        kind: ColumnKind,
+    },
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IoError::Io(e) => write!(f, "io error: {}", e),
+            #[cfg(feature = "arrow")]
+            IoError::Arrow(e) => write!(f, "arrow error: {}", e),
+            IoError::Schema(msg) => write!(f, "schema error: {}", msg),
+            IoError::Parse {
+                line,
+                column,
+                value,
+                kind,
+            } => write!(
+                f,
+                "line {}: cannot parse '{}' as {:?} (column '{}')",
+                line, value, kind, column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IoError::Io(e) => Some(e),
+            #[cfg(feature = "arrow")]
+            IoError::Arrow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for IoError {
+    fn from(e: std::io::Error) -> Self {
+        IoError::Io(e)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<::arrow::error::ArrowError> for IoError {
+    fn from(e: ::arrow::error::ArrowError) -> Self {
+        IoError::Arrow(e)
+    }
+}
@@ -6,14 +6,18 @@ pub mod builtins;
 pub mod exec;
 pub mod expr;
 pub mod io;
+pub mod pipeline;
 pub mod table;
-// pub mod pipeline;  // WIP: untracked
 
-pub use builtins::{abs_column, dlog_column, ln_column, mean, mean0, sum, sum0};
+pub use builtins::{
+    abs_column, diff_column, dlog_column, ln_column, mean, mean0, shift_column, sum, sum0,
+    try_abs_column, try_dlog_column, try_ln_column,
+};
 pub use table::{
-    compose, lookup_ori, Column, Ori, OriClass, ReduceMode, Table, TableView, VecAxis, NULL_DATE,
+    compose, lookup_ori, AggKind, Column, ColumnTypeError, DropHow, Ori, OriClass, ReduceMode,
+    SymBuilder, Table, TableView, VecAxis, ViewError, NULL_DATE, NULL_I64, NULL_SYM,
     NULL_TIMESTAMP, NULL_TS, ORI_H, ORI_N, ORI_R, ORI_S, ORI_X, ORI_Z, ORI__H, ORI__N, ORI__S,
-    ORI__Z,
+    ORI__SZ, ORI__Z,
 };
 
 /// API Contract Self-Test
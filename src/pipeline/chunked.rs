@@ -0,0 +1,328 @@
+//! Chunked (out-of-core) pipeline execution
+//!
+//! Runs a plan over a stream of row chunks instead of one in-memory table.
+//! `ChunkState` keeps what each colwise segment needs from earlier chunks:
+//!
+//! - A fused segment continues one `KernelState` per column, so
+//!   cumulative sum/product keep their running totals and windowed ops
+//!   (dlog, diff, shift, w5) see the previous chunk's tail.
+//! - Any other colwise segment (rolling moments, wmean, ...) gets the last
+//!   few rows of its previous input prepended, and the prefix rows are
+//!   dropped from its output.
+//!
+//! Every emitted row therefore sees the same history it would in a
+//! whole-table run. Unfused ops with unbounded history, whole-column ops
+//! (zscore) and reducers are rejected.
+
+use super::colwise_fused::{ColwiseKernel, KernelState};
+use super::execution_plan::{ExecutionPlan, OpStep, SegmentKind};
+use super::executor::{ExecutionValue, Executor};
+use super::ir::OpId;
+use crate::table::Table;
+
+/// Carry-over between chunks, one entry per plan segment
+#[derive(Clone, Debug)]
+pub struct ChunkState {
+    /// Rows of history the plan's windowed ops look back on
+    lookback: usize,
+    segments: Vec<SegmentState>,
+}
+
+#[derive(Clone, Debug)]
+enum SegmentState {
+    /// Rowwise/Each/Real segment: never reaches across rows
+    Stateless,
+    /// Fused colwise segment: one kernel state per column
+    Fused {
+        kernel: ColwiseKernel,
+        columns: Vec<KernelState>,
+    },
+    /// Unfused colwise segment: last `lookback` input rows seen so far
+    Tail {
+        lookback: usize,
+        tail: Option<Table>,
+    },
+}
+
+impl ChunkState {
+    /// State for a plan; errors if any op cannot run chunked
+    pub fn for_plan(plan: &ExecutionPlan) -> Result<Self, String> {
+        let mut lookback = 0;
+        let mut segments = Vec::with_capacity(plan.segments.len());
+        for segment in &plan.segments {
+            if segment.kind != SegmentKind::Colwise {
+                segments.push(SegmentState::Stateless);
+                continue;
+            }
+
+            let kernel = ColwiseKernel::from_segment(segment);
+            let mut segment_lookback = 0;
+            for op in &segment.ops {
+                // The fused kernel carries cumulative totals itself
+                let cumulative = matches!(op.name, OpId::Cs1 | OpId::Cp1);
+                if !(cumulative && kernel.is_some()) {
+                    segment_lookback += op_lookback(op)?;
+                }
+            }
+            lookback += segment_lookback;
+
+            segments.push(match kernel {
+                Some(kernel) => SegmentState::Fused {
+                    kernel,
+                    columns: Vec::new(),
+                },
+                None => SegmentState::Tail {
+                    lookback: segment_lookback,
+                    tail: None,
+                },
+            });
+        }
+        Ok(ChunkState { lookback, segments })
+    }
+
+    /// Rows of history the plan's windowed ops look back on
+    pub fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+/// Prepend the carried tail to `chunk` and remember the new tail
+///
+/// Returns the extended table and the number of prefix rows to drop from
+/// its output.
+fn extend(
+    tail: &mut Option<Table>,
+    lookback: usize,
+    chunk: Table,
+) -> Result<(Table, usize), String> {
+    let (extended, prefix) = match tail.take() {
+        Some(prev) => {
+            let table = Table::concat_rows(&[&prev, &chunk])
+                .map_err(|e| format!("chunk schema changed: {}", e))?;
+            (table, prev.row_count())
+        }
+        None => (chunk, 0),
+    };
+
+    if lookback > 0 {
+        let n = extended.row_count();
+        *tail = Some(extended.slice_rows(n.saturating_sub(lookback), n));
+    }
+    Ok((extended, prefix))
+}
+
+/// Unwrap a segment's table result
+fn into_table(value: ExecutionValue) -> Result<Table, String> {
+    match value {
+        ExecutionValue::Table(t) => Ok(t),
+        other => Err(format!(
+            "chunked execution needs a table result, got {:?}",
+            other
+        )),
+    }
+}
+
+/// Rows of history one op needs (argument defaults as in `FusedOp`)
+fn op_lookback(op: &OpStep) -> Result<usize, String> {
+    let arg = |default: f64| op.args.first().copied().unwrap_or(default);
+    match &op.name {
+        OpId::Dlog | OpId::Diff | OpId::PctChange => Ok(arg(1.0) as usize),
+        OpId::Shift => {
+            let lag = arg(1.0) as i64;
+            if lag < 0 {
+                return Err(format!(
+                    "shift {} needs future rows; not supported in chunked execution",
+                    lag
+                ));
+            }
+            Ok(lag as usize)
+        }
+        OpId::W5 => Ok(4),
+        OpId::WMean(w) => Ok(w.saturating_sub(1)),
+        OpId::RollMean(w) | OpId::RollStd(w) => Ok(w.window),
+        OpId::AddConst
+        | OpId::SubConst
+        | OpId::MulConst
+        | OpId::DivConst
+        | OpId::Sqrt
+        | OpId::Exp
+        | OpId::Pow
+        | OpId::Ln
+        | OpId::Abs
+        | OpId::Neg
+        | OpId::Sign
+        | OpId::Clip => Ok(0),
+        OpId::Bin { .. } | OpId::Select(_) => Ok(0),
+        OpId::Generic(name) if name == "ln" || name == "abs" => Ok(0),
+        other => Err(format!(
+            "{:?} needs unbounded history or the whole column; not supported in chunked execution",
+            other
+        )),
+    }
+}
+
+impl Executor {
+    /// Execute a plan over a stream of row chunks
+    ///
+    /// Each chunk runs through the plan segment by segment, continuing from
+    /// the earlier chunks (see `ChunkState`), and the result is handed to
+    /// `sink`. Concatenating the sink's tables gives the same result as
+    /// `execute` on the concatenated input.
+    ///
+    /// # Errors
+    /// Errors if the plan contains an op that cannot run chunked, if a chunk's
+    /// schema differs from the first, or if the plan does not produce a table.
+    pub fn execute_chunked(
+        &mut self,
+        plan: &ExecutionPlan,
+        source: impl Iterator<Item = Table>,
+        sink: &mut impl FnMut(Table),
+    ) -> Result<(), String> {
+        let mut state = ChunkState::for_plan(plan)?;
+
+        for chunk in source {
+            let mut table = chunk;
+            for (segment, seg_state) in plan.segments.iter().zip(&mut state.segments) {
+                table = match seg_state {
+                    SegmentState::Stateless => {
+                        into_table(self.execute_segment(segment, ExecutionValue::Table(table))?)?
+                    }
+                    SegmentState::Fused { kernel, columns } => {
+                        if !columns.is_empty() && columns.len() != table.col_count() {
+                            return Err(format!(
+                                "chunk schema changed: {} columns, expected {}",
+                                table.col_count(),
+                                columns.len()
+                            ));
+                        }
+                        self.execute_fused_chunk(kernel, &table, columns)
+                    }
+                    SegmentState::Tail { lookback, tail } => {
+                        let (extended, prefix) = extend(tail, *lookback, table)?;
+                        let out = into_table(
+                            self.execute_segment(segment, ExecutionValue::Table(extended))?,
+                        )?;
+                        out.slice_rows(prefix, out.row_count())
+                    }
+                };
+            }
+            sink(table);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{PipeIR, Planner, RollWindow, Step};
+    use crate::table::{Column, ORI_H};
+
+    fn plan_of(ops: &[(OpId, Vec<f64>)]) -> ExecutionPlan {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        for (name, args) in ops {
+            ir.push(Step::Op {
+                name: name.clone(),
+                args: args.clone(),
+            });
+        }
+        Planner::plan(&ir).unwrap()
+    }
+
+    #[test]
+    fn test_lookback_sums_over_chain() {
+        let plan = plan_of(&[
+            (OpId::Dlog, vec![2.0]),
+            (OpId::MulConst, vec![3.0]),
+            (OpId::RollMean(RollWindow::new(5)), vec![]),
+            (OpId::W5, vec![]),
+        ]);
+        assert_eq!(ChunkState::for_plan(&plan).unwrap().lookback(), 11);
+    }
+
+    #[test]
+    fn test_unbounded_ops_rejected() {
+        for op in [OpId::Sum, OpId::Generic("zscore".to_string())] {
+            let plan = plan_of(&[(op, vec![])]);
+            assert!(ChunkState::for_plan(&plan).is_err());
+        }
+        let plan = plan_of(&[(OpId::Shift, vec![-1.0])]);
+        assert!(ChunkState::for_plan(&plan).is_err());
+
+        // Cumulative ops only carry their totals inside a fused segment
+        let plan = plan_of(&[(OpId::Cs1, vec![])]);
+        assert!(ChunkState::for_plan(&plan).is_ok());
+        let plan = plan_of(&[
+            (OpId::Cs1, vec![]),
+            (OpId::Generic("ln".to_string()), vec![]),
+        ]);
+        assert!(ChunkState::for_plan(&plan).is_err());
+    }
+
+    #[test]
+    fn test_cumulative_after_windowed_segment() {
+        // Fused diff+cs1, standalone roll_mean, fused cp1: each segment
+        // carries its own state across the seams
+        let plan = plan_of(&[
+            (OpId::Diff, vec![1.0]),
+            (OpId::Cs1, vec![]),
+            (OpId::RollMean(RollWindow::new(3)), vec![]),
+            (OpId::MulConst, vec![0.5]),
+            (OpId::Cp1, vec![]),
+        ]);
+        let data: Vec<f64> = (0..17).map(|i| 1.0 + (i as f64 * 0.9).sin()).collect();
+        let table =
+            |part: &[f64]| Table::new(vec!["x".to_string()], vec![Column::F64(part.to_vec())]);
+
+        let whole = match Executor::new().execute(&plan, table(&data)).unwrap().value {
+            ExecutionValue::Table(t) => t,
+            other => panic!("expected table, got {:?}", other),
+        };
+
+        let chunks = [&data[..2], &data[2..9], &data[9..]].map(table);
+        let mut out = Vec::new();
+        Executor::new()
+            .execute_chunked(&plan, chunks.into_iter(), &mut |t: Table| {
+                out.extend_from_slice(t.columns[0].f64_data())
+            })
+            .unwrap();
+
+        let expected = whole.columns[0].f64_data();
+        assert_eq!(out.len(), expected.len());
+        for (a, b) in out.iter().zip(expected) {
+            assert!(a.to_bits() == b.to_bits() || (a - b).abs() <= 1e-12 * b.abs());
+        }
+        assert!(expected[16].is_finite());
+    }
+
+    #[test]
+    fn test_tail_spans_short_chunks() {
+        let plan = plan_of(&[(OpId::Diff, vec![3.0])]);
+        let chunks = (0..4).map(|k| {
+            let start = (k * 2) as f64;
+            Table::new(
+                vec!["x".to_string()],
+                vec![Column::F64(vec![
+                    start * start,
+                    (start + 1.0) * (start + 1.0),
+                ])],
+            )
+        });
+
+        let mut out = Vec::new();
+        Executor::new()
+            .execute_chunked(&plan, chunks, &mut |t: Table| {
+                out.extend_from_slice(t.columns[0].f64_data())
+            })
+            .unwrap();
+
+        assert_eq!(out.len(), 8);
+        assert!(out[..3].iter().all(|x| x.is_nan()));
+        let sq = |k: usize| (k * k) as f64;
+        for (i, &v) in out.iter().enumerate().skip(3) {
+            assert_eq!(v, sq(i) - sq(i - 3));
+        }
+    }
+}
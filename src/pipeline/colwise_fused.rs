@@ -3,9 +3,11 @@
 //! Executes a sequence of colwise operations in a single pass per column,
 //! minimizing intermediate allocations.
 
-use crate::table::{Table, Column};
-use super::execution_plan::Segment;
+use super::execution_plan::{OpStep, Segment};
 use super::ir::OpId;
+use crate::builtins::kernels_masked::{dlog_value, ln_value};
+use crate::builtins::ops::{clip_slice, diff_slice, pct_change_slice, shift_slice};
+use crate::table::{Column, Table};
 
 /// Fused operation types (safe subset for fusion)
 #[derive(Clone, Debug)]
@@ -13,6 +15,12 @@ pub enum FusedOp {
     /// Delta log: log(x[i]) - log(x[i-period])
     Dlog { period: usize },
 
+    /// First difference: x[i] - x[i-lag]
+    Diff { lag: usize },
+
+    /// Simple return: x[i] / x[i-lag] - 1, NaN on a zero base
+    PctChange { lag: usize },
+
     /// Add constant: x[i] + c
     AddConst(f64),
 
@@ -25,13 +33,180 @@ pub enum FusedOp {
     /// Divide constant: x[i] / c
     DivConst(f64),
 
+    /// Square root: sqrt(x[i]), NaN for negative inputs
+    Sqrt,
+
+    /// Exponential: exp(x[i])
+    Exp,
+
+    /// Power with constant exponent: x[i]^p
+    PowConst(f64),
+
+    /// Natural log: ln(x[i]), NaN for x[i] <= 0 (no -inf at 0)
+    Ln,
+
+    /// Absolute value: |x[i]|
+    Abs,
+
+    /// Negate: -x[i]
+    Neg,
+
+    /// Sign: -1, 0 or 1 (both 0.0 and -0.0 map to 0)
+    Sign,
+
+    /// Clamp into [lo, hi]; None = unbounded on that side
+    Clip { lo: Option<f64>, hi: Option<f64> },
+
     /// Rolling window mean (window=5)
     WMean5,
 
     /// Cumulative sum
     Cumsum,
+
+    /// Cumulative product (e.g. compounding gross returns 1+r)
+    ///
+    /// Same NaN policy as Cumsum: a NaN input yields NaN at that position,
+    /// but the running product skips it (acts as a multiplicative 1.0).
+    Cumprod,
+
+    /// Lag/lead: x[i - lag], NaN where out of range
+    Shift { lag: i64 },
+}
+
+impl FusedOp {
+    /// Convert a planned op step into its fused form
+    ///
+    /// Returns None if the op has no fused implementation.
+    pub fn from_op_step(op_step: &OpStep) -> Option<Self> {
+        let fused_op = match &op_step.name {
+            OpId::Dlog => {
+                let period = op_step.args.first().copied().unwrap_or(1.0) as usize;
+                FusedOp::Dlog { period }
+            }
+            OpId::Diff => {
+                let lag = op_step.args.first().copied().unwrap_or(1.0) as usize;
+                FusedOp::Diff { lag }
+            }
+            OpId::PctChange => {
+                let lag = op_step.args.first().copied().unwrap_or(1.0) as usize;
+                FusedOp::PctChange { lag }
+            }
+            OpId::AddConst => FusedOp::AddConst(op_step.args.first().copied().unwrap_or(0.0)),
+            OpId::SubConst => FusedOp::SubConst(op_step.args.first().copied().unwrap_or(0.0)),
+            OpId::MulConst => FusedOp::MulConst(op_step.args.first().copied().unwrap_or(1.0)),
+            OpId::DivConst => FusedOp::DivConst(op_step.args.first().copied().unwrap_or(1.0)),
+            OpId::Sqrt => FusedOp::Sqrt,
+            OpId::Exp => FusedOp::Exp,
+            OpId::Pow => FusedOp::PowConst(op_step.args.first().copied().unwrap_or(1.0)),
+            OpId::Ln => FusedOp::Ln,
+            OpId::Abs => FusedOp::Abs,
+            OpId::Neg => FusedOp::Neg,
+            OpId::Sign => FusedOp::Sign,
+            OpId::Clip => {
+                let bound = |k: usize| op_step.args.get(k).copied().filter(|b| !b.is_nan());
+                FusedOp::Clip {
+                    lo: bound(0),
+                    hi: bound(1),
+                }
+            }
+            OpId::W5 => FusedOp::WMean5,
+            OpId::Cs1 => FusedOp::Cumsum,
+            OpId::Cp1 => FusedOp::Cumprod,
+            OpId::Shift => {
+                let lag = op_step.args.first().copied().unwrap_or(1.0) as i64;
+                FusedOp::Shift { lag }
+            }
+            _ => return None, // Non-fusable op
+        };
+
+        Some(fused_op)
+    }
+
+    /// Rows of history the op reads before position i (None = unbounded)
+    fn lookback(&self) -> Option<usize> {
+        match self {
+            FusedOp::Dlog { period } => Some(*period),
+            FusedOp::Diff { lag } | FusedOp::PctChange { lag } => Some(*lag),
+            FusedOp::Shift { lag } => Some((*lag).max(0) as usize),
+            FusedOp::WMean5 => Some(4),
+            FusedOp::Cumsum | FusedOp::Cumprod => None,
+            FusedOp::AddConst(_)
+            | FusedOp::SubConst(_)
+            | FusedOp::MulConst(_)
+            | FusedOp::DivConst(_)
+            | FusedOp::Sqrt
+            | FusedOp::Exp
+            | FusedOp::PowConst(_)
+            | FusedOp::Ln
+            | FusedOp::Abs
+            | FusedOp::Neg
+            | FusedOp::Sign
+            | FusedOp::Clip { .. } => Some(0),
+        }
+    }
+}
+
+/// Per-column carry-over between chunks for `execute_column_with_carry`
+///
+/// Holds one entry per kernel op: the running total for Cumsum/Cumprod,
+/// or the tail of that op's previous input for windowed ops (Dlog, Diff,
+/// Shift, WMean5). A fresh state behaves like the first chunk.
+#[derive(Clone, Debug, Default)]
+pub struct KernelState {
+    carries: Vec<OpCarry>,
 }
 
+#[derive(Clone, Debug)]
+enum OpCarry {
+    /// Running cumulative sum or product
+    Total(f64),
+    /// Last `lookback` inputs of the op
+    Tail(Vec<f64>),
+}
+
+impl KernelState {
+    /// Empty state (start of stream)
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Cumulative sum continuing from `total` (NaN yields NaN, skipped in the sum)
+fn cumsum_from(data: &[f64], total: &mut f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(data.len());
+
+    for &x in data {
+        if x.is_nan() {
+            out.push(f64::NAN);
+        } else {
+            *total += x;
+            out.push(*total);
+        }
+    }
+
+    out
+}
+
+/// Cumulative product continuing from `total` (NaN yields NaN, acts as 1.0)
+fn cumprod_from(data: &[f64], total: &mut f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(data.len());
+
+    for &x in data {
+        if x.is_nan() {
+            out.push(f64::NAN);
+        } else {
+            *total *= x;
+            out.push(*total);
+        }
+    }
+
+    out
+}
+
+/// Column count below which `ColwiseKernel::execute` stays serial (feature `parallel`)
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_COLUMNS: usize = 2;
+
 /// Fused colwise kernel
 #[derive(Clone, Debug)]
 pub struct ColwiseKernel {
@@ -47,37 +222,11 @@ impl ColwiseKernel {
             return None;
         }
 
-        let mut ops = Vec::new();
-
-        for op_step in &segment.ops {
-            let fused_op = match &op_step.name {
-                OpId::Dlog => {
-                    let period = op_step.args.get(0).copied().unwrap_or(1.0) as usize;
-                    FusedOp::Dlog { period }
-                }
-                OpId::AddConst => {
-                    let c = op_step.args.get(0).copied().unwrap_or(0.0);
-                    FusedOp::AddConst(c)
-                }
-                OpId::SubConst => {
-                    let c = op_step.args.get(0).copied().unwrap_or(0.0);
-                    FusedOp::SubConst(c)
-                }
-                OpId::MulConst => {
-                    let c = op_step.args.get(0).copied().unwrap_or(1.0);
-                    FusedOp::MulConst(c)
-                }
-                OpId::DivConst => {
-                    let c = op_step.args.get(0).copied().unwrap_or(1.0);
-                    FusedOp::DivConst(c)
-                }
-                OpId::W5 => FusedOp::WMean5,
-                OpId::Cs1 => FusedOp::Cumsum,
-                _ => return None, // Non-fusable op
-            };
-
-            ops.push(fused_op);
-        }
+        let ops = segment
+            .ops
+            .iter()
+            .map(FusedOp::from_op_step)
+            .collect::<Option<Vec<_>>>()?;
 
         Some(ColwiseKernel { ops })
     }
@@ -86,24 +235,115 @@ impl ColwiseKernel {
     ///
     /// Processes each F64 column in a single pass through the kernel.
     /// Preserves Date/Timestamp columns unchanged.
+    ///
+    /// With feature `parallel`, columns are distributed across rayon threads
+    /// once the table has at least `PARALLEL_MIN_COLUMNS` columns. Columns
+    /// are independent, so the result (including column order) is identical
+    /// to the serial path.
     pub fn execute(&self, input: &Table) -> Table {
-        let mut new_columns = Vec::with_capacity(input.columns.len());
-
-        for col in &input.columns {
-            let new_col = match col {
-                Column::F64(data) => {
-                    // Execute fused kernel on this column
-                    let result = self.execute_column(data);
-                    Column::F64(result)
-                }
-                Column::Date(_) | Column::Timestamp(_) => {
-                    // Preserve temporal columns unchanged
-                    col.clone()
-                }
-            };
+        #[cfg(feature = "parallel")]
+        if input.columns.len() >= PARALLEL_MIN_COLUMNS {
+            return self.execute_parallel(input);
+        }
+
+        self.execute_serial(input)
+    }
+
+    /// Process columns in order on the current thread
+    fn execute_serial(&self, input: &Table) -> Table {
+        let new_columns = input
+            .columns
+            .iter()
+            .map(|col| self.execute_any_column(col))
+            .collect();
+
+        Table::new(input.names.clone(), new_columns)
+    }
+
+    /// Process columns across rayon threads (one output column per task)
+    #[cfg(feature = "parallel")]
+    fn execute_parallel(&self, input: &Table) -> Table {
+        use rayon::prelude::*;
+
+        // Indexed collect keeps the input column order
+        let new_columns = input
+            .columns
+            .par_iter()
+            .map(|col| self.execute_any_column(col))
+            .collect();
+
+        Table::new(input.names.clone(), new_columns)
+    }
 
-            new_columns.push(new_col);
+    /// Run the kernel on a float column; clone any other column unchanged
+    ///
+    /// F32 is widened, run through the same f64 kernel and rounded back
+    /// to F32 once at the end.
+    fn execute_any_column(&self, col: &Column) -> Column {
+        match col {
+            Column::F64(data) => Column::F64(self.execute_column(data)),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => Column::F64(self.execute_column(m.as_slice())),
+            Column::F32(data) => {
+                let wide: Vec<f64> = data.iter().map(|&x| x as f64).collect();
+                let out = self.execute_column(&wide);
+                Column::F32(out.into_iter().map(|x| x as f32).collect())
+            }
+            Column::Date(_)
+            | Column::Timestamp(_)
+            | Column::Ts(_)
+            | Column::Bool(_)
+            | Column::I64(_)
+            | Column::Sym { .. } => {
+                // Preserve temporal, mask and symbol columns unchanged
+                col.clone()
+            }
         }
+    }
+
+    /// Execute kernel on one chunk of a table, continuing from `states`
+    ///
+    /// `states` holds one `KernelState` per column and is filled on the
+    /// first chunk; each float column continues as in
+    /// `execute_column_with_carry`. Other columns are cloned unchanged, as
+    /// in `execute`.
+    ///
+    /// # Panics
+    /// As `execute_column_with_carry`, or if the column count differs from
+    /// the chunk that filled `states`.
+    pub fn execute_with_carry(&self, input: &Table, states: &mut Vec<KernelState>) -> Table {
+        if states.is_empty() {
+            states.resize_with(input.columns.len(), KernelState::new);
+        }
+        assert_eq!(
+            states.len(),
+            input.columns.len(),
+            "execute_with_carry: column count changed between chunks"
+        );
+
+        let new_columns = input
+            .columns
+            .iter()
+            .zip(states.iter_mut())
+            .map(|(col, state)| match col {
+                Column::F64(data) => Column::F64(self.execute_column_with_carry(data, state)),
+                #[cfg(feature = "mmap")]
+                Column::F64Mmap(m) => {
+                    Column::F64(self.execute_column_with_carry(m.as_slice(), state))
+                }
+                Column::F32(data) => {
+                    let wide: Vec<f64> = data.iter().map(|&x| x as f64).collect();
+                    let out = self.execute_column_with_carry(&wide, state);
+                    Column::F32(out.into_iter().map(|x| x as f32).collect())
+                }
+                Column::Date(_)
+                | Column::Timestamp(_)
+                | Column::Ts(_)
+                | Column::Bool(_)
+                | Column::I64(_)
+                | Column::Sym { .. } => col.clone(),
+            })
+            .collect();
 
         Table::new(input.names.clone(), new_columns)
     }
@@ -126,6 +366,58 @@ impl ColwiseKernel {
         result
     }
 
+    /// Execute kernel on one chunk of a column, continuing from `state`
+    ///
+    /// Feeding consecutive chunks of a column through the same `state` gives
+    /// the same values as `execute_column` on the whole column: Cumsum and
+    /// Cumprod continue their running totals and windowed ops see the
+    /// previous chunk's tail. `state` is updated for the next chunk.
+    ///
+    /// # Panics
+    /// Panics if the kernel contains a lead (`Shift` with negative lag),
+    /// which would need rows from the next chunk, or if `state` was used
+    /// with a kernel of a different length.
+    pub fn execute_column_with_carry(&self, data: &[f64], state: &mut KernelState) -> Vec<f64> {
+        if state.carries.is_empty() {
+            state.carries = self
+                .ops
+                .iter()
+                .map(|op| match op {
+                    FusedOp::Cumsum => OpCarry::Total(0.0),
+                    FusedOp::Cumprod => OpCarry::Total(1.0),
+                    _ => OpCarry::Tail(Vec::new()),
+                })
+                .collect();
+        }
+        assert_eq!(
+            state.carries.len(),
+            self.ops.len(),
+            "execute_column_with_carry: state belongs to a different kernel"
+        );
+
+        let mut result = data.to_vec();
+        for (op, carry) in self.ops.iter().zip(state.carries.iter_mut()) {
+            result = match (op, carry) {
+                (FusedOp::Cumsum, OpCarry::Total(total)) => cumsum_from(&result, total),
+                (FusedOp::Cumprod, OpCarry::Total(total)) => cumprod_from(&result, total),
+                (FusedOp::Shift { lag }, _) if *lag < 0 => {
+                    panic!("execute_column_with_carry: shift {} needs future rows", lag)
+                }
+                (op, OpCarry::Tail(tail)) => {
+                    let k = op.lookback().unwrap_or(0);
+                    let prefix = tail.len();
+                    tail.extend_from_slice(&result);
+                    let out = self.apply_op(op, tail).split_off(prefix);
+                    tail.drain(..tail.len().saturating_sub(k));
+                    out
+                }
+                (op, carry) => unreachable!("carry {:?} does not match {:?}", carry, op),
+            };
+        }
+
+        result
+    }
+
     /// Apply a single fused operation
     fn apply_op(&self, op: &FusedOp, data: &[f64]) -> Vec<f64> {
         let n = data.len();
@@ -134,17 +426,14 @@ impl ColwiseKernel {
             FusedOp::Dlog { period } => {
                 let mut out = vec![f64::NAN; n];
                 for i in *period..n {
-                    let curr = data[i];
-                    let prev = data[i - period];
-                    if curr.is_nan() || prev.is_nan() || curr <= 0.0 || prev <= 0.0 {
-                        out[i] = f64::NAN;
-                    } else {
-                        out[i] = curr.ln() - prev.ln();
-                    }
+                    out[i] = dlog_value(data[i], data[i - period]);
                 }
                 out
             }
 
+            FusedOp::Diff { lag } => diff_slice(data, *lag),
+            FusedOp::PctChange { lag } => pct_change_slice(data, *lag),
+
             FusedOp::AddConst(c) => {
                 let mut out = Vec::with_capacity(n);
                 for &x in data {
@@ -177,6 +466,32 @@ impl ColwiseKernel {
                 out
             }
 
+            FusedOp::Sqrt => {
+                let mut out = Vec::with_capacity(n);
+                for &x in data {
+                    // sqrt of a negative is NaN (IEEE 754)
+                    out.push(x.sqrt());
+                }
+                out
+            }
+
+            FusedOp::Exp => {
+                let mut out = Vec::with_capacity(n);
+                for &x in data {
+                    out.push(x.exp());
+                }
+                out
+            }
+
+            FusedOp::PowConst(p) => {
+                let mut out = Vec::with_capacity(n);
+                for &x in data {
+                    // Explicit check: powf(NaN, 0.0) is 1.0
+                    out.push(if x.is_nan() { f64::NAN } else { x.powf(*p) });
+                }
+                out
+            }
+
             FusedOp::WMean5 => {
                 const WINDOW: usize = 5;
                 let mut out = vec![f64::NAN; n];
@@ -203,21 +518,31 @@ impl ColwiseKernel {
                 out
             }
 
-            FusedOp::Cumsum => {
-                let mut out = Vec::with_capacity(n);
-                let mut cumsum = 0.0;
-
-                for &x in data {
-                    if x.is_nan() {
-                        out.push(f64::NAN);
+            FusedOp::Cumsum => cumsum_from(data, &mut 0.0),
+
+            FusedOp::Cumprod => cumprod_from(data, &mut 1.0),
+
+            FusedOp::Ln => data.iter().map(|&x| ln_value(x)).collect(),
+            FusedOp::Abs => data.iter().map(|&x| x.abs()).collect(),
+            FusedOp::Neg => data.iter().map(|&x| -x).collect(),
+            FusedOp::Sign => data
+                .iter()
+                .map(|&x| {
+                    // signum maps ±0.0 to ±1.0; NaN compares false and passes through
+                    if x > 0.0 {
+                        1.0
+                    } else if x < 0.0 {
+                        -1.0
+                    } else if x == 0.0 {
+                        0.0
                     } else {
-                        cumsum += x;
-                        out.push(cumsum);
+                        x
                     }
-                }
+                })
+                .collect(),
+            FusedOp::Clip { lo, hi } => clip_slice(data, *lo, *hi),
 
-                out
-            }
+            FusedOp::Shift { lag } => shift_slice(data, *lag, f64::NAN),
         }
     }
 }
@@ -263,6 +588,94 @@ mod tests {
         assert_eq!(result, vec![12.0, 14.0, 16.0]);
     }
 
+    #[test]
+    fn test_fused_clip_in_chain() {
+        // (x * 2) clipped to [0, 5]
+        let kernel = ColwiseKernel {
+            ops: vec![
+                FusedOp::MulConst(2.0),
+                FusedOp::Clip {
+                    lo: Some(0.0),
+                    hi: Some(5.0),
+                },
+            ],
+        };
+
+        let result = kernel.execute_column(&[-1.0, 1.0, f64::NAN, 4.0]);
+        assert_eq!(result[0], 0.0);
+        assert_eq!(result[1], 2.0);
+        assert!(result[2].is_nan());
+        assert_eq!(result[3], 5.0);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_execute_parallel_matches_serial() {
+        use crate::pipeline::{Executor, PipeIR, Planner, Step};
+        use crate::table::ORI_H;
+
+        let nrows: usize = 500;
+        let mut names = Vec::new();
+        let mut columns = Vec::new();
+        for c in 0..20 {
+            names.push(format!("c{}", c));
+            columns.push(match c {
+                3 => Column::Date((0..nrows as i32).collect()),
+                7 => Column::Timestamp((0..nrows as i64).map(|i| i * 1_000).collect()),
+                _ => Column::F64(
+                    (0..nrows)
+                        .map(|i| 100.0 + ((i * (c + 3)) % 17) as f64)
+                        .collect(),
+                ),
+            });
+        }
+        let input = Table::new(names, columns);
+
+        let kernel = ColwiseKernel {
+            ops: vec![
+                FusedOp::Dlog { period: 1 },
+                FusedOp::MulConst(2.0),
+                FusedOp::Cumsum,
+            ],
+        };
+        let serial = kernel.execute_serial(&input);
+        let parallel = kernel.execute(&input);
+
+        assert!(parallel.approx_eq(&serial, 0.0));
+        assert_eq!(
+            parallel.columns[3].date_data(),
+            input.columns[3].date_data()
+        );
+        assert!(parallel.columns[7].approx_eq(&input.columns[7], 0.0));
+
+        // Still one allocation per column through the executor
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![],
+        });
+        let plan = Planner::plan(&ir).unwrap();
+        let result = Executor::new().execute(&plan, input).unwrap();
+        assert_eq!(result.stats.allocations, 20);
+    }
+
+    #[test]
+    fn test_fused_pct_change_matches_column_op() {
+        use crate::builtins::ops::pct_change_column;
+
+        let data = vec![100.0, 105.0, 0.0, 3.0, f64::NAN, 4.0];
+        let kernel = ColwiseKernel {
+            ops: vec![FusedOp::PctChange { lag: 1 }, FusedOp::MulConst(100.0)],
+        };
+        let result = kernel.execute_column(&data);
+        let expected = pct_change_column(&Column::F64(data), 1);
+
+        for (r, e) in result.iter().zip(expected.f64_data()) {
+            assert!((r.is_nan() && e.is_nan()) || (r - e * 100.0).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_fused_dlog() {
         let kernel = ColwiseKernel {
@@ -289,6 +702,161 @@ mod tests {
         assert_eq!(result, vec![1.0, 3.0, 6.0, 10.0]);
     }
 
+    #[test]
+    fn test_fused_cumprod() {
+        let kernel = ColwiseKernel {
+            ops: vec![FusedOp::Cumprod],
+        };
+
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let result = kernel.execute_column(&data);
+
+        assert_eq!(result, vec![1.0, 2.0, 6.0, 24.0]);
+    }
+
+    #[test]
+    fn test_fused_cumprod_nan_does_not_poison() {
+        // Equity curve from gross returns with a missing day
+        let kernel = ColwiseKernel {
+            ops: vec![FusedOp::AddConst(1.0), FusedOp::Cumprod],
+        };
+
+        let data = vec![0.1, f64::NAN, 0.5, -0.5];
+        let result = kernel.execute_column(&data);
+
+        assert!((result[0] - 1.1).abs() < 1e-12);
+        assert!(result[1].is_nan());
+        assert!((result[2] - 1.65).abs() < 1e-12);
+        assert!((result[3] - 0.825).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fused_shift() {
+        // Feature: x - x[i-1] via shift inside a fused chain
+        let kernel = ColwiseKernel {
+            ops: vec![FusedOp::Shift { lag: 1 }, FusedOp::MulConst(10.0)],
+        };
+
+        let result = kernel.execute_column(&[1.0, 2.0, 3.0]);
+        assert!(result[0].is_nan());
+        assert_eq!(&result[1..], &[10.0, 20.0]);
+
+        let lead = ColwiseKernel {
+            ops: vec![FusedOp::Shift { lag: -1 }],
+        };
+        let result = lead.execute_column(&[1.0, 2.0, 3.0]);
+        assert_eq!(&result[..2], &[2.0, 3.0]);
+        assert!(result[2].is_nan());
+    }
+
+    #[test]
+    fn test_fused_f32_computed_in_f64() {
+        let kernel = ColwiseKernel {
+            ops: vec![FusedOp::MulConst(0.1), FusedOp::AddConst(1.0)],
+        };
+        let table = Table::new(
+            vec!["x".to_string(), "d".to_string()],
+            vec![Column::F32(vec![10.0, 20.0]), Column::Date(vec![1, 2])],
+        );
+        let out = kernel.execute(&table);
+        assert_eq!(
+            out.columns[0].f32_data(),
+            &[(10.0f64 * 0.1 + 1.0) as f32, (20.0f64 * 0.1 + 1.0) as f32]
+        );
+        assert_eq!(out.columns[1].date_data(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_fused_ln_abs() {
+        let ln = ColwiseKernel {
+            ops: vec![FusedOp::Ln],
+        };
+        let result = ln.execute_column(&[1.0, std::f64::consts::E, -1.0, 0.0, f64::NAN, -0.0]);
+        assert_eq!(result[0], 0.0);
+        assert!((result[1] - 1.0).abs() < 1e-15);
+        assert!(result[2].is_nan());
+        assert!(result[3].is_nan());
+        assert!(result[4].is_nan());
+        assert!(result[5].is_nan());
+
+        let abs = ColwiseKernel {
+            ops: vec![FusedOp::Abs],
+        };
+        let result = abs.execute_column(&[-2.5, 3.0, -0.0, f64::NAN]);
+        assert_eq!(&result[..3], &[2.5, 3.0, 0.0]);
+        assert!(result[2].is_sign_positive());
+        assert!(result[3].is_nan());
+
+        // abs then ln: log-magnitude in one pass
+        let chain = ColwiseKernel {
+            ops: vec![FusedOp::Abs, FusedOp::Ln, FusedOp::MulConst(2.0)],
+        };
+        let result = chain.execute_column(&[-1.0, -std::f64::consts::E]);
+        assert_eq!(result[0], 0.0);
+        assert!((result[1] - 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_fused_neg_sign() {
+        let neg = ColwiseKernel {
+            ops: vec![FusedOp::Neg],
+        };
+        let result = neg.execute_column(&[2.5, -1.0, f64::NAN, 0.0]);
+        assert_eq!(result[0], -2.5);
+        assert_eq!(result[1], 1.0);
+        assert!(result[2].is_nan());
+        assert!(result[3] == 0.0 && result[3].is_sign_negative());
+
+        let sign = ColwiseKernel {
+            ops: vec![FusedOp::Sign],
+        };
+        let result = sign.execute_column(&[3.0, -0.5, 0.0, -0.0, f64::NAN, f64::NEG_INFINITY]);
+        assert_eq!(result[0], 1.0);
+        assert_eq!(result[1], -1.0);
+        assert_eq!(result[2], 0.0);
+        assert_eq!(result[3], 0.0);
+        assert!(result[4].is_nan());
+        assert_eq!(result[5], -1.0);
+
+        // Sign of a negation
+        let chain = ColwiseKernel {
+            ops: vec![FusedOp::Neg, FusedOp::Sign],
+        };
+        assert_eq!(chain.execute_column(&[4.0, -4.0]), vec![-1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_fused_sqrt_exp_pow() {
+        let sqrt = ColwiseKernel {
+            ops: vec![FusedOp::Sqrt],
+        };
+        let result = sqrt.execute_column(&[4.0, -1.0, f64::NAN, 0.0]);
+        assert_eq!(result[0], 2.0);
+        assert!(result[1].is_nan());
+        assert!(result[2].is_nan());
+        assert_eq!(result[3], 0.0);
+
+        let exp = ColwiseKernel {
+            ops: vec![FusedOp::Exp],
+        };
+        let result = exp.execute_column(&[0.0, 1.0, f64::NAN]);
+        assert_eq!(result[0], 1.0);
+        assert_eq!(result[1], std::f64::consts::E);
+        assert!(result[2].is_nan());
+
+        let pow = ColwiseKernel {
+            ops: vec![FusedOp::PowConst(0.0)],
+        };
+        let result = pow.execute_column(&[3.0, f64::NAN]);
+        assert_eq!(result[0], 1.0);
+        assert!(result[1].is_nan());
+
+        let square = ColwiseKernel {
+            ops: vec![FusedOp::PowConst(2.0), FusedOp::Sqrt],
+        };
+        assert_eq!(square.execute_column(&[-3.0, 5.0]), vec![3.0, 5.0]);
+    }
+
     #[test]
     fn test_fused_wmean5() {
         let kernel = ColwiseKernel {
@@ -353,4 +921,59 @@ mod tests {
             panic!("Expected F64 column");
         }
     }
+
+    #[test]
+    fn test_carry_cumsum_and_dlog_continuous_at_seam() {
+        let data: Vec<f64> = (1..=12).map(|i| 100.0 + i as f64 * 1.5).collect();
+        let mut with_nan = data.clone();
+        with_nan[6] = f64::NAN;
+
+        for kernel in [
+            ColwiseKernel {
+                ops: vec![FusedOp::Cumsum],
+            },
+            ColwiseKernel {
+                ops: vec![FusedOp::Dlog { period: 2 }],
+            },
+            ColwiseKernel {
+                ops: vec![FusedOp::Dlog { period: 1 }, FusedOp::Cumsum],
+            },
+        ] {
+            for input in [&data, &with_nan] {
+                let whole = kernel.execute_column(input);
+
+                let mut state = KernelState::new();
+                let mut chunked = kernel.execute_column_with_carry(&input[..7], &mut state);
+                chunked.extend(kernel.execute_column_with_carry(&input[7..], &mut state));
+
+                assert_eq!(chunked.len(), whole.len());
+                for (i, (a, b)) in chunked.iter().zip(&whole).enumerate() {
+                    assert!(
+                        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()),
+                        "{:?} row {}: {} vs {}",
+                        kernel.ops,
+                        i,
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_carry_resets_each_call() {
+        let kernel = ColwiseKernel {
+            ops: vec![FusedOp::Cumsum],
+        };
+        assert_eq!(kernel.execute_column(&[1.0, 2.0]), vec![1.0, 3.0]);
+        assert_eq!(kernel.execute_column(&[1.0, 2.0]), vec![1.0, 3.0]);
+
+        let mut state = KernelState::new();
+        kernel.execute_column_with_carry(&[1.0, 2.0], &mut state);
+        assert_eq!(
+            kernel.execute_column_with_carry(&[1.0, 2.0], &mut state),
+            vec![4.0, 6.0]
+        );
+    }
 }
@@ -3,8 +3,8 @@
 //! Splits a pipeline into segments based on orientation class stability.
 //! Each segment has a constant orientation class and can be optimized independently.
 
-use crate::table::{Ori, OriClass};
-use super::ir::{OpId, Step};
+use super::ir::OpId;
+use crate::table::Ori;
 
 /// Kind of execution segment
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -117,18 +117,31 @@ fn is_fusable_op(op: &OpId) -> bool {
     matches!(
         op,
         OpId::Dlog
+            | OpId::Diff
+            | OpId::PctChange
             | OpId::AddConst
             | OpId::SubConst
             | OpId::MulConst
             | OpId::DivConst
+            | OpId::Sqrt
+            | OpId::Exp
+            | OpId::Pow
+            | OpId::Ln
+            | OpId::Abs
+            | OpId::Neg
+            | OpId::Sign
+            | OpId::Clip
             | OpId::W5
             | OpId::Cs1
+            | OpId::Cp1
+            | OpId::Shift
     )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pipeline::ir::RollWindow;
     use crate::table::ORI_H;
 
     #[test]
@@ -154,7 +167,10 @@ mod tests {
         assert!(is_fusable_op(&OpId::Dlog));
         assert!(is_fusable_op(&OpId::AddConst));
         assert!(is_fusable_op(&OpId::W5));
+        assert!(is_fusable_op(&OpId::Cp1));
         assert!(!is_fusable_op(&OpId::Sum));
+        assert!(!is_fusable_op(&OpId::RollMean(RollWindow::new(5))));
+        assert!(!is_fusable_op(&OpId::RollStd(RollWindow::new(5))));
         assert!(!is_fusable_op(&OpId::Generic("custom".to_string())));
     }
 
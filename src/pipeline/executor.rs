@@ -2,11 +2,15 @@
 //!
 //! Executes an ExecutionPlan by dispatching segments to appropriate executors.
 
-use crate::table::{Table, Column, TableView};
-use crate::builtins::ori_ops;
-use super::execution_plan::{ExecutionPlan, Segment, SegmentKind};
-use super::colwise_fused::ColwiseKernel;
-use std::sync::Arc;
+use super::colwise_fused::{ColwiseKernel, FusedOp, KernelState};
+use super::execution_plan::{ExecutionPlan, OpStep, Segment, SegmentKind};
+use super::ir::{BinOpId, OpId};
+use crate::builtins::kernels_fused::sub_mul_add_no_nulls;
+use crate::builtins::ops::zscore_column;
+use crate::builtins::{
+    abs_column, ln_column, mean0, ori_ops, rolling_moments_past_only_f64, MomentsMask,
+};
+use crate::table::{Column, Table, TableView};
 
 /// Execution statistics for performance measurement
 #[derive(Clone, Debug, Default)]
@@ -38,12 +42,15 @@ impl Executor {
     }
 
     /// Execute a plan on input table
-    pub fn execute(&mut self, plan: &ExecutionPlan, input: Table) -> Result<ExecutionResult, String> {
+    pub fn execute(
+        &mut self,
+        plan: &ExecutionPlan,
+        input: Table,
+    ) -> Result<ExecutionResult, String> {
         let mut current_value = ExecutionValue::Table(input);
 
         for segment in &plan.segments {
             current_value = self.execute_segment(segment, current_value)?;
-            self.stats.segments_executed += 1;
         }
 
         Ok(ExecutionResult {
@@ -53,11 +60,17 @@ impl Executor {
     }
 
     /// Execute a single segment
-    fn execute_segment(&mut self, segment: &Segment, input: ExecutionValue) -> Result<ExecutionValue, String> {
+    pub(super) fn execute_segment(
+        &mut self,
+        segment: &Segment,
+        input: ExecutionValue,
+    ) -> Result<ExecutionValue, String> {
+        self.stats.segments_executed += 1;
         match segment.kind {
             SegmentKind::Colwise => self.execute_colwise_segment(segment, input),
             SegmentKind::Rowwise => self.execute_rowwise_segment(segment, input),
-            SegmentKind::Each | SegmentKind::Real => self.execute_other_segment(segment, input),
+            SegmentKind::Each => self.execute_each_segment(segment, input),
+            SegmentKind::Real => self.execute_real_segment(segment, input),
             SegmentKind::Scalar | SegmentKind::Vector => {
                 // These should not appear in table pipelines
                 Err("Scalar/Vector segments not supported in table pipelines".to_string())
@@ -66,7 +79,11 @@ impl Executor {
     }
 
     /// Execute a colwise segment (try fusion, fallback to unfused)
-    fn execute_colwise_segment(&mut self, segment: &Segment, input: ExecutionValue) -> Result<ExecutionValue, String> {
+    fn execute_colwise_segment(
+        &mut self,
+        segment: &Segment,
+        input: ExecutionValue,
+    ) -> Result<ExecutionValue, String> {
         let table = input.as_table()?;
 
         // Try to build a fused kernel
@@ -83,15 +100,178 @@ impl Executor {
         }
     }
 
+    /// Execute a fused colwise segment on one chunk, continuing from
+    /// `states` (see `ColwiseKernel::execute_with_carry`)
+    pub(super) fn execute_fused_chunk(
+        &mut self,
+        kernel: &ColwiseKernel,
+        table: &Table,
+        states: &mut Vec<KernelState>,
+    ) -> Table {
+        self.stats.segments_executed += 1;
+        self.stats.segments_fused += 1;
+        self.stats.allocations += table.columns.len();
+        kernel.execute_with_carry(table, states)
+    }
+
     /// Execute colwise segment without fusion (fallback)
-    fn execute_unfused_colwise(&mut self, _segment: &Segment, table: Table) -> Result<ExecutionValue, String> {
-        // Execute each op in sequence using existing kernels
-        // For now, return error - we haven't implemented unfused dispatch yet
-        Err("Unfused colwise execution not yet implemented".to_string())
+    ///
+    /// Runs each op in sequence, materializing an intermediate table per op.
+    /// Results are identical to running the ops one at a time.
+    fn execute_unfused_colwise(
+        &mut self,
+        segment: &Segment,
+        table: Table,
+    ) -> Result<ExecutionValue, String> {
+        let mut current = ExecutionValue::Table(table);
+
+        for op in &segment.ops {
+            let table = current.as_table()?;
+            current = self.execute_colwise_op(op, segment, table)?;
+        }
+
+        Ok(current)
+    }
+
+    /// Execute a single colwise op on a materialized table
+    fn execute_colwise_op(
+        &mut self,
+        op: &OpStep,
+        segment: &Segment,
+        table: Table,
+    ) -> Result<ExecutionValue, String> {
+        // Ops with a fused form run as a single-op kernel
+        if let Some(fused_op) = FusedOp::from_op_step(op) {
+            let kernel = ColwiseKernel {
+                ops: vec![fused_op],
+            };
+            self.stats.allocations += table.columns.len();
+            return Ok(ExecutionValue::Table(kernel.execute(&table)));
+        }
+
+        match &op.name {
+            OpId::Sum => {
+                let view = TableView::with_ori(table, segment.start_ori);
+                self.stats.allocations += 1;
+                Ok(ExecutionValue::Column(ori_ops::sum(&view)))
+            }
+            OpId::Mean => {
+                let means = table
+                    .columns
+                    .iter()
+                    .map(|col| match col {
+                        Column::F64(_) => mean0(col),
+                        _ => f64::NAN,
+                    })
+                    .collect();
+                self.stats.allocations += 1;
+                Ok(ExecutionValue::Column(Column::F64(means)))
+            }
+            OpId::WMean(window) => {
+                let view = TableView::with_ori(table, segment.start_ori);
+                self.stats.allocations += view.table.columns.len();
+                Ok(ExecutionValue::Table(ori_ops::wmean(&view, *window)))
+            }
+            OpId::RollMean(w) | OpId::RollStd(w) => {
+                let (window, min_periods) = (w.window, w.min_periods);
+                let mean = matches!(op.name, OpId::RollMean(_));
+                let mask = MomentsMask::new(if mean {
+                    MomentsMask::MEAN
+                } else {
+                    MomentsMask::STD
+                });
+                let kernel = |col: &Column| {
+                    let out = rolling_moments_past_only_f64(
+                        col.f64_data(),
+                        window,
+                        min_periods,
+                        mask,
+                        None,
+                    );
+                    Column::F64(if mean {
+                        out.mean.unwrap()
+                    } else {
+                        out.std.unwrap()
+                    })
+                };
+                Ok(ExecutionValue::Table(self.map_f64_columns(&table, kernel)))
+            }
+            OpId::Bin {
+                op: BinOpId::Spread,
+                left,
+                right,
+            } => {
+                let f64_column = |k: usize| {
+                    let col = table.columns.get(k).ok_or_else(|| {
+                        format!(
+                            "spread: column index {} out of range ({} columns)",
+                            k,
+                            table.col_count()
+                        )
+                    })?;
+                    col.as_f64_slice().map_err(|_| {
+                        format!("spread: column {} must be F64, got {}", k, col.type_name())
+                    })
+                };
+                let (x, y) = (f64_column(*left)?, f64_column(*right)?);
+                let a = op.args.first().copied().unwrap_or(1.0);
+                let b = op.args.get(1).copied().unwrap_or(0.0);
+
+                let mut out = vec![0.0; x.len()];
+                sub_mul_add_no_nulls(&mut out, x, y, a, b);
+                self.stats.allocations += 1;
+
+                let name = format!("{}-{}", table.names[*left], table.names[*right]);
+                Ok(ExecutionValue::Table(Table::new(
+                    vec![name],
+                    vec![Column::F64(out)],
+                )))
+            }
+            OpId::Select(name) => {
+                let k = table
+                    .column_index(name)
+                    .ok_or_else(|| format!("select: column '{}' not found", name))?;
+                Ok(ExecutionValue::Table(Table::new(
+                    vec![name.clone()],
+                    vec![table.columns[k].clone()],
+                )))
+            }
+            OpId::Generic(name) => {
+                let kernel: fn(&Column) -> Column = match name.as_str() {
+                    "ln" => ln_column,
+                    "abs" => abs_column,
+                    "zscore" => zscore_column,
+                    _ => return Err(format!("Unknown colwise op: {}", name)),
+                };
+                Ok(ExecutionValue::Table(self.map_f64_columns(&table, kernel)))
+            }
+            other => Err(format!("No unfused colwise kernel for {:?}", other)),
+        }
+    }
+
+    /// Apply a column kernel to every F64 column, preserving other columns
+    fn map_f64_columns(&mut self, table: &Table, kernel: impl Fn(&Column) -> Column) -> Table {
+        let columns = table
+            .columns
+            .iter()
+            .map(|col| match col {
+                Column::F64(_) => {
+                    self.stats.allocations += 1;
+                    kernel(col)
+                }
+                _ => col.clone(),
+            })
+            .collect();
+
+        Table::new(table.names.clone(), columns)
     }
 
     /// Execute a rowwise segment
-    fn execute_rowwise_segment(&mut self, _segment: &Segment, input: ExecutionValue) -> Result<ExecutionValue, String> {
+    fn execute_rowwise_segment(
+        &mut self,
+        _segment: &Segment,
+        input: ExecutionValue,
+    ) -> Result<ExecutionValue, String> {
         let table = input.as_table()?;
         self.stats.segments_unfused += 1;
 
@@ -100,16 +280,55 @@ impl Executor {
         Ok(ExecutionValue::Table(table))
     }
 
-    /// Execute other segment types (Each, Real)
-    fn execute_other_segment(&mut self, _segment: &Segment, input: ExecutionValue) -> Result<ExecutionValue, String> {
-        let table = input.as_table()?;
+    /// Execute an Each (X) segment: elementwise broadcast ops
+    fn execute_each_segment(
+        &mut self,
+        segment: &Segment,
+        input: ExecutionValue,
+    ) -> Result<ExecutionValue, String> {
+        let mut table = input.as_table()?;
         self.stats.segments_unfused += 1;
 
-        // For now, just return the table unchanged
-        // TODO: Implement Each/Real dispatch
+        for op in &segment.ops {
+            let view = TableView::with_ori(table, segment.start_ori);
+            table = match op.name {
+                OpId::AddConst => ori_ops::add(&view, op.args.first().copied().unwrap_or(0.0)),
+                OpId::SubConst => ori_ops::sub(&view, op.args.first().copied().unwrap_or(0.0)),
+                OpId::MulConst => ori_ops::mul(&view, op.args.first().copied().unwrap_or(1.0)),
+                OpId::DivConst => ori_ops::div(&view, op.args.first().copied().unwrap_or(1.0)),
+                ref other => return Err(format!("No Each (X) kernel for {:?}", other)),
+            };
+            self.stats.allocations += table.columns.len();
+        }
+
         Ok(ExecutionValue::Table(table))
     }
 
+    /// Execute a Real (R) segment: reduce the whole table to a scalar
+    ///
+    /// Only reducers (`Sum`, `Mean`) have a Real kernel; the planner gives
+    /// each reducer a segment of its own.
+    fn execute_real_segment(
+        &mut self,
+        segment: &Segment,
+        input: ExecutionValue,
+    ) -> Result<ExecutionValue, String> {
+        let mut current = input;
+        self.stats.segments_unfused += 1;
+
+        for op in &segment.ops {
+            let view = TableView::with_ori(current.as_table()?, segment.start_ori);
+            let reduced = match op.name {
+                OpId::Sum => ori_ops::sum(&view),
+                OpId::Mean => ori_ops::mean(&view),
+                ref other => return Err(format!("No Real (R) kernel for {:?}", other)),
+            };
+            current = ExecutionValue::Scalar(reduced.f64_data()[0]);
+        }
+
+        Ok(current)
+    }
+
     /// Get execution statistics
     pub fn stats(&self) -> &ExecutionStats {
         &self.stats
@@ -148,19 +367,25 @@ pub struct ExecutionResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pipeline::{PipeIR, Step, OpId, Planner};
-    use crate::table::ORI_H;
+    use crate::pipeline::{OpId, PipeIR, PlanError, Planner, Step};
+    use crate::table::{ORI_H, ORI_R, ORI_X};
 
     #[test]
     fn test_execute_simple_pipeline() {
         // Create IR: (o H) (x+ 10) (x* 2)
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::AddConst, args: vec![10.0] });
-        ir.push(Step::Op { name: OpId::MulConst, args: vec![2.0] });
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![10.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.0],
+        });
 
         // Plan
-        let plan = Planner::plan(&ir);
+        let plan = Planner::plan(&ir).unwrap();
 
         // Create input table
         let input = Table::new(
@@ -194,10 +419,13 @@ mod tests {
         // Create IR: (o H) (dlog)
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::Dlog, args: vec![1.0] });
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
 
         // Plan
-        let plan = Planner::plan(&ir);
+        let plan = Planner::plan(&ir).unwrap();
 
         // Create input table
         let input = Table::new(
@@ -228,18 +456,18 @@ mod tests {
         // Create IR: (o H) (x* 3)
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::MulConst, args: vec![3.0] });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![3.0],
+        });
 
         // Plan
-        let plan = Planner::plan(&ir);
+        let plan = Planner::plan(&ir).unwrap();
 
         // Create input table with 2 columns
         let input = Table::new(
             vec!["a".to_string(), "b".to_string()],
-            vec![
-                Column::F64(vec![1.0, 2.0]),
-                Column::F64(vec![3.0, 4.0]),
-            ],
+            vec![Column::F64(vec![1.0, 2.0]), Column::F64(vec![3.0, 4.0])],
         );
 
         // Execute
@@ -259,4 +487,279 @@ mod tests {
         // Check that we allocated 2 columns (one per input column)
         assert_eq!(result.stats.allocations, 2);
     }
+
+    #[test]
+    fn test_unfused_colwise_fallback() {
+        // Create IR: (o H) (x* 2) (ln) (x+ 1) — Generic ln is not fusable (OpId::Ln is)
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::Generic("ln".to_string()),
+            args: vec![],
+        });
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![1.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        assert_eq!(plan.segments.len(), 1);
+        assert!(!plan.segments[0].is_fusable());
+
+        let data = vec![1.0, 2.0, f64::NAN, 4.0];
+        let input = Table::new(
+            vec!["a".to_string(), "d".to_string()],
+            vec![Column::F64(data.clone()), Column::Date(vec![1, 2, 3, 4])],
+        );
+
+        let mut executor = Executor::new();
+        let result = executor.execute(&plan, input).unwrap();
+
+        // Manual baseline: ops applied one at a time
+        let expected: Vec<f64> = data.iter().map(|&x| (x * 2.0).ln() + 1.0).collect();
+
+        let ExecutionValue::Table(table) = result.value else {
+            panic!("Expected Table result");
+        };
+        let out = table.columns[0].f64_data();
+        for (got, want) in out.iter().zip(&expected) {
+            if want.is_nan() {
+                assert!(got.is_nan());
+            } else {
+                assert!((got - want).abs() < 1e-12);
+            }
+        }
+        assert_eq!(table.columns[1].date_data(), &[1, 2, 3, 4]);
+
+        assert_eq!(result.stats.segments_executed, 1);
+        assert_eq!(result.stats.segments_fused, 0);
+        assert_eq!(result.stats.segments_unfused, 1);
+    }
+
+    #[test]
+    fn test_unfused_reducer_segment() {
+        // (o H) (x+ 1) (sum) — sum segment runs through the fallback
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::Sum,
+            args: vec![],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        let input = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![Column::F64(vec![1.0, 2.0]), Column::F64(vec![3.0, 4.0])],
+        );
+
+        let mut executor = Executor::new();
+        let result = executor.execute(&plan, input).unwrap();
+
+        let ExecutionValue::Column(col) = result.value else {
+            panic!("Expected Column result");
+        };
+        assert_eq!(col.f64_data(), &[5.0, 9.0]);
+        assert_eq!(result.stats.segments_fused, 1);
+        assert_eq!(result.stats.segments_unfused, 1);
+    }
+
+    #[test]
+    fn test_unfused_unknown_op_errors() {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Generic("nope".to_string()),
+            args: vec![],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        let input = Table::new(vec!["a".to_string()], vec![Column::F64(vec![1.0])]);
+
+        let mut executor = Executor::new();
+        let err = executor.execute(&plan, input).err().unwrap();
+        assert!(err.contains("nope"));
+    }
+
+    #[test]
+    fn test_each_segment_add_const() {
+        // (o X) (x+ 5) (x* 2) — every cell transformed
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_X));
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![5.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        assert_eq!(plan.segments[0].kind, SegmentKind::Each);
+
+        let input = Table::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0]),
+                Column::F64(vec![3.0, f64::NAN]),
+            ],
+        );
+
+        let mut executor = Executor::new();
+        let result = executor.execute(&plan, input).unwrap();
+
+        let ExecutionValue::Table(table) = result.value else {
+            panic!("Expected Table result");
+        };
+        assert_eq!(table.columns[0].f64_data(), &[12.0, 14.0]);
+        assert_eq!(table.columns[1].f64_data()[0], 16.0);
+        assert!(table.columns[1].f64_data()[1].is_nan());
+    }
+
+    #[test]
+    fn test_each_segment_unsupported_op_errors() {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_X));
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        let input = Table::new(vec!["a".to_string()], vec![Column::F64(vec![1.0])]);
+
+        let err = Executor::new().execute(&plan, input).err().unwrap();
+        assert!(err.contains("Each"), "{}", err);
+    }
+
+    fn real_reduce(op: OpId) -> Result<ExecutionResult, String> {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_R));
+        ir.push(Step::Op {
+            name: op,
+            args: vec![],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        assert_eq!(plan.segments[0].kind, SegmentKind::Real);
+        let input = Table::new(
+            vec!["a".to_string(), "d".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0, f64::NAN]),
+                Column::Date(vec![1, 2, 3]),
+                Column::F64(vec![3.0, 4.0, 5.0]),
+            ],
+        );
+        Executor::new().execute(&plan, input)
+    }
+
+    #[test]
+    fn test_real_segment_sum_and_mean_scalars() {
+        // (o R) (sum): NaN-skipping sum over every F64 cell
+        let result = real_reduce(OpId::Sum).unwrap();
+        let ExecutionValue::Scalar(total) = result.value else {
+            panic!("Expected Scalar result, got {:?}", result.value);
+        };
+        assert_eq!(total, 15.0);
+        assert_eq!(result.stats.segments_unfused, 1);
+
+        // (o R) (mean): 15 / 5 valid cells
+        let ExecutionValue::Scalar(mean) = real_reduce(OpId::Mean).unwrap().value else {
+            panic!("Expected Scalar result");
+        };
+        assert_eq!(mean, 3.0);
+    }
+
+    #[test]
+    fn test_real_segment_non_reducer_errors() {
+        let err = real_reduce(OpId::Dlog).err().unwrap();
+        assert!(err.contains("Real"), "{}", err);
+    }
+
+    #[test]
+    fn test_binop_spread_matches_kernel() {
+        // (o H) (spread 0 2 [2, 1]) (x* 10): (a - c) * 2 + 1, then scaled
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::BinOp {
+            name: BinOpId::Spread,
+            left: 0,
+            right: 2,
+            args: vec![2.0, 1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![10.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        assert_eq!(plan.segments.len(), 2);
+
+        let a = vec![10.0, 20.0, f64::NAN, 40.0];
+        let c = vec![1.0, 2.5, 3.0, 4.0];
+        let input = Table::new(
+            vec!["a".to_string(), "d".to_string(), "c".to_string()],
+            vec![
+                Column::F64(a.clone()),
+                Column::Date(vec![1, 2, 3, 4]),
+                Column::F64(c.clone()),
+            ],
+        );
+
+        let result = Executor::new().execute(&plan, input).unwrap();
+        let ExecutionValue::Table(table) = result.value else {
+            panic!("Expected Table result");
+        };
+
+        let mut expected = vec![0.0; 4];
+        sub_mul_add_no_nulls(&mut expected, &a, &c, 2.0, 1.0);
+        assert_eq!(table.names, vec!["a-c"]);
+        for (got, want) in table.columns[0].f64_data().iter().zip(&expected) {
+            assert!((got.is_nan() && want.is_nan()) || *got == want * 10.0);
+        }
+    }
+
+    #[test]
+    fn test_binop_spread_errors() {
+        let spread = |left, right| Step::BinOp {
+            name: BinOpId::Spread,
+            left,
+            right,
+            args: vec![],
+        };
+        let input = Table::new(
+            vec!["a".to_string(), "d".to_string()],
+            vec![Column::F64(vec![1.0]), Column::Date(vec![1])],
+        );
+
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(spread(0, 5));
+        let plan = Planner::plan(&ir).unwrap();
+        let err = Executor::new().execute(&plan, input.clone()).err().unwrap();
+        assert_eq!(err, "spread: column index 5 out of range (2 columns)");
+
+        let mut ir = PipeIR::new();
+        ir.push(spread(0, 1));
+        let plan = Planner::plan(&ir).unwrap();
+        let err = Executor::new().execute(&plan, input).err().unwrap();
+        assert_eq!(err, "spread: column 1 must be F64, got Date");
+
+        // Indices only address columns in a colwise orientation
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_X));
+        ir.push(spread(0, 1));
+        assert!(matches!(
+            Planner::plan(&ir),
+            Err(PlanError::InvalidOp { .. })
+        ));
+    }
 }
@@ -10,10 +10,20 @@ use crate::table::Ori;
 pub enum OpId {
     /// Delta log: dlog(period)
     Dlog,
+    /// First difference: diff(lag)
+    Diff,
+    /// Simple return: pct_change(lag)
+    PctChange,
     /// Rolling window mean: w5 (window=5)
     W5,
+    /// Trailing rolling mean over [i-window+1, i], skipping NaN: wmean(window)
+    WMean(usize),
     /// Cumulative sum: cs1
     Cs1,
+    /// Cumulative product: cp1
+    Cp1,
+    /// Lag/lead: shift(lag), negative lag leads
+    Shift,
     /// Add constant: x+ c
     AddConst,
     /// Subtract constant: x- c
@@ -22,16 +32,92 @@ pub enum OpId {
     MulConst,
     /// Divide constant: x/ c
     DivConst,
+    /// Square root: sqrt
+    Sqrt,
+    /// Exponential: exp
+    Exp,
+    /// Power with constant exponent: pow p
+    Pow,
+    /// Natural logarithm: ln
+    Ln,
+    /// Absolute value: abs
+    Abs,
+    /// Unary negate: neg
+    Neg,
+    /// Sign: sign (-1, 0 or 1)
+    Sign,
+    /// Clamp into [lo, hi]: clip(lo, hi), NaN or missing bound = unbounded
+    Clip,
+    /// Past-only rolling mean: roll_mean(window, min_periods)
+    RollMean(RollWindow),
+    /// Past-only rolling standard deviation: roll_std(window, min_periods)
+    RollStd(RollWindow),
     /// Sum aggregation
     Sum,
     /// Mean aggregation
     Mean,
+    /// Two-column op on column indices (lowered from `Step::BinOp`)
+    Bin {
+        op: BinOpId,
+        left: usize,
+        right: usize,
+    },
+    /// Keep only the named column: select(name)
+    Select(String),
     /// Generic operation (fallback)
     Generic(String),
 }
 
+/// Binary (two-column) operation identifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BinOpId {
+    /// Scaled spread: (left - right) * a + b, args [a, b] (default 1, 0)
+    Spread,
+}
+
+/// Window parameters for rolling ops
+///
+/// Window is [i-window, i-1] (past-only); `min_periods` defaults to `window`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RollWindow {
+    pub window: usize,
+    pub min_periods: Option<usize>,
+}
+
+impl RollWindow {
+    /// Full window required (min_periods = window)
+    pub fn new(window: usize) -> Self {
+        RollWindow {
+            window,
+            min_periods: None,
+        }
+    }
+
+    /// Emit once `min_periods` valid observations are in the window
+    pub fn with_min_periods(window: usize, min_periods: usize) -> Self {
+        RollWindow {
+            window,
+            min_periods: Some(min_periods),
+        }
+    }
+
+    /// Check window > 0 and min_periods <= window
+    pub fn validate(&self) -> Result<(), String> {
+        if self.window == 0 {
+            return Err("rolling window must be > 0".to_string());
+        }
+        match self.min_periods {
+            Some(m) if m > self.window => Err(format!(
+                "min_periods {} exceeds rolling window {}",
+                m, self.window
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
 /// A single step in the pipeline IR
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Step {
     /// Set absolute orientation: (o A)
     OriSet(Ori),
@@ -45,6 +131,15 @@ pub enum Step {
         /// Scalar arguments (constants for arithmetic, lag for dlog, etc.)
         args: Vec<f64>,
     },
+
+    /// Combine two columns (by index) into a single-column table
+    BinOp {
+        name: BinOpId,
+        left: usize,
+        right: usize,
+        /// Scalar arguments (see `BinOpId`)
+        args: Vec<f64>,
+    },
 }
 
 /// Pipeline intermediate representation
@@ -94,8 +189,14 @@ mod tests {
         assert!(ir.is_empty());
 
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::Dlog, args: vec![1.0] });
-        ir.push(Step::Op { name: OpId::AddConst, args: vec![10.0] });
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![10.0],
+        });
 
         assert_eq!(ir.len(), 3);
     }
@@ -103,8 +204,14 @@ mod tests {
     #[test]
     fn test_step_types() {
         let step1 = Step::OriSet(ORI_H);
-        let step2 = Step::Op { name: OpId::W5, args: vec![] };
-        let step3 = Step::Op { name: OpId::MulConst, args: vec![2.5] };
+        let step2 = Step::Op {
+            name: OpId::W5,
+            args: vec![],
+        };
+        let step3 = Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.5],
+        };
 
         let mut ir = PipeIR::new();
         ir.push(step1);
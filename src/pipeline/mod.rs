@@ -31,14 +31,16 @@
 //! - No cross-segment optimization
 //! - Single-threaded execution
 
-pub mod ir;
-pub mod execution_plan;
-pub mod planner;
+pub mod chunked;
 pub mod colwise_fused;
+pub mod execution_plan;
 pub mod executor;
+pub mod ir;
+pub mod planner;
 
-pub use ir::{OpId, Step, PipeIR};
-pub use execution_plan::{ExecutionPlan, Segment, SegmentKind, OpStep};
-pub use planner::Planner;
-pub use colwise_fused::{ColwiseKernel, FusedOp};
-pub use executor::{Executor, ExecutionValue, ExecutionResult, ExecutionStats};
+pub use chunked::ChunkState;
+pub use colwise_fused::{ColwiseKernel, FusedOp, KernelState};
+pub use execution_plan::{ExecutionPlan, OpStep, Segment, SegmentKind};
+pub use executor::{ExecutionResult, ExecutionStats, ExecutionValue, Executor};
+pub use ir::{BinOpId, OpId, PipeIR, RollWindow, Step};
+pub use planner::{PlanError, Planner};
@@ -4,9 +4,36 @@
 //! 1. Tracking orientation class symbolically
 //! 2. Splitting into segments at boundaries (orientation changes, reducers, etc.)
 
-use crate::table::{Ori, OriClass, ORI_H};
+use super::execution_plan::{ExecutionPlan, OpStep, Segment, SegmentKind};
 use super::ir::{OpId, PipeIR, Step};
-use super::execution_plan::{ExecutionPlan, Segment, SegmentKind, OpStep};
+use crate::table::{Ori, OriClass, ORI_H};
+use std::fmt;
+
+/// Planning error: the IR cannot be turned into a valid plan
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlanError {
+    /// Relative orientation `(ro rel)` where `current` or `rel` is not D4
+    /// (X or R): there is no composition, only an absolute `(o ...)` applies
+    NonD4RelativeOri { current: Ori, rel: Ori },
+    /// Op parameters are invalid (e.g. rolling window of 0)
+    InvalidOp { op: OpId, reason: String },
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::NonD4RelativeOri { current, rel } => write!(
+                f,
+                "cannot compose relative orientation {} onto {}: both must be D4",
+                rel.canonical_name(),
+                current.canonical_name()
+            ),
+            PlanError::InvalidOp { op, reason } => write!(f, "{:?}: {}", op, reason),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
 
 /// Pipeline planner
 pub struct Planner {
@@ -31,23 +58,46 @@ impl Planner {
     }
 
     /// Plan a pipeline IR into an execution plan
-    pub fn plan(ir: &PipeIR) -> ExecutionPlan {
+    ///
+    /// # Errors
+    /// - `PlanError::NonD4RelativeOri` for `(ro A)` when the current
+    ///   orientation or `A` is X or R
+    /// - `PlanError::InvalidOp` for invalid op parameters (rolling window of
+    ///   0, min_periods larger than the window)
+    pub fn plan(ir: &PipeIR) -> Result<ExecutionPlan, PlanError> {
         let mut planner = Planner::new();
 
         for step in &ir.steps {
-            planner.process_step(step);
+            planner.process_step(step)?;
         }
 
         // Flush any remaining segment
         planner.flush_segment();
 
-        ExecutionPlan {
+        Ok(ExecutionPlan {
             segments: planner.segments,
+        })
+    }
+
+    /// Validate op parameters carried in the OpId
+    fn validate_op(op: &OpId) -> Result<(), PlanError> {
+        match op {
+            OpId::RollMean(w) | OpId::RollStd(w) => {
+                w.validate().map_err(|reason| PlanError::InvalidOp {
+                    op: op.clone(),
+                    reason,
+                })
+            }
+            OpId::WMean(0) => Err(PlanError::InvalidOp {
+                op: op.clone(),
+                reason: "rolling window must be > 0".to_string(),
+            }),
+            _ => Ok(()),
         }
     }
 
     /// Process a single step
-    fn process_step(&mut self, step: &Step) {
+    fn process_step(&mut self, step: &Step) -> Result<(), PlanError> {
         match step {
             Step::OriSet(new_ori) => {
                 // Absolute orientation change - always flush
@@ -56,25 +106,26 @@ impl Planner {
             }
 
             Step::OriRel(rel_ori) => {
-                // Relative orientation change - compose and check if class changed
-                if let Some(new_ori) = super::super::table::d4_compose::compose(self.current_ori, *rel_ori) {
-                    let old_class = self.current_ori.class();
-                    let new_class = new_ori.class();
-
-                    if old_class != new_class {
-                        // Class changed - flush segment
-                        self.flush_segment();
-                    }
-
-                    self.current_ori = new_ori;
-                } else {
-                    // Composition failed (X or R) - flush and set absolute
+                // Relative orientation change - compose and check if class changed.
+                // Composition only exists within D4 (same as
+                // TableView::compose_orientation returning None)
+                let new_ori = super::super::table::d4_compose::compose(self.current_ori, *rel_ori)
+                    .ok_or(PlanError::NonD4RelativeOri {
+                        current: self.current_ori,
+                        rel: *rel_ori,
+                    })?;
+
+                if self.current_ori.class() != new_ori.class() {
+                    // Class changed - flush segment
                     self.flush_segment();
-                    self.current_ori = *rel_ori;
                 }
+
+                self.current_ori = new_ori;
             }
 
             Step::Op { name, args } => {
+                Self::validate_op(name)?;
+
                 // Check if this op requires a boundary
                 if self.requires_boundary(name) {
                     self.flush_segment();
@@ -93,18 +144,66 @@ impl Planner {
                     });
                 }
 
-                // If this was a reducer, flush immediately
-                if self.is_reducer(name) {
+                // Reducers and standalone ops end their segment
+                if self.is_reducer(name) || self.is_standalone(name) {
                     self.flush_segment();
                 }
             }
+
+            Step::BinOp {
+                name,
+                left,
+                right,
+                args,
+            } => {
+                let op = OpId::Bin {
+                    op: *name,
+                    left: *left,
+                    right: *right,
+                };
+                // Column indices only address columns in a colwise view
+                if self.current_ori.class() != OriClass::ColwiseLike {
+                    return Err(PlanError::InvalidOp {
+                        op,
+                        reason: format!(
+                            "binary column ops need a colwise orientation, got {}",
+                            self.current_ori.canonical_name()
+                        ),
+                    });
+                }
+                self.process_step(&Step::Op {
+                    name: op,
+                    args: args.clone(),
+                })?;
+            }
         }
+
+        Ok(())
     }
 
     /// Check if an operation requires a segment boundary before it
     fn requires_boundary(&self, op: &OpId) -> bool {
         // Reducers change shape/type - always boundary
-        self.is_reducer(op)
+        self.is_reducer(op) || self.is_standalone(op)
+    }
+
+    /// Check if an operation runs in a segment of its own
+    ///
+    /// Rolling-window ops need the full window history, which the
+    /// single-pass fused kernel cannot provide; isolating them keeps the
+    /// neighbouring ops fusable.
+    ///
+    /// Binary column ops and column selection reshape the table to a
+    /// single column.
+    fn is_standalone(&self, op: &OpId) -> bool {
+        matches!(
+            op,
+            OpId::WMean(_)
+                | OpId::RollMean(_)
+                | OpId::RollStd(_)
+                | OpId::Bin { .. }
+                | OpId::Select(_)
+        )
     }
 
     /// Check if an operation is a reducer (changes shape/type)
@@ -147,16 +246,23 @@ impl Default for Planner {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::table::{ORI_H, ORI_Z};
+    use crate::pipeline::ir::RollWindow;
+    use crate::table::{ORI_H, ORI_R, ORI_X, ORI_Z};
 
     #[test]
     fn test_simple_colwise_segment() {
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::Dlog, args: vec![1.0] });
-        ir.push(Step::Op { name: OpId::AddConst, args: vec![10.0] });
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![10.0],
+        });
 
-        let plan = Planner::plan(&ir);
+        let plan = Planner::plan(&ir).unwrap();
 
         assert_eq!(plan.segments.len(), 1);
         assert_eq!(plan.segments[0].kind, SegmentKind::Colwise);
@@ -167,11 +273,17 @@ mod tests {
     fn test_orientation_change_splits_segment() {
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::Dlog, args: vec![1.0] });
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
         ir.push(Step::OriSet(ORI_Z)); // Orientation change
-        ir.push(Step::Op { name: OpId::W5, args: vec![] });
+        ir.push(Step::Op {
+            name: OpId::W5,
+            args: vec![],
+        });
 
-        let plan = Planner::plan(&ir);
+        let plan = Planner::plan(&ir).unwrap();
 
         assert_eq!(plan.segments.len(), 2);
         assert_eq!(plan.segments[0].kind, SegmentKind::Colwise);
@@ -184,11 +296,20 @@ mod tests {
     fn test_reducer_splits_segment() {
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::Dlog, args: vec![1.0] });
-        ir.push(Step::Op { name: OpId::Sum, args: vec![] }); // Reducer
-        ir.push(Step::Op { name: OpId::AddConst, args: vec![1.0] });
-
-        let plan = Planner::plan(&ir);
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::Sum,
+            args: vec![],
+        }); // Reducer
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![1.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
 
         // Should have 3 segments: [dlog], [sum], [add]
         assert_eq!(plan.segments.len(), 3);
@@ -197,10 +318,111 @@ mod tests {
         assert_eq!(plan.segments[2].ops.len(), 1); // add
     }
 
+    #[test]
+    fn test_rolling_op_is_standalone_segment() {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::RollStd(RollWindow::new(20)),
+            args: vec![],
+        });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        assert_eq!(plan.len(), 3);
+        assert_eq!(
+            plan.segments[1].ops[0].name,
+            OpId::RollStd(RollWindow::new(20))
+        );
+        assert!(plan.segments[0].is_fusable());
+        assert!(!plan.segments[1].is_fusable());
+        assert!(plan.segments[2].is_fusable());
+    }
+
+    #[test]
+    fn test_rolling_window_validation() {
+        for (w, ok) in [
+            (RollWindow::new(0), false),
+            (RollWindow::with_min_periods(5, 6), false),
+            (RollWindow::with_min_periods(5, 2), true),
+        ] {
+            let mut ir = PipeIR::new();
+            ir.push(Step::Op {
+                name: OpId::RollMean(w),
+                args: vec![],
+            });
+            assert_eq!(Planner::plan(&ir).is_ok(), ok, "{:?}", w);
+        }
+    }
+
+    #[test]
+    fn test_relative_ori_from_each_is_error() {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_X));
+        ir.push(Step::OriRel(ORI_Z));
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+
+        let err = Planner::plan(&ir).unwrap_err();
+        assert_eq!(
+            err,
+            PlanError::NonD4RelativeOri {
+                current: ORI_X,
+                rel: ORI_Z
+            }
+        );
+
+        // Relative R onto a D4 orientation is equally undefined
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriRel(ORI_R));
+        assert!(matches!(
+            Planner::plan(&ir),
+            Err(PlanError::NonD4RelativeOri { .. })
+        ));
+    }
+
+    #[test]
+    fn test_relative_ori_class_change_splits_segment() {
+        // (o H) (dlog) (ro Z) (dlog) (ro Z) (x+ 1)
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::OriRel(ORI_Z));
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::OriRel(ORI_Z));
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![1.0],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
+        assert_eq!(plan.len(), 3);
+        assert_eq!(plan.segments[0].kind, SegmentKind::Colwise);
+        assert_eq!(plan.segments[1].kind, SegmentKind::Rowwise);
+        assert_eq!(plan.segments[1].start_ori, ORI_Z);
+        // Z ∘ Z = H
+        assert_eq!(plan.segments[2].start_ori, ORI_H);
+    }
+
     #[test]
     fn test_empty_ir() {
         let ir = PipeIR::new();
-        let plan = Planner::plan(&ir);
+        let plan = Planner::plan(&ir).unwrap();
 
         assert!(plan.segments.is_empty());
     }
@@ -209,12 +431,24 @@ mod tests {
     fn test_multiple_ops_in_segment() {
         let mut ir = PipeIR::new();
         ir.push(Step::OriSet(ORI_H));
-        ir.push(Step::Op { name: OpId::Dlog, args: vec![1.0] });
-        ir.push(Step::Op { name: OpId::AddConst, args: vec![5.0] });
-        ir.push(Step::Op { name: OpId::MulConst, args: vec![2.0] });
-        ir.push(Step::Op { name: OpId::W5, args: vec![] });
-
-        let plan = Planner::plan(&ir);
+        ir.push(Step::Op {
+            name: OpId::Dlog,
+            args: vec![1.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::AddConst,
+            args: vec![5.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.0],
+        });
+        ir.push(Step::Op {
+            name: OpId::W5,
+            args: vec![],
+        });
+
+        let plan = Planner::plan(&ir).unwrap();
 
         assert_eq!(plan.segments.len(), 1);
         assert_eq!(plan.segments[0].kind, SegmentKind::Colwise);
@@ -105,6 +105,95 @@ impl Bitmap {
         }
     }
 
+    /// Bitwise NOT: out = !a
+    ///
+    /// Unused bits past `len` in the last word stay zero.
+    pub fn not_into(a: &Bitmap, out: &mut Bitmap) {
+        assert_eq!(a.len, out.len);
+        for w in 0..a.bits.len() {
+            out.bits[w] = !a.bits[w];
+        }
+        if let Some(last) = out.bits.last_mut() {
+            *last &= a.tail_mask();
+        }
+    }
+
+    /// Bitwise XOR: out = a ^ b
+    pub fn xor_into(a: &Bitmap, b: &Bitmap, out: &mut Bitmap) {
+        assert_eq!(a.len, b.len);
+        assert_eq!(a.len, out.len);
+        for w in 0..a.bits.len() {
+            out.bits[w] = a.bits[w] ^ b.bits[w];
+        }
+    }
+
+    /// Mask of the used bits in the last word (all ones if len % 64 == 0)
+    #[inline]
+    fn tail_mask(&self) -> u64 {
+        match self.len % 64 {
+            0 => !0u64,
+            rem => (1u64 << rem) - 1,
+        }
+    }
+
+    /// Number of valid (1) bits
+    ///
+    /// Unused bits past `len` in the last word are masked off, so stray
+    /// bits written through `bits_mut` are never counted.
+    pub fn count_valid(&self) -> usize {
+        let Some((last, full)) = self.bits.split_last() else {
+            return 0;
+        };
+        let body: usize = full.iter().map(|w| w.count_ones() as usize).sum();
+        body + (last & self.tail_mask()).count_ones() as usize
+    }
+
+    /// Number of null (0) bits
+    pub fn count_null(&self) -> usize {
+        self.len - self.count_valid()
+    }
+
+    /// True if every element is valid (vacuously true when empty)
+    pub fn all_valid(&self) -> bool {
+        self.count_valid() == self.len
+    }
+
+    /// True if at least one element is null
+    pub fn any_null(&self) -> bool {
+        !self.all_valid()
+    }
+
+    /// Indices of valid (1) bits, ascending
+    ///
+    /// Walks word by word, emitting each set bit via `trailing_zeros`, so
+    /// sparse bitmaps cost O(words + set bits) rather than O(len).
+    pub fn iter_valid(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter_bits(false)
+    }
+
+    /// Indices of null (0) bits, ascending (see `iter_valid`)
+    pub fn iter_null(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter_bits(true)
+    }
+
+    fn iter_bits(&self, invert: bool) -> impl Iterator<Item = usize> + '_ {
+        let last = self.bits.len().saturating_sub(1);
+        self.bits.iter().enumerate().flat_map(move |(w, &word)| {
+            let mut word = if invert { !word } else { word };
+            if w == last {
+                word &= self.tail_mask();
+            }
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1; // clear lowest set bit
+                Some(w * 64 + bit)
+            })
+        })
+    }
+
     /// Clone the bits vector
     pub fn clone_bits(&self) -> Vec<u64> {
         self.bits.clone()
@@ -156,4 +245,99 @@ mod tests {
         assert!(!out.get(20), "b was null");
         assert!(out.get(30), "both valid");
     }
+
+    #[test]
+    fn test_count_partial_last_word() {
+        let mut bm = Bitmap::new_all_valid(100);
+        assert_eq!(bm.count_valid(), 100);
+        assert_eq!(bm.count_null(), 0);
+        assert!(bm.all_valid() && !bm.any_null());
+
+        bm.set(0, false);
+        bm.set(63, false);
+        bm.set(64, false);
+        bm.set(99, false);
+        assert_eq!(bm.count_valid(), 96);
+        assert_eq!(bm.count_null(), 4);
+        assert!(!bm.all_valid() && bm.any_null());
+
+        // Phantom bits past len are ignored
+        bm.bits_mut()[1] |= !0u64 << 36;
+        assert_eq!(bm.count_valid(), 96);
+        assert_eq!(Bitmap::new_all_null(100).count_null(), 100);
+    }
+
+    #[test]
+    fn test_count_word_aligned_and_empty() {
+        let bm = Bitmap::new_all_valid(128);
+        assert_eq!(bm.count_valid(), 128);
+
+        let empty = Bitmap::new_all_valid(0);
+        assert_eq!(empty.count_valid(), 0);
+        assert!(empty.all_valid() && !empty.any_null());
+    }
+
+    #[test]
+    fn test_not_all_valid_is_all_null() {
+        let a = Bitmap::new_all_valid(128);
+        let mut out = Bitmap::new_all_valid(128);
+        Bitmap::not_into(&a, &mut out);
+        assert_eq!(out.count_valid(), 0);
+    }
+
+    #[test]
+    fn test_not_partial_word_leaves_tail_zero() {
+        let mut a = Bitmap::new_all_null(100);
+        a.set(3, true);
+
+        let mut out = Bitmap::new_all_null(100);
+        Bitmap::not_into(&a, &mut out);
+
+        assert!(!out.get(3));
+        assert!(out.get(0) && out.get(99));
+        assert_eq!(out.count_valid(), 99);
+        // Bits 100..127 of the last word are unused and must stay zero
+        assert_eq!(out.word(1) >> 36, 0);
+    }
+
+    #[test]
+    fn test_xor() {
+        let mut a = Bitmap::new_all_valid(70);
+        let mut b = Bitmap::new_all_valid(70);
+        a.set(5, false);
+        b.set(5, false);
+        b.set(69, false);
+
+        let mut out = Bitmap::new_all_null(70);
+        Bitmap::xor_into(&a, &b, &mut out);
+
+        assert!(!out.get(5), "both null");
+        assert!(out.get(69), "only b null");
+        assert_eq!(out.count_valid(), 1);
+    }
+
+    #[test]
+    fn test_iter_sparse_matches_scan() {
+        let len = 200;
+        let mut bm = Bitmap::new_all_null(len);
+        for i in [0, 63, 64, 130, 191, 199] {
+            bm.set(i, true);
+        }
+        // Phantom bits past len are never emitted
+        bm.bits_mut()[3] |= !0u64 << 8;
+
+        let naive_valid: Vec<usize> = (0..len).filter(|&i| bm.get(i)).collect();
+        let naive_null: Vec<usize> = (0..len).filter(|&i| !bm.get(i)).collect();
+
+        assert_eq!(bm.iter_valid().collect::<Vec<_>>(), naive_valid);
+        assert_eq!(
+            bm.iter_valid().collect::<Vec<_>>(),
+            vec![0, 63, 64, 130, 191, 199]
+        );
+        assert_eq!(bm.iter_null().collect::<Vec<_>>(), naive_null);
+        assert_eq!(bm.iter_null().count(), len - 6);
+
+        assert_eq!(Bitmap::new_all_valid(0).iter_valid().count(), 0);
+        assert_eq!(Bitmap::new_all_valid(128).iter_null().count(), 0);
+    }
 }
@@ -1,5 +1,11 @@
 //! Typed column with embedded null sentinels (kdb-style)
 
+use super::bitmap::Bitmap;
+#[cfg(feature = "mmap")]
+use super::mmap::MmapF64;
+use crate::builtins::kernels_masked::unary_no_nulls;
+use std::sync::Arc;
+
 /// Null sentinel for Date columns (i32 days since epoch)
 ///
 /// Using i32::MIN as the null date sentinel, similar to kdb's type-specific nulls.
@@ -18,13 +24,48 @@ pub const NULL_TIMESTAMP: i64 = i64::MIN;
 /// This avoids bitmap overhead and keeps null embedded in the data vector.
 pub const NULL_TS: i64 = i64::MIN;
 
+/// Null sentinel for I64 columns
+///
+/// Using i64::MIN as the null integer sentinel, similar to kdb's `0Nj`.
+pub const NULL_I64: i64 = i64::MIN;
+
+/// Null sentinel for Sym columns (code with no dictionary entry)
+pub const NULL_SYM: u32 = u32::MAX;
+
+/// Column type mismatch error (recoverable alternative to a panic)
+///
+/// Carries the expected and actual type tags (see `Column::type_name`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnTypeError {
+    pub expected: &'static str,
+    pub actual: &'static str,
+}
+
+impl ColumnTypeError {
+    pub fn new(expected: &'static str, actual: &'static str) -> Self {
+        Self { expected, actual }
+    }
+}
+
+impl std::fmt::Display for ColumnTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {} column, got {}", self.expected, self.actual)
+    }
+}
+
+impl std::error::Error for ColumnTypeError {}
+
 /// A typed column of data with type-specific null representation (kdb-style)
 ///
 /// All nulls are embedded as sentinel values in the data vector:
 /// - F64: f64::NAN
+/// - F32: f32::NAN
 /// - Date: NULL_DATE (i32::MIN)
 /// - Timestamp: NULL_TIMESTAMP (i64::MIN)
 /// - Ts: NULL_TS (i64::MIN, deprecated)
+/// - I64: NULL_I64 (i64::MIN)
+/// - Sym: NULL_SYM (u32::MAX)
+/// - Bool: no null (masks are always fully defined)
 ///
 /// No validity bitmaps - keeps compute engine pure and vectorizable.
 #[derive(Clone, Debug)]
@@ -53,7 +94,42 @@ pub enum Column {
     /// Missing values represented as NULL_TS (i64::MIN).
     /// Pure kdb-style: null is a value, no bitmap overhead.
     Ts(Vec<i64>),
-    // TODO: I64, Sym, Bool
+
+    /// Bool column: predicate results and row masks
+    ///
+    /// Has no null sentinel: comparisons against NaN produce `false`,
+    /// so a mask is always fully defined.
+    Bool(Vec<bool>),
+
+    /// I64 column: signed integers (counts, ids, volumes)
+    ///
+    /// Missing values represented as NULL_I64 (i64::MIN).
+    I64(Vec<i64>),
+
+    /// F32 column: single-precision floats for memory-constrained datasets
+    ///
+    /// Missing values represented as f32::NAN. Reductions accumulate in f64.
+    F32(Vec<f32>),
+
+    /// F64 column backed by a read-only memory-mapped file (feature `mmap`)
+    ///
+    /// Same values and NaN nulls as F64, but nothing is copied into RAM.
+    /// Read accessors (`f64_data`, `as_f64_slice`) return the mapped slice;
+    /// `try_f64_data_mut` returns an error and `f64_data_mut` panics. Ops
+    /// that build a new column (filter, take, slice, kernels) return an
+    /// owned F64.
+    #[cfg(feature = "mmap")]
+    F64Mmap(MmapF64),
+
+    /// Sym column: interned strings (tickers, venues) as dictionary codes
+    ///
+    /// Each row holds an index into `dict`, which has no duplicates; build
+    /// with `SymBuilder`. Missing values represented as NULL_SYM (u32::MAX).
+    /// Row ops copy codes and share the dictionary.
+    Sym {
+        codes: Vec<u32>,
+        dict: Arc<Vec<String>>,
+    },
 }
 
 impl Column {
@@ -77,12 +153,58 @@ impl Column {
         Column::Ts(data)
     }
 
+    /// Create Bool column (predicate/mask)
+    pub fn new_bool(data: Vec<bool>) -> Self {
+        Column::Bool(data)
+    }
+
+    /// Create I64 column with embedded NULL_I64 for missing values (kdb-style)
+    pub fn new_i64(data: Vec<i64>) -> Self {
+        Column::I64(data)
+    }
+
+    /// Create F32 column with embedded NaN for missing values (kdb-style)
+    pub fn new_f32(data: Vec<f32>) -> Self {
+        Column::F32(data)
+    }
+
+    /// Build an F64 column from optional values (`None` → NaN)
+    pub fn from_f64_iter(iter: impl Iterator<Item = Option<f64>>) -> Self {
+        Column::F64(iter.map(|x| x.unwrap_or(f64::NAN)).collect())
+    }
+
+    /// Build an F32 column from optional values (`None` → NaN)
+    pub fn from_f32_iter(iter: impl Iterator<Item = Option<f32>>) -> Self {
+        Column::F32(iter.map(|x| x.unwrap_or(f32::NAN)).collect())
+    }
+
+    /// Build a Date column from optional day counts (`None` → NULL_DATE)
+    pub fn from_date_iter(iter: impl Iterator<Item = Option<i32>>) -> Self {
+        Column::Date(iter.map(|x| x.unwrap_or(NULL_DATE)).collect())
+    }
+
+    /// Build a Timestamp column from optional nanoseconds (`None` → NULL_TIMESTAMP)
+    pub fn from_timestamp_iter(iter: impl Iterator<Item = Option<i64>>) -> Self {
+        Column::Timestamp(iter.map(|x| x.unwrap_or(NULL_TIMESTAMP)).collect())
+    }
+
+    /// Build an I64 column from optional values (`None` → NULL_I64)
+    pub fn from_i64_iter(iter: impl Iterator<Item = Option<i64>>) -> Self {
+        Column::I64(iter.map(|x| x.unwrap_or(NULL_I64)).collect())
+    }
+
     pub fn len(&self) -> usize {
         match self {
             Column::F64(data) => data.len(),
             Column::Date(data) => data.len(),
             Column::Timestamp(data) => data.len(),
             Column::Ts(data) => data.len(),
+            Column::Bool(data) => data.len(),
+            Column::I64(data) => data.len(),
+            Column::F32(data) => data.len(),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => m.len(),
+            Column::Sym { codes, .. } => codes.len(),
         }
     }
 
@@ -90,22 +212,59 @@ impl Column {
         self.len() == 0
     }
 
-    /// Get data slice (F64) - kdb-style direct access
+    /// Type tag for error messages ("F64", "Date", "Timestamp", "Ts", "Bool", "I64", "F32",
+    /// "F64Mmap", "Sym")
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Column::F64(_) => "F64",
+            Column::Date(_) => "Date",
+            Column::Timestamp(_) => "Timestamp",
+            Column::Ts(_) => "Ts",
+            Column::Bool(_) => "Bool",
+            Column::I64(_) => "I64",
+            Column::F32(_) => "F32",
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(_) => "F64Mmap",
+            Column::Sym { .. } => "Sym",
+        }
+    }
+
+    /// Get data slice (F64, or the mapped slice of F64Mmap) - kdb-style direct access
     pub fn f64_data(&self) -> &[f64] {
         match self {
             Column::F64(data) => data,
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => m.as_slice(),
             _ => panic!("Not an F64 column"),
         }
     }
 
     /// Get mutable data slice (F64)
+    ///
+    /// # Panics
+    /// Panics for non-F64 columns, including read-only F64Mmap.
     pub fn f64_data_mut(&mut self) -> &mut [f64] {
         match self {
             Column::F64(data) => data,
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(_) => panic!("F64Mmap column is read-only"),
             _ => panic!("Not an F64 column"),
         }
     }
 
+    /// Get mutable data slice (F64), returning an error instead of panicking
+    ///
+    /// Read-only F64Mmap storage is an error (`expected mutable F64 column,
+    /// got F64Mmap`), like any other non-F64 column.
+    pub fn try_f64_data_mut(&mut self) -> Result<&mut [f64], ColumnTypeError> {
+        match self {
+            Column::F64(data) => Ok(data),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(_) => Err(ColumnTypeError::new("mutable F64", "F64Mmap")),
+            other => Err(ColumnTypeError::new("F64", other.type_name())),
+        }
+    }
+
     /// Get data slice (Date) - kdb-style direct access
     pub fn date_data(&self) -> &[i32] {
         match self {
@@ -154,6 +313,54 @@ impl Column {
         }
     }
 
+    /// Get data slice (Bool) - direct access
+    pub fn bool_data(&self) -> &[bool] {
+        match self {
+            Column::Bool(data) => data,
+            _ => panic!("Not a Bool column"),
+        }
+    }
+
+    /// Get mutable data slice (Bool)
+    pub fn bool_data_mut(&mut self) -> &mut [bool] {
+        match self {
+            Column::Bool(data) => data,
+            _ => panic!("Not a Bool column"),
+        }
+    }
+
+    /// Get data slice (I64) - kdb-style direct access
+    pub fn i64_data(&self) -> &[i64] {
+        match self {
+            Column::I64(data) => data,
+            _ => panic!("Not an I64 column"),
+        }
+    }
+
+    /// Get mutable data slice (I64)
+    pub fn i64_data_mut(&mut self) -> &mut [i64] {
+        match self {
+            Column::I64(data) => data,
+            _ => panic!("Not an I64 column"),
+        }
+    }
+
+    /// Get data slice (F32) - kdb-style direct access
+    pub fn f32_data(&self) -> &[f32] {
+        match self {
+            Column::F32(data) => data,
+            _ => panic!("Not an F32 column"),
+        }
+    }
+
+    /// Get mutable data slice (F32)
+    pub fn f32_data_mut(&mut self) -> &mut [f32] {
+        match self {
+            Column::F32(data) => data,
+            _ => panic!("Not an F32 column"),
+        }
+    }
+
     /// Get raw F64 slice for monomorphic kernels (zero-cost)
     ///
     /// Returns error instead of panic for better error handling.
@@ -162,6 +369,8 @@ impl Column {
     pub fn as_f64_slice(&self) -> Result<&[f64], &'static str> {
         match self {
             Column::F64(data) => Ok(data),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => Ok(m.as_slice()),
             _ => Err("Expected F64 column"),
         }
     }
@@ -193,6 +402,33 @@ impl Column {
         }
     }
 
+    /// Get raw Bool slice for monomorphic kernels (zero-cost)
+    #[inline(always)]
+    pub fn as_bool_slice(&self) -> Result<&[bool], &'static str> {
+        match self {
+            Column::Bool(data) => Ok(data),
+            _ => Err("Expected Bool column"),
+        }
+    }
+
+    /// Get raw I64 slice for monomorphic kernels (zero-cost)
+    #[inline(always)]
+    pub fn as_i64_slice(&self) -> Result<&[i64], &'static str> {
+        match self {
+            Column::I64(data) => Ok(data),
+            _ => Err("Expected I64 column"),
+        }
+    }
+
+    /// Get raw F32 slice for monomorphic kernels (zero-cost)
+    #[inline(always)]
+    pub fn as_f32_slice(&self) -> Result<&[f32], &'static str> {
+        match self {
+            Column::F32(data) => Ok(data),
+            _ => Err("Expected F32 column"),
+        }
+    }
+
     /// Create F64 column from raw vector (for kernel output) - kdb-style
     #[inline(always)]
     pub fn from_f64_vec(data: Vec<f64>) -> Self {
@@ -217,19 +453,344 @@ impl Column {
         Column::Ts(data)
     }
 
+    /// Create Bool column from raw vector (for kernel output)
+    #[inline(always)]
+    pub fn from_bool_vec(data: Vec<bool>) -> Self {
+        Column::Bool(data)
+    }
+
+    /// Create I64 column from raw vector (for kernel output) - kdb-style
+    #[inline(always)]
+    pub fn from_i64_vec(data: Vec<i64>) -> Self {
+        Column::I64(data)
+    }
+
+    /// Create F32 column from raw vector (for kernel output) - kdb-style
+    #[inline(always)]
+    pub fn from_f32_vec(data: Vec<f32>) -> Self {
+        Column::F32(data)
+    }
+
+    /// Map an F64 column through `f`
+    ///
+    /// Null positions are passed to `f` as NaN and whatever `f` returns is
+    /// stored verbatim. Errors on non-F64 columns.
+    pub fn apply_f64(&self, f: impl Fn(f64) -> f64) -> Result<Column, &'static str> {
+        let data = self.as_f64_slice()?;
+        let mut out = vec![0.0; data.len()];
+        unary_no_nulls(&mut out, data, f);
+        Ok(Column::F64(out))
+    }
+
+    /// Keep elements where `mask` is true (same type, sentinels preserved)
+    ///
+    /// # Panics
+    /// Panics if `mask.len() != self.len()`.
+    pub fn filter(&self, mask: &[bool]) -> Column {
+        assert_eq!(mask.len(), self.len(), "filter: mask length mismatch");
+
+        fn keep<T: Copy>(data: &[T], mask: &[bool]) -> Vec<T> {
+            data.iter()
+                .zip(mask)
+                .filter_map(|(&v, &m)| m.then_some(v))
+                .collect()
+        }
+
+        match self {
+            Column::F64(data) => Column::F64(keep(data, mask)),
+            Column::Date(data) => Column::Date(keep(data, mask)),
+            Column::Timestamp(data) => Column::Timestamp(keep(data, mask)),
+            Column::Ts(data) => Column::Ts(keep(data, mask)),
+            Column::Bool(data) => Column::Bool(keep(data, mask)),
+            Column::I64(data) => Column::I64(keep(data, mask)),
+            Column::F32(data) => Column::F32(keep(data, mask)),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => Column::F64(keep(m.as_slice(), mask)),
+            Column::Sym { codes, dict } => Column::Sym {
+                codes: keep(codes, mask),
+                dict: Arc::clone(dict),
+            },
+        }
+    }
+
+    /// Gather elements at `indices` (same type, sentinels preserved)
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    pub fn take(&self, indices: &[usize]) -> Column {
+        fn gather<T: Copy>(data: &[T], indices: &[usize]) -> Vec<T> {
+            indices.iter().map(|&i| data[i]).collect()
+        }
+
+        match self {
+            Column::F64(data) => Column::F64(gather(data, indices)),
+            Column::Date(data) => Column::Date(gather(data, indices)),
+            Column::Timestamp(data) => Column::Timestamp(gather(data, indices)),
+            Column::Ts(data) => Column::Ts(gather(data, indices)),
+            Column::Bool(data) => Column::Bool(gather(data, indices)),
+            Column::I64(data) => Column::I64(gather(data, indices)),
+            Column::F32(data) => Column::F32(gather(data, indices)),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => Column::F64(gather(m.as_slice(), indices)),
+            Column::Sym { codes, dict } => Column::Sym {
+                codes: gather(codes, indices),
+                dict: Arc::clone(dict),
+            },
+        }
+    }
+
+    /// Copy of rows [start, end) (same type, sentinels preserved)
+    ///
+    /// Clamping rule: `end` is clamped to `len()`, then `start` to `end`, so
+    /// out-of-range bounds give a shorter (possibly empty) column, never a
+    /// panic.
+    pub fn slice(&self, start: usize, end: usize) -> Column {
+        let end = end.min(self.len());
+        let start = start.min(end);
+
+        match self {
+            Column::F64(data) => Column::F64(data[start..end].to_vec()),
+            Column::Date(data) => Column::Date(data[start..end].to_vec()),
+            Column::Timestamp(data) => Column::Timestamp(data[start..end].to_vec()),
+            Column::Ts(data) => Column::Ts(data[start..end].to_vec()),
+            Column::Bool(data) => Column::Bool(data[start..end].to_vec()),
+            Column::I64(data) => Column::I64(data[start..end].to_vec()),
+            Column::F32(data) => Column::F32(data[start..end].to_vec()),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => Column::F64(m.as_slice()[start..end].to_vec()),
+            Column::Sym { codes, dict } => Column::Sym {
+                codes: codes[start..end].to_vec(),
+                dict: Arc::clone(dict),
+            },
+        }
+    }
+
+    /// Append `other`'s values to this column
+    ///
+    /// A read-only F64Mmap column is first copied into an owned F64. Sym
+    /// columns with different dictionaries are re-coded into a merged one.
+    ///
+    /// # Panics
+    /// Panics if the two columns have different variants.
+    pub fn extend_from(&mut self, other: &Column) {
+        #[cfg(feature = "mmap")]
+        if let Column::F64Mmap(m) = self {
+            *self = Column::F64(m.as_slice().to_vec());
+        }
+
+        match (self, other) {
+            (Column::F64(a), Column::F64(b)) => a.extend_from_slice(b),
+            #[cfg(feature = "mmap")]
+            (Column::F64(a), Column::F64Mmap(b)) => a.extend_from_slice(b.as_slice()),
+            (Column::Date(a), Column::Date(b)) => a.extend_from_slice(b),
+            (Column::Timestamp(a), Column::Timestamp(b)) => a.extend_from_slice(b),
+            (Column::Ts(a), Column::Ts(b)) => a.extend_from_slice(b),
+            (Column::Bool(a), Column::Bool(b)) => a.extend_from_slice(b),
+            (Column::I64(a), Column::I64(b)) => a.extend_from_slice(b),
+            (Column::F32(a), Column::F32(b)) => a.extend_from_slice(b),
+            (
+                Column::Sym { codes, dict },
+                Column::Sym {
+                    codes: other_codes,
+                    dict: other_dict,
+                },
+            ) => super::sym::extend_sym(codes, dict, other_codes, other_dict),
+            (a, b) => panic!(
+                "extend_from: cannot append {} to {}",
+                b.type_name(),
+                a.type_name()
+            ),
+        }
+    }
+
+    /// Element-wise equality with tolerance for float columns
+    ///
+    /// F64/F32 values match if both are NaN, equal, or within `epsilon`
+    /// (inclusive). Integer, temporal and Bool columns compare exactly; Sym
+    /// columns compare by string, whatever their codes.
+    /// Different variants or lengths never match, except that F64Mmap
+    /// compares as F64.
+    pub fn approx_eq(&self, other: &Column, epsilon: f64) -> bool {
+        let close =
+            |a: f64, b: f64| (a.is_nan() && b.is_nan()) || a == b || (a - b).abs() <= epsilon;
+
+        #[cfg(feature = "mmap")]
+        if let (Ok(a), Ok(b)) = (self.as_f64_slice(), other.as_f64_slice()) {
+            return a.len() == b.len() && a.iter().zip(b).all(|(&x, &y)| close(x, y));
+        }
+
+        match (self, other) {
+            (Column::F64(a), Column::F64(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(&x, &y)| close(x, y))
+            }
+            (Column::F32(a), Column::F32(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(&x, &y)| close(x as f64, y as f64))
+            }
+            (Column::Date(a), Column::Date(b)) => a == b,
+            (Column::Timestamp(a), Column::Timestamp(b)) => a == b,
+            (Column::Ts(a), Column::Ts(b)) => a == b,
+            (Column::Bool(a), Column::Bool(b)) => a == b,
+            (Column::I64(a), Column::I64(b)) => a == b,
+            (Column::Sym { codes: a, .. }, Column::Sym { codes: b, .. }) => {
+                a.len() == b.len() && (0..a.len()).all(|i| self.sym_str(i) == other.sym_str(i))
+            }
+            _ => false,
+        }
+    }
+
     /// Check if column contains any null values
     ///
-    /// Checks for type-specific null sentinels.
+    /// Checks for type-specific null sentinels. Bool columns never have nulls.
     pub fn has_nulls(&self) -> bool {
         match self {
             Column::F64(data) => data.iter().any(|x| x.is_nan()),
             Column::Date(data) => data.contains(&NULL_DATE),
             Column::Timestamp(data) => data.contains(&NULL_TIMESTAMP),
             Column::Ts(data) => data.contains(&NULL_TS),
+            Column::Bool(_) => false,
+            Column::I64(data) => data.contains(&NULL_I64),
+            Column::F32(data) => data.iter().any(|x| x.is_nan()),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => m.as_slice().iter().any(|x| x.is_nan()),
+            Column::Sym { codes, .. } => codes.contains(&NULL_SYM),
+        }
+    }
+
+    /// Check if element `i` is null (NaN or the type's sentinel)
+    ///
+    /// Bool columns never have nulls.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    pub fn is_null(&self, i: usize) -> bool {
+        match self {
+            Column::F64(data) => data[i].is_nan(),
+            Column::Date(data) => data[i] == NULL_DATE,
+            Column::Timestamp(data) => data[i] == NULL_TIMESTAMP,
+            Column::Ts(data) => data[i] == NULL_TS,
+            Column::Bool(data) => {
+                assert!(i < data.len(), "is_null: index {} out of bounds", i);
+                false
+            }
+            Column::I64(data) => data[i] == NULL_I64,
+            Column::F32(data) => data[i].is_nan(),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => m.as_slice()[i].is_nan(),
+            Column::Sym { codes, .. } => codes[i] == NULL_SYM,
+        }
+    }
+
+    /// Number of null elements (see `is_null`)
+    pub fn null_count(&self) -> usize {
+        match self {
+            Column::F64(data) => data.iter().filter(|x| x.is_nan()).count(),
+            Column::Date(data) => data.iter().filter(|&&x| x == NULL_DATE).count(),
+            Column::Timestamp(data) => data.iter().filter(|&&x| x == NULL_TIMESTAMP).count(),
+            Column::Ts(data) => data.iter().filter(|&&x| x == NULL_TS).count(),
+            Column::Bool(_) => 0,
+            Column::I64(data) => data.iter().filter(|&&x| x == NULL_I64).count(),
+            Column::F32(data) => data.iter().filter(|x| x.is_nan()).count(),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => m.as_slice().iter().filter(|x| x.is_nan()).count(),
+            Column::Sym { codes, .. } => codes.iter().filter(|&&c| c == NULL_SYM).count(),
+        }
+    }
+
+    /// True if the values are non-decreasing with any nulls as a trailing
+    /// block
+    ///
+    /// Nulls sit where `Table::sort_by(.., false)` puts them (last), so its
+    /// output always passes. Bool orders false < true; Sym compares strings.
+    /// Empty and single-row columns are sorted.
+    pub fn is_sorted_ascending(&self) -> bool {
+        match self {
+            Column::F64(data) => sorted_nulls_last(data, |x| x.is_nan()),
+            Column::Date(data) => sorted_nulls_last(data, |&x| x == NULL_DATE),
+            Column::Timestamp(data) => sorted_nulls_last(data, |&x| x == NULL_TIMESTAMP),
+            Column::Ts(data) => sorted_nulls_last(data, |&x| x == NULL_TS),
+            Column::Bool(data) => data.is_sorted(),
+            Column::I64(data) => sorted_nulls_last(data, |&x| x == NULL_I64),
+            Column::F32(data) => sorted_nulls_last(data, |x| x.is_nan()),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => sorted_nulls_last(m.as_slice(), |x| x.is_nan()),
+            Column::Sym { codes, dict } => {
+                let n = codes.iter().take_while(|&&c| c != NULL_SYM).count();
+                codes[n..].iter().all(|&c| c == NULL_SYM)
+                    && codes[..n]
+                        .windows(2)
+                        .all(|w| dict[w[0] as usize] <= dict[w[1] as usize])
+            }
+        }
+    }
+
+    /// Validity bitmap derived from the null sentinels (set bit = valid)
+    ///
+    /// Columns carry nulls only as sentinels, so a NaN here always reads as
+    /// null. To keep NaN as a genuine data value, build the `Bitmap`
+    /// yourself and pass it alongside the data to the masked kernels
+    /// (`kernels_masked`, `kernels_fused`, `rolling_quantile`).
+    ///
+    /// There is deliberately no stored `valid: Option<Bitmap>` on F64: every
+    /// kernel, the fused pipeline and the IO paths read `Column::F64(Vec<f64>)`
+    /// directly and treat NaN as missing, and a second source of truth would
+    /// have to be kept in sync by each of them. The cost is that a column
+    /// cannot say "this NaN is a real value, not a null" (e.g. the result of
+    /// `0.0 / 0.0` kept as data); that distinction only exists for callers
+    /// holding their own bitmap.
+    pub fn validity(&self) -> Bitmap {
+        let mut bm = Bitmap::new_all_valid(self.len());
+        let mut mark = |i: usize, valid: bool| {
+            if !valid {
+                bm.set(i, false);
+            }
+        };
+        match self {
+            Column::F64(data) => data
+                .iter()
+                .enumerate()
+                .for_each(|(i, x)| mark(i, !x.is_nan())),
+            Column::F32(data) => data
+                .iter()
+                .enumerate()
+                .for_each(|(i, x)| mark(i, !x.is_nan())),
+            Column::Date(data) => data
+                .iter()
+                .enumerate()
+                .for_each(|(i, &x)| mark(i, x != NULL_DATE)),
+            Column::Timestamp(data) => data
+                .iter()
+                .enumerate()
+                .for_each(|(i, &x)| mark(i, x != NULL_TIMESTAMP)),
+            Column::Ts(data) => data
+                .iter()
+                .enumerate()
+                .for_each(|(i, &x)| mark(i, x != NULL_TS)),
+            Column::I64(data) => data
+                .iter()
+                .enumerate()
+                .for_each(|(i, &x)| mark(i, x != NULL_I64)),
+            Column::Bool(_) => {}
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => m
+                .as_slice()
+                .iter()
+                .enumerate()
+                .for_each(|(i, x)| mark(i, !x.is_nan())),
+            Column::Sym { codes, .. } => codes
+                .iter()
+                .enumerate()
+                .for_each(|(i, &c)| mark(i, c != NULL_SYM)),
         }
+        bm
     }
 }
 
+/// Non-decreasing valid prefix followed only by nulls
+fn sorted_nulls_last<T: PartialOrd>(data: &[T], is_null: impl Fn(&T) -> bool) -> bool {
+    let n = data.iter().take_while(|x| !is_null(x)).count();
+    data[n..].iter().all(is_null) && data[..n].is_sorted()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,6 +802,90 @@ mod tests {
         assert!(!col.has_nulls());
     }
 
+    #[test]
+    fn test_from_option_iters() {
+        let col = Column::from_f64_iter([Some(1.5), None, Some(-2.0)].into_iter());
+        assert!(col.has_nulls());
+        assert_eq!(col.f64_data()[0], 1.5);
+        assert!(col.f64_data()[1].is_nan());
+        assert_eq!(col.f64_data()[2], -2.0);
+
+        let col = Column::from_date_iter([None, Some(18628)].into_iter());
+        assert_eq!(col.date_data(), &[NULL_DATE, 18628]);
+
+        let col = Column::from_timestamp_iter([Some(7), None].into_iter());
+        assert_eq!(col.timestamp_data(), &[7, NULL_TIMESTAMP]);
+
+        let col = Column::from_i64_iter((0..4).map(|i| (i % 2 == 0).then_some(i)));
+        assert_eq!(col.i64_data(), &[0, NULL_I64, 2, NULL_I64]);
+
+        let col = Column::from_f32_iter([Some(1.0f32)].into_iter());
+        assert!(!col.has_nulls());
+        assert!(!Column::from_f64_iter(std::iter::empty()).has_nulls());
+    }
+
+    #[test]
+    fn test_has_nulls_vs_explicit_validity() {
+        use crate::builtins::kernels_masked::unary_masked;
+
+        // Sentinel view: the NaN is a null
+        let col = Column::new_f64(vec![1.0, f64::NAN, 3.0]);
+        assert!(col.has_nulls());
+        let derived = col.validity();
+        assert_eq!(derived.iter_null().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(derived.count_valid(), 2);
+
+        // Explicit mask: the same NaN is a valid data value, row 2 is null
+        let mut explicit = Bitmap::new_all_valid(3);
+        explicit.set(2, false);
+        let mut out = vec![0.0; 3];
+        let mut out_valid = Bitmap::new_all_null(3);
+        unary_masked(&mut out, &mut out_valid, col.f64_data(), &explicit, |x| {
+            x + 1.0
+        });
+        assert_eq!(out_valid.iter_valid().collect::<Vec<_>>(), vec![0, 1]);
+        assert!(out[1].is_nan());
+
+        let dates = Column::new_date(vec![NULL_DATE, 5]);
+        assert!(dates.has_nulls());
+        assert_eq!(dates.validity().iter_valid().collect::<Vec<_>>(), vec![1]);
+        assert!(Column::new_bool(vec![false]).validity().all_valid());
+    }
+
+    #[test]
+    fn test_approx_eq_nan_and_epsilon() {
+        let a = Column::new_f64(vec![1.0, f64::NAN, 3.0]);
+        assert!(a.approx_eq(&Column::new_f64(vec![1.0, f64::NAN, 3.0]), 0.0));
+        assert!(!a.approx_eq(&Column::new_f64(vec![1.0, 2.0, 3.0]), 1.0));
+        assert!(!a.approx_eq(&Column::new_f64(vec![1.0, f64::NAN]), 1.0));
+
+        // Boundary is inclusive
+        let b = Column::new_f64(vec![1.0, f64::NAN, 3.25]);
+        assert!(a.approx_eq(&b, 0.25));
+        assert!(!a.approx_eq(&b, 0.125));
+
+        let inf = Column::new_f64(vec![f64::INFINITY]);
+        assert!(inf.approx_eq(&inf, 0.0));
+    }
+
+    #[test]
+    fn test_approx_eq_exact_types() {
+        let d = Column::new_date(vec![1, NULL_DATE]);
+        assert!(d.approx_eq(&Column::new_date(vec![1, NULL_DATE]), 0.0));
+        assert!(!d.approx_eq(&Column::new_date(vec![2, NULL_DATE]), 10.0));
+        assert!(!d.approx_eq(&Column::new_i64(vec![1, NULL_DATE as i64]), 0.0));
+        assert!(!Column::new_f64(vec![1.0]).approx_eq(&Column::new_f32(vec![1.0]), 0.0));
+    }
+
+    #[test]
+    fn test_slice_clamps() {
+        let col = Column::new_i64(vec![1, 2, 3, 4]);
+        assert_eq!(col.slice(1, 3).i64_data(), &[2, 3]);
+        assert_eq!(col.slice(2, 100).i64_data(), &[3, 4]);
+        assert!(col.slice(3, 1).is_empty());
+        assert!(col.slice(10, 20).is_empty());
+    }
+
     #[test]
     fn test_has_nulls() {
         // Column without nulls
@@ -262,5 +907,126 @@ mod tests {
         // Ts column with NULL_TS
         let col_ts_old = Column::Ts(vec![100, NULL_TS, 300]);
         assert!(col_ts_old.has_nulls());
+
+        // Bool column never has nulls
+        let col_bool = Column::Bool(vec![true, false, true]);
+        assert!(!col_bool.has_nulls());
+
+        // I64 column with NULL_I64
+        let col_i64 = Column::I64(vec![1, NULL_I64, 3]);
+        assert!(col_i64.has_nulls());
+        assert_eq!(col_i64.type_name(), "I64");
+    }
+
+    #[test]
+    fn test_is_null_and_null_count() {
+        let cols = [
+            Column::F64(vec![1.0, f64::NAN, 3.0]),
+            Column::F32(vec![1.0, f32::NAN, 3.0]),
+            Column::Date(vec![100, NULL_DATE, 300]),
+            Column::Timestamp(vec![100, NULL_TIMESTAMP, 300]),
+            Column::Ts(vec![100, NULL_TS, 300]),
+            Column::I64(vec![0, NULL_I64, -1]),
+        ];
+        for col in &cols {
+            let nulls: Vec<bool> = (0..col.len()).map(|i| col.is_null(i)).collect();
+            assert_eq!(nulls, vec![false, true, false], "{}", col.type_name());
+            assert_eq!(col.null_count(), 1, "{}", col.type_name());
+            assert_eq!(col.null_count(), col.len() - col.validity().count_valid());
+        }
+
+        let col_bool = Column::Bool(vec![true, false]);
+        assert!(!col_bool.is_null(0) && !col_bool.is_null(1));
+        assert_eq!(col_bool.null_count(), 0);
+
+        // Zero is a value, not a null
+        assert!(!Column::F64(vec![0.0]).is_null(0));
+        assert!(!Column::Date(vec![0]).is_null(0));
+        assert_eq!(Column::F64(vec![f64::NAN; 4]).null_count(), 4);
+    }
+
+    #[test]
+    fn test_is_sorted_ascending() {
+        assert!(Column::Date(vec![1, 2, 2, 5]).is_sorted_ascending());
+        assert!(Column::F64(vec![-1.0, 0.0, 0.5]).is_sorted_ascending());
+        assert!(Column::Bool(vec![false, true, true]).is_sorted_ascending());
+        assert!(Column::Date(vec![]).is_sorted_ascending());
+        assert!(Column::I64(vec![7]).is_sorted_ascending());
+
+        assert!(!Column::Date(vec![1, 3, 2]).is_sorted_ascending());
+        assert!(!Column::Timestamp(vec![5, 4]).is_sorted_ascending());
+        assert!(!Column::Bool(vec![true, false]).is_sorted_ascending());
+    }
+
+    #[test]
+    fn test_is_sorted_ascending_nulls_last() {
+        // Trailing nulls are allowed, anywhere else they break the order
+        assert!(Column::Date(vec![1, 2, NULL_DATE, NULL_DATE]).is_sorted_ascending());
+        assert!(Column::Timestamp(vec![NULL_TIMESTAMP]).is_sorted_ascending());
+        assert!(Column::F64(vec![1.0, 2.0, f64::NAN]).is_sorted_ascending());
+        assert!(!Column::Date(vec![NULL_DATE, 1, 2]).is_sorted_ascending());
+        assert!(!Column::F64(vec![1.0, f64::NAN, 2.0]).is_sorted_ascending());
+        assert!(!Column::I64(vec![1, NULL_I64, 2]).is_sorted_ascending());
+
+        let sym = Column::Sym {
+            codes: vec![1, 0, NULL_SYM],
+            dict: Arc::new(vec!["b".to_string(), "a".to_string()]),
+        };
+        assert!(sym.is_sorted_ascending());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_is_null_out_of_bounds() {
+        Column::Bool(vec![true]).is_null(1);
+    }
+
+    #[test]
+    fn test_column_type_error_display() {
+        let err = ColumnTypeError::new("F64", Column::Date(vec![]).type_name());
+        assert_eq!(err.actual, "Date");
+        assert_eq!(err.to_string(), "expected F64 column, got Date");
+    }
+
+    #[test]
+    fn test_try_f64_data_mut() {
+        let mut col = Column::F64(vec![1.0, 2.0]);
+        col.try_f64_data_mut().unwrap()[1] = 5.0;
+        assert_eq!(col.f64_data(), &[1.0, 5.0]);
+
+        let err = Column::Date(vec![1]).try_f64_data_mut().unwrap_err();
+        assert_eq!(err, ColumnTypeError::new("F64", "Date"));
+    }
+
+    #[test]
+    fn test_bool_column() {
+        let col = Column::new_bool(vec![true, false, true]);
+        assert_eq!(col.len(), 3);
+        assert!(!col.is_empty());
+        assert_eq!(col.type_name(), "Bool");
+        assert_eq!(col.bool_data(), &[true, false, true]);
+        assert_eq!(col.as_bool_slice().unwrap(), &[true, false, true]);
+        assert!(col.as_f64_slice().is_err());
+
+        let empty = Column::from_bool_vec(vec![]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_apply_f64_square() {
+        let col = Column::new_f64(vec![1.0, -2.0, f64::NAN]);
+        let out = col.apply_f64(|x| x * x).unwrap();
+        assert_eq!(&out.f64_data()[..2], &[1.0, 4.0]);
+        assert!(out.f64_data()[2].is_nan());
+
+        // Output is stored verbatim, even for null inputs
+        let filled = col.apply_f64(|x| if x.is_nan() { 0.0 } else { x }).unwrap();
+        assert_eq!(filled.f64_data(), &[1.0, -2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_apply_f64_rejects_non_f64() {
+        let err = Column::new_date(vec![1]).apply_f64(|x| x).unwrap_err();
+        assert_eq!(err, "Expected F64 column");
     }
 }
@@ -0,0 +1,288 @@
+//! Group-by aggregation over a key column
+
+use super::{Column, Table};
+use crate::builtins::ops::{max0, mean0, min0, sum0};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Aggregation applied to each group of an F64 column
+///
+/// All aggregations skip NaN (the `0`-style builtins: `sum0`, `mean0`,
+/// `min0`, `max0`). `Count` counts non-NaN values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AggKind {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Count,
+}
+
+impl AggKind {
+    /// Lowercase name, used as the output column suffix
+    pub fn name(self) -> &'static str {
+        match self {
+            AggKind::Sum => "sum",
+            AggKind::Mean => "mean",
+            AggKind::Min => "min",
+            AggKind::Max => "max",
+            AggKind::Count => "count",
+        }
+    }
+
+    fn apply(self, values: &Column) -> f64 {
+        match self {
+            AggKind::Sum => sum0(values),
+            AggKind::Mean => mean0(values),
+            AggKind::Min => min0(values),
+            AggKind::Max => max0(values),
+            AggKind::Count => values.f64_data().iter().filter(|x| !x.is_nan()).count() as f64,
+        }
+    }
+}
+
+impl Table {
+    /// Aggregate F64 columns by distinct values of `key_col`
+    ///
+    /// The key column must be Date, I64, Timestamp, Ts or Sym; its null
+    /// sentinel forms a group of its own. Sym keys group by code and the
+    /// output key column shares the input dictionary. Groups appear in
+    /// first-seen order. The result has the key column followed by one F64
+    /// column per `(name, agg)` pair, named `"{name}_{agg}"` (e.g.
+    /// `"px_mean"`).
+    pub fn group_by(&self, key_col: &str, agg: &[(&str, AggKind)]) -> Result<Table, String> {
+        let key = self
+            .column(key_col)
+            .ok_or_else(|| format!("group_by: column '{}' not found", key_col))?;
+
+        let (groups, key_out) = match key {
+            Column::Date(data) => {
+                let (groups, first) = group_indices(data);
+                (groups, Column::Date(first))
+            }
+            Column::I64(data) => {
+                let (groups, first) = group_indices(data);
+                (groups, Column::I64(first))
+            }
+            Column::Timestamp(data) => {
+                let (groups, first) = group_indices(data);
+                (groups, Column::Timestamp(first))
+            }
+            Column::Ts(data) => {
+                let (groups, first) = group_indices(data);
+                (groups, Column::Ts(first))
+            }
+            Column::Sym { codes, dict } => {
+                let (groups, first) = group_indices(codes);
+                (
+                    groups,
+                    Column::Sym {
+                        codes: first,
+                        dict: Arc::clone(dict),
+                    },
+                )
+            }
+            Column::F64(_) | Column::F32(_) | Column::Bool(_) => {
+                return Err(format!(
+                    "group_by: key column '{}' must be Date, I64, Timestamp or Sym, got {}",
+                    key_col,
+                    key.type_name()
+                ))
+            }
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(_) => {
+                return Err(format!(
+                    "group_by: key column '{}' must be Date, I64, Timestamp or Sym, got {}",
+                    key_col,
+                    key.type_name()
+                ))
+            }
+        };
+
+        let mut names = Vec::with_capacity(agg.len() + 1);
+        let mut columns = Vec::with_capacity(agg.len() + 1);
+        names.push(key_col.to_string());
+        columns.push(key_out);
+
+        for &(name, kind) in agg {
+            let col = self
+                .column(name)
+                .ok_or_else(|| format!("group_by: column '{}' not found", name))?;
+            let data = col.as_f64_slice().map_err(|_| {
+                format!(
+                    "group_by: column '{}' must be F64, got {}",
+                    name,
+                    col.type_name()
+                )
+            })?;
+
+            let out = groups
+                .iter()
+                .map(|rows| {
+                    let values = Column::F64(rows.iter().map(|&i| data[i]).collect());
+                    kind.apply(&values)
+                })
+                .collect();
+
+            names.push(format!("{}_{}", name, kind.name()));
+            columns.push(Column::F64(out));
+        }
+
+        Ok(Table::new(names, columns))
+    }
+}
+
+/// Row indices per distinct key (first-seen order) and the distinct keys
+pub(super) fn group_indices<K: Copy + Eq + std::hash::Hash>(
+    keys: &[K],
+) -> (Vec<Vec<usize>>, Vec<K>) {
+    let mut slot: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut distinct = Vec::new();
+
+    for (i, &k) in keys.iter().enumerate() {
+        let g = *slot.entry(k).or_insert_with(|| {
+            groups.push(Vec::new());
+            distinct.push(k);
+            groups.len() - 1
+        });
+        groups[g].push(i);
+    }
+
+    (groups, distinct)
+}
+
+/// `group_indices` for keys already sorted ascending: each run of equal
+/// keys is one group, found without hashing
+pub(super) fn run_indices<K: Copy + Eq>(keys: &[K]) -> (Vec<Vec<usize>>, Vec<K>) {
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut distinct = Vec::new();
+
+    for (i, &k) in keys.iter().enumerate() {
+        if distinct.last() != Some(&k) {
+            groups.push(Vec::new());
+            distinct.push(k);
+        }
+        groups.last_mut().unwrap().push(i);
+    }
+
+    (groups, distinct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{SymBuilder, NULL_I64, NULL_SYM};
+
+    fn trades() -> Table {
+        Table::new(
+            vec!["sym".to_string(), "px".to_string(), "qty".to_string()],
+            vec![
+                Column::I64(vec![2, 1, 2, NULL_I64, 1, 2]),
+                Column::F64(vec![10.0, 5.0, f64::NAN, 7.0, 6.0, 14.0]),
+                Column::F64(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_group_by_duplicate_keys_first_seen_order() {
+        let out = trades()
+            .group_by(
+                "sym",
+                &[
+                    ("px", AggKind::Sum),
+                    ("px", AggKind::Mean),
+                    ("qty", AggKind::Count),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(out.names, vec!["sym", "px_sum", "px_mean", "qty_count"]);
+        assert_eq!(out.columns[0].i64_data(), &[2, 1, NULL_I64]);
+        assert_eq!(out.columns[1].f64_data(), &[24.0, 11.0, 7.0]);
+        assert_eq!(out.columns[2].f64_data(), &[12.0, 5.5, 7.0]);
+        assert_eq!(out.columns[3].f64_data(), &[3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_group_by_nan_values() {
+        let t = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![100, 101, 100, 101]),
+                Column::F64(vec![f64::NAN, f64::NAN, 3.0, f64::NAN]),
+            ],
+        );
+        let out = t
+            .group_by(
+                "d",
+                &[
+                    ("x", AggKind::Min),
+                    ("x", AggKind::Max),
+                    ("x", AggKind::Count),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(out.columns[0].date_data(), &[100, 101]);
+        assert_eq!(out.columns[1].f64_data()[0], 3.0);
+        assert!(out.columns[1].f64_data()[1].is_nan());
+        assert_eq!(out.columns[2].f64_data()[0], 3.0);
+        assert!(out.columns[2].f64_data()[1].is_nan());
+        assert_eq!(out.columns[3].f64_data(), &[1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_group_by_errors() {
+        let t = trades();
+        let err = t.group_by("nope", &[]).unwrap_err();
+        assert_eq!(err, "group_by: column 'nope' not found");
+
+        let err = t.group_by("sym", &[("missing", AggKind::Sum)]).unwrap_err();
+        assert_eq!(err, "group_by: column 'missing' not found");
+
+        assert!(t.group_by("px", &[]).is_err());
+    }
+
+    #[test]
+    fn test_group_by_sym_ticker() {
+        let ticker = [
+            Some("MSFT"),
+            Some("AAPL"),
+            Some("MSFT"),
+            None,
+            Some("AAPL"),
+            Some("MSFT"),
+        ]
+        .into_iter()
+        .collect::<SymBuilder>()
+        .finish();
+        let t = Table::new(
+            vec!["ticker".to_string(), "pnl".to_string()],
+            vec![
+                ticker.clone(),
+                Column::F64(vec![1.0, 2.0, f64::NAN, 4.0, f64::NAN, 3.0]),
+            ],
+        );
+
+        let out = t
+            .group_by("ticker", &[("pnl", AggKind::Sum), ("pnl", AggKind::Count)])
+            .unwrap();
+
+        let Column::Sym { codes, dict } = &out.columns[0] else {
+            panic!("expected Sym key column");
+        };
+        let Column::Sym {
+            dict: input_dict, ..
+        } = &ticker
+        else {
+            unreachable!()
+        };
+        assert!(Arc::ptr_eq(dict, input_dict));
+        assert_eq!(dict.as_slice(), &["MSFT", "AAPL"]);
+        assert_eq!(codes, &[0, 1, NULL_SYM]);
+        assert_eq!(out.columns[1].f64_data(), &[4.0, 2.0, 4.0]);
+        assert_eq!(out.columns[2].f64_data(), &[2.0, 1.0, 1.0]);
+    }
+}
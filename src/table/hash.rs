@@ -0,0 +1,142 @@
+//! Stable content hashing for tables
+
+use super::{Column, Table, NULL_SYM};
+
+/// FNV-1a, 64-bit: fixed constants, so hashes are stable across runs,
+/// platforms and compiler versions (unlike `DefaultHasher`)
+struct Fnv64(u64);
+
+impl Fnv64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Fnv64(Self::OFFSET)
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn u64(&mut self, x: u64) {
+        self.bytes(&x.to_le_bytes());
+    }
+}
+
+/// f64 bits with every NaN mapped to one pattern and -0.0 to 0.0
+fn canonical_f64_bits(x: f64) -> u64 {
+    if x.is_nan() {
+        f64::NAN.to_bits()
+    } else if x == 0.0 {
+        0
+    } else {
+        x.to_bits()
+    }
+}
+
+impl Table {
+    /// Deterministic hash of names, column types and values
+    ///
+    /// Floats hash by bit pattern after canonicalizing NaN payloads and the
+    /// sign of zero, so tables that are `approx_eq` with epsilon 0 hash
+    /// equally. Column order matters. Intended for snapshot tests, not as a
+    /// cryptographic digest.
+    pub fn content_hash(&self) -> u64 {
+        let mut h = Fnv64::new();
+        h.u64(self.columns.len() as u64);
+
+        for (name, col) in self.names.iter().zip(&self.columns) {
+            h.u64(name.len() as u64);
+            h.bytes(name.as_bytes());
+            h.bytes(col.type_name().as_bytes());
+            h.u64(col.len() as u64);
+
+            match col {
+                Column::F64(data) => data.iter().for_each(|&x| h.u64(canonical_f64_bits(x))),
+                Column::F32(data) => data
+                    .iter()
+                    .for_each(|&x| h.u64(canonical_f64_bits(x as f64))),
+                Column::Date(data) => data.iter().for_each(|&x| h.bytes(&x.to_le_bytes())),
+                Column::Timestamp(data) | Column::Ts(data) | Column::I64(data) => {
+                    data.iter().for_each(|&x| h.bytes(&x.to_le_bytes()))
+                }
+                Column::Bool(data) => data.iter().for_each(|&x| h.bytes(&[x as u8])),
+                // By string, so equal symbols hash alike across dictionaries
+                Column::Sym { codes, dict } => codes.iter().for_each(|&c| match c {
+                    NULL_SYM => h.u64(u64::MAX),
+                    c => {
+                        let s = &dict[c as usize];
+                        h.u64(s.len() as u64);
+                        h.bytes(s.as_bytes());
+                    }
+                }),
+                #[cfg(feature = "mmap")]
+                Column::F64Mmap(m) => m
+                    .as_slice()
+                    .iter()
+                    .for_each(|&x| h.u64(canonical_f64_bits(x))),
+            }
+        }
+
+        h.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::NULL_DATE;
+
+    fn sample() -> Table {
+        Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![18628, NULL_DATE]),
+                Column::F64(vec![1.5, f64::NAN]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_identical_tables_hash_equal() {
+        assert_eq!(sample().content_hash(), sample().content_hash());
+
+        // Different NaN payload and zero sign still match
+        let a = Table::new(
+            vec!["x".to_string()],
+            vec![Column::F64(vec![0.0, f64::NAN])],
+        );
+        let b = Table::new(
+            vec!["x".to_string()],
+            vec![Column::F64(vec![
+                -0.0,
+                f64::from_bits(0x7ff8_0000_0000_0001),
+            ])],
+        );
+        assert!(a.approx_eq(&b, 0.0));
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn test_hash_detects_changes() {
+        let t = sample();
+        let reordered = t.select(&["x", "d"]).unwrap();
+        assert_ne!(t.content_hash(), reordered.content_hash());
+
+        let mut mutated = t.clone();
+        mutated.columns[1].f64_data_mut()[0] = 1.5000000000000002;
+        assert_ne!(t.content_hash(), mutated.content_hash());
+
+        let mut renamed = t.clone();
+        renamed.names[1] = "y".to_string();
+        assert_ne!(t.content_hash(), renamed.content_hash());
+
+        // Same bits, different type
+        let as_i64 = Table::new(vec!["v".to_string()], vec![Column::I64(vec![7])]);
+        let as_ts = Table::new(vec!["v".to_string()], vec![Column::Ts(vec![7])]);
+        assert_ne!(as_i64.content_hash(), as_ts.content_hash());
+    }
+}
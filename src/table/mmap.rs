@@ -0,0 +1,306 @@
+//! Memory-mapped read-only F64 storage (feature `mmap`)
+//!
+//! Backs `Column::F64Mmap`: a file region of native-endian f64s exposed as
+//! `&[f64]` without copying into RAM. Read-only kernels reach it through
+//! `Column::as_f64_slice` / `f64_data`; mutating accessors panic.
+
+use super::Column;
+use memmap2::Mmap;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+const F64_SIZE: usize = std::mem::size_of::<f64>();
+
+/// Read-only f64 slice over a shared memory map
+///
+/// Clones share the mapping. The file must not be modified or truncated
+/// while any clone is alive: the slice would change (or fault) under the
+/// reader.
+#[derive(Clone)]
+pub struct MmapF64 {
+    map: Arc<Mmap>,
+    /// Byte offset of the first element in the map
+    offset: usize,
+    /// Number of f64 elements
+    len: usize,
+}
+
+impl MmapF64 {
+    /// Map a whole file of native-endian f64s
+    ///
+    /// Errors if the file cannot be mapped or its size is not a multiple of 8.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let bytes = std::fs::metadata(path)?.len() as usize;
+        if !bytes.is_multiple_of(F64_SIZE) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("mmap: file size {} is not a multiple of 8", bytes),
+            ));
+        }
+        Self::open_region(path, 0, bytes / F64_SIZE)
+    }
+
+    /// Map `len` f64s starting at byte `offset`
+    ///
+    /// Errors if `offset` is not 8-byte aligned or the region runs past
+    /// the end of the file.
+    pub fn open_region(path: &Path, offset: usize, len: usize) -> io::Result<Self> {
+        if !offset.is_multiple_of(F64_SIZE) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("mmap: offset {} is not 8-byte aligned", offset),
+            ));
+        }
+
+        let file = File::open(path)?;
+        // SAFETY: the map is only read; callers must not modify the file
+        // while it is mapped (see type docs)
+        let map = unsafe { Mmap::map(&file)? };
+
+        let end = len
+            .checked_mul(F64_SIZE)
+            .and_then(|n| n.checked_add(offset))
+            .filter(|&end| end <= map.len())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "mmap: region of {} f64s at offset {} exceeds file size {}",
+                        len,
+                        offset,
+                        map.len()
+                    ),
+                )
+            })?;
+        debug_assert!(end <= map.len());
+
+        Ok(MmapF64 {
+            map: Arc::new(map),
+            offset,
+            len,
+        })
+    }
+
+    /// Mapped values
+    #[inline]
+    pub fn as_slice(&self) -> &[f64] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: the map is page-aligned and `offset` is a multiple of 8,
+        // so the pointer is f64-aligned; `open_region` checked the region
+        // lies inside the map, and every bit pattern is a valid f64
+        unsafe {
+            let ptr = self.map.as_ptr().add(self.offset) as *const f64;
+            std::slice::from_raw_parts(ptr, self.len)
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Debug for MmapF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmapF64")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl Column {
+    /// Map a whole file of native-endian f64s as a read-only `F64Mmap` column
+    pub fn mmap_f64(path: &Path) -> io::Result<Column> {
+        MmapF64::open(path).map(Column::F64Mmap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtins::ops::{dlog_column, sum};
+    use std::path::PathBuf;
+
+    /// Write `data` to a fresh temp file as native-endian bytes
+    fn write_temp(name: &str, data: &[f64]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("blawk_{}_{}.f64", name, std::process::id()));
+        let bytes: Vec<u8> = data.iter().flat_map(|x| x.to_ne_bytes()).collect();
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_mmap_matches_in_memory() {
+        let data: Vec<f64> = (0..1000)
+            .map(|i| {
+                if i % 97 == 0 {
+                    f64::NAN
+                } else {
+                    100.0 + (i as f64 * 0.3).sin()
+                }
+            })
+            .collect();
+        let path = write_temp("match", &data);
+
+        let mapped = Column::mmap_f64(&path).unwrap();
+        let owned = Column::F64(data.clone());
+
+        assert_eq!(mapped.len(), 1000);
+        assert_eq!(mapped.as_f64_slice().unwrap().len(), 1000);
+        assert_eq!(sum(&mapped).to_bits(), sum(&owned).to_bits());
+        assert!(dlog_column(&mapped, 1).approx_eq(&dlog_column(&owned, 1), 0.0));
+        assert!(matches!(dlog_column(&mapped, 1), Column::F64(_)));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mmap_read_only_kernels_match_in_memory() {
+        use crate::builtins::ops::{
+            abs_column, clip_column, diff_column, ln_column, max, mean, min, pct_change_column,
+            quantile, var, zscore_column,
+        };
+        use crate::builtins::{ewma_column, rank_column, RankMethod};
+
+        let data = vec![4.0, 1.5, f64::NAN, 9.0, 2.5, 7.0, 3.0];
+        let path = write_temp("kernels", &data);
+        let mapped = Column::mmap_f64(&path).unwrap();
+        let owned = Column::F64(data);
+
+        type Kernel = fn(&Column) -> Column;
+        type Reduce = fn(&Column) -> f64;
+
+        let columns: [(&str, Kernel); 8] = [
+            ("ln", ln_column),
+            ("abs", abs_column),
+            ("diff", |x| diff_column(x, 1)),
+            ("pct_change", |x| pct_change_column(x, 2)),
+            ("clip", |x| clip_column(x, Some(2.0), Some(8.0))),
+            ("ewma", |x| ewma_column(x, 0.3)),
+            ("rank", |x| rank_column(x, RankMethod::Average)),
+            ("zscore", zscore_column),
+        ];
+        for (name, kernel) in columns {
+            let out = kernel(&mapped);
+            assert!(matches!(out, Column::F64(_)), "{}", name);
+            assert!(out.approx_eq(&kernel(&owned), 0.0), "{}", name);
+        }
+
+        let scalars: [(&str, Reduce); 5] = [
+            ("mean", mean),
+            ("min", min),
+            ("max", max),
+            ("var", var),
+            ("quantile", |x| quantile(x, 0.5)),
+        ];
+        for (name, reduce) in scalars {
+            let (a, b) = (reduce(&mapped), reduce(&owned));
+            assert!(a.to_bits() == b.to_bits(), "{}: {} vs {}", name, a, b);
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mmap_ori_reducers_match_in_memory() {
+        use crate::builtins::ori_ops;
+        use crate::table::{Table, TableView, ORI_H, ORI_R, ORI_Z};
+
+        let data = vec![1.0, f64::NAN, 3.0];
+        let path = write_temp("ori_reducers", &data);
+        let names = vec!["a".to_string(), "b".to_string()];
+        let b = Column::F64(vec![10.0, 20.0, 30.0]);
+        let mapped = Table::new(
+            names.clone(),
+            vec![Column::mmap_f64(&path).unwrap(), b.clone()],
+        );
+        let owned = Table::new(names, vec![Column::F64(data), b]);
+
+        type Reduce = fn(&TableView) -> Column;
+        let reducers: [Reduce; 4] = [ori_ops::sum, ori_ops::mean, ori_ops::max, ori_ops::std];
+        for ori in [ORI_H, ORI_Z, ORI_R] {
+            for reduce in reducers {
+                let got = reduce(&TableView::with_ori(mapped.clone(), ori));
+                let want = reduce(&TableView::with_ori(owned.clone(), ori));
+                assert!(
+                    got.approx_eq(&want, 0.0),
+                    "{:?}: {:?} vs {:?}",
+                    ori,
+                    got,
+                    want
+                );
+            }
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mmap_demean_in_both_orientations() {
+        use crate::builtins::ori_ops::demean;
+        use crate::table::{Table, TableView, ORI_H, ORI_Z};
+
+        let data = vec![1.0, 4.0, 7.0];
+        let path = write_temp("demean", &data);
+        let names = vec!["a".to_string(), "b".to_string()];
+        let b = Column::F64(vec![3.0, 6.0, 9.0]);
+        let mapped = Table::new(
+            names.clone(),
+            vec![Column::mmap_f64(&path).unwrap(), b.clone()],
+        );
+        let owned = Table::new(names, vec![Column::F64(data), b]);
+
+        for ori in [ORI_H, ORI_Z] {
+            let got = demean(&TableView::with_ori(mapped.clone(), ori));
+            let want = demean(&TableView::with_ori(owned.clone(), ori));
+            assert!(got.approx_eq(&want, 0.0), "{:?}", ori);
+        }
+        // Row means include the mapped column: rows are (1, 3), (4, 6), (7, 9)
+        let z = demean(&TableView::with_ori(mapped, ORI_Z));
+        assert_eq!(z.columns[0].f64_data(), &[-1.0, -1.0, -1.0]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mmap_region_and_errors() {
+        let path = write_temp("region", &[1.0, 2.0, 3.0, 4.0]);
+
+        let region = MmapF64::open_region(&path, 8, 2).unwrap();
+        assert_eq!(region.as_slice(), &[2.0, 3.0]);
+        assert!(MmapF64::open_region(&path, 4, 1).is_err());
+        assert!(MmapF64::open_region(&path, 8, 4).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "F64Mmap column is read-only")]
+    fn test_mmap_mutation_panics() {
+        let path = write_temp("readonly", &[1.0]);
+        let mut col = Column::mmap_f64(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        col.f64_data_mut()[0] = 2.0;
+    }
+
+    #[test]
+    fn test_mmap_try_mutation_errors() {
+        let path = write_temp("try_readonly", &[1.0]);
+        let mut col = Column::mmap_f64(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let err = col.try_f64_data_mut().unwrap_err();
+        assert_eq!(err.to_string(), "expected mutable F64 column, got F64Mmap");
+        assert_eq!(col.f64_data(), &[1.0]);
+    }
+}
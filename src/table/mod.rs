@@ -3,14 +3,48 @@
 pub mod bitmap;
 pub mod column;
 pub mod d4_compose;
+pub mod group_by;
+pub mod hash;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 pub mod orientation;
+pub mod resample;
+pub mod rows;
+pub mod sample;
+pub mod schema;
+pub mod sym;
+pub mod temporal;
 pub mod view;
 
 pub use bitmap::Bitmap;
-pub use column::{Column, NULL_DATE, NULL_TIMESTAMP, NULL_TS};
+pub use column::{Column, ColumnTypeError, NULL_DATE, NULL_I64, NULL_SYM, NULL_TIMESTAMP, NULL_TS};
 pub use d4_compose::compose;
+pub use group_by::AggKind;
+pub use orientation::{
+    lookup_ori, Ori, OriClass, OriSpec, ReduceMode, VecAxis, ORI_H, ORI_N, ORI_R, ORI_S, ORI_SPECS,
+    ORI_X, ORI_Z, ORI__H, ORI__N, ORI__S, ORI__SZ, ORI__Z,
+};
+pub use rows::{ColumnKey, RowRef};
+pub use schema::{ColumnKind, Schema, SchemaError};
+pub use sym::SymBuilder;
+pub use temporal::date_diff;
+pub use view::{TableView, ViewError};
+
+use std::cmp::Ordering;
+
+/// Which rows `Table::dropna` drops
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropHow {
+    /// Drop a row if any considered column is null
+    Any,
+    /// Drop a row only if every considered column is null
+    All,
+}
 
 /// A table is a collection of named, typed columns
+///
+/// Invariant: all columns have the same length (`row_count`). `try_new`
+/// checks it; `new` only checks that names and columns pair up.
 #[derive(Debug, Clone)]
 pub struct Table {
     pub names: Vec<String>,
@@ -18,11 +52,42 @@ pub struct Table {
 }
 
 impl Table {
+    /// Build a table, panicking if `names` and `columns` differ in length
+    ///
+    /// Column lengths are not checked; use `try_new` for untrusted input.
     pub fn new(names: Vec<String>, columns: Vec<Column>) -> Self {
         assert_eq!(names.len(), columns.len());
         Self { names, columns }
     }
 
+    /// Build a table, checking the name count and that all columns share
+    /// the first column's length
+    pub fn try_new(names: Vec<String>, columns: Vec<Column>) -> Result<Self, String> {
+        if names.len() != columns.len() {
+            return Err(format!(
+                "try_new: {} names for {} columns",
+                names.len(),
+                columns.len()
+            ));
+        }
+        if let Some(first) = columns.first() {
+            let len = first.len();
+            if let Some(k) = columns.iter().position(|c| c.len() != len) {
+                return Err(format!(
+                    "try_new: column {} ('{}') has {} rows, expected {}",
+                    k,
+                    names[k],
+                    columns[k].len(),
+                    len
+                ));
+            }
+        }
+        Ok(Self { names, columns })
+    }
+
+    /// Number of rows: the length of the first column (0 if none)
+    ///
+    /// Relies on the equal-length invariant; see `try_new`.
     pub fn row_count(&self) -> usize {
         self.columns.first().map(|c| c.len()).unwrap_or(0)
     }
@@ -30,10 +95,742 @@ impl Table {
     pub fn col_count(&self) -> usize {
         self.columns.len()
     }
+
+    /// Index of the column with the given name (first match)
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    /// Get column by name
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.column_index(name).map(|i| &self.columns[i])
+    }
+
+    /// Project a subset of columns by name, in the requested order
+    ///
+    /// Duplicates are allowed (the column is copied once per mention).
+    /// Returns an error naming the first missing column.
+    pub fn select(&self, names: &[&str]) -> Result<Table, String> {
+        let mut new_names = Vec::with_capacity(names.len());
+        let mut new_columns = Vec::with_capacity(names.len());
+
+        for &name in names {
+            let idx = self
+                .column_index(name)
+                .ok_or_else(|| format!("select: column '{}' not found", name))?;
+            new_names.push(self.names[idx].clone());
+            new_columns.push(self.columns[idx].clone());
+        }
+
+        Ok(Table::new(new_names, new_columns))
+    }
+
+    /// Copy of the table with column `old` renamed to `new` (see `rename`)
+    pub fn rename_column(&self, old: &str, new: &str) -> Result<Table, String> {
+        self.rename(&[(old, new)])
+    }
+
+    /// Copy of the table with columns renamed by exact `(old, new)` match
+    ///
+    /// Renames apply simultaneously, so `[("a", "b"), ("b", "a")]` swaps two
+    /// columns. Errors if an old name is missing or if a renamed column would
+    /// share its name with another column.
+    pub fn rename(&self, map: &[(&str, &str)]) -> Result<Table, String> {
+        for &(old, _) in map {
+            if self.column_index(old).is_none() {
+                return Err(format!("rename: column '{}' not found", old));
+            }
+        }
+
+        let target = |name: &str| {
+            map.iter()
+                .find(|&&(old, _)| old == name)
+                .map(|&(_, new)| new)
+        };
+        let new_names: Vec<String> = self
+            .names
+            .iter()
+            .map(|n| target(n).unwrap_or(n).to_string())
+            .collect();
+
+        for (i, old) in self.names.iter().enumerate() {
+            if target(old).is_none() {
+                continue;
+            }
+            let new = &new_names[i];
+            if new_names
+                .iter()
+                .enumerate()
+                .any(|(j, n)| j != i && n == new)
+            {
+                return Err(format!(
+                    "rename: renaming '{}' to '{}' would duplicate a column name",
+                    old, new
+                ));
+            }
+        }
+
+        Ok(Table::new(new_names, self.columns.clone()))
+    }
+
+    /// Copy of the table with column `name` replaced by `f(column)`
+    ///
+    /// The targeted counterpart to the whole-table colwise kernels: e.g.
+    /// `t.transform_column("close", |c| dlog_column(c, 1))`. Other columns
+    /// are copied unchanged. Errors if `name` is missing or `f` changes the
+    /// column length.
+    pub fn transform_column(
+        &self,
+        name: &str,
+        f: impl Fn(&Column) -> Column,
+    ) -> Result<Table, String> {
+        let idx = self
+            .column_index(name)
+            .ok_or_else(|| format!("transform_column: column '{}' not found", name))?;
+
+        let old = &self.columns[idx];
+        let new = f(old);
+        if new.len() != old.len() {
+            return Err(format!(
+                "transform_column: column '{}' changed length from {} to {}",
+                name,
+                old.len(),
+                new.len()
+            ));
+        }
+
+        let mut columns = self.columns.clone();
+        columns[idx] = new;
+        Ok(Table::new(self.names.clone(), columns))
+    }
+
+    /// Keep rows where the Bool `mask` column is true
+    ///
+    /// Applied to every column at once so rows stay aligned; column types and
+    /// null sentinels are preserved. Errors if the mask is not Bool or its
+    /// length differs from `row_count()`.
+    pub fn filter_rows(&self, mask: &Column) -> Result<Table, String> {
+        let mask = mask
+            .as_bool_slice()
+            .map_err(|_| format!("filter_rows: mask must be Bool, got {}", mask.type_name()))?;
+
+        if !self.columns.is_empty() && mask.len() != self.row_count() {
+            return Err(format!(
+                "filter_rows: mask length {} != row count {}",
+                mask.len(),
+                self.row_count()
+            ));
+        }
+
+        let columns = self.columns.iter().map(|c| c.filter(mask)).collect();
+        Ok(Table::new(self.names.clone(), columns))
+    }
+
+    /// Drop rows with nulls (see `Column::is_null`) in the `subset` columns
+    ///
+    /// `None` considers every column; an empty subset drops nothing. Errors
+    /// if a subset column does not exist.
+    pub fn dropna(&self, subset: Option<&[&str]>, how: DropHow) -> Result<Table, String> {
+        let cols: Vec<&Column> = match subset {
+            None => self.columns.iter().collect(),
+            Some(names) => names
+                .iter()
+                .map(|&name| {
+                    self.column(name)
+                        .ok_or_else(|| format!("dropna: column '{}' not found", name))
+                })
+                .collect::<Result<_, _>>()?,
+        };
+
+        let keep = (0..self.row_count())
+            .map(|i| {
+                let mut nulls = cols.iter().map(|c| c.is_null(i));
+                let drop = match how {
+                    DropHow::Any => nulls.any(|b| b),
+                    DropHow::All => !cols.is_empty() && nulls.all(|b| b),
+                };
+                !drop
+            })
+            .collect();
+        self.filter_rows(&Column::Bool(keep))
+    }
+
+    /// Rows [start, end) of every column, clamped as in `Column::slice`
+    pub fn slice_rows(&self, start: usize, end: usize) -> Table {
+        let columns = self.columns.iter().map(|c| c.slice(start, end)).collect();
+        Table::new(self.names.clone(), columns)
+    }
+
+    /// First `n` rows (all rows if `n >= row_count`)
+    pub fn head(&self, n: usize) -> Table {
+        self.slice_rows(0, n)
+    }
+
+    /// Last `n` rows in original order (all rows if `n >= row_count`)
+    pub fn tail(&self, n: usize) -> Table {
+        let rows = self.row_count();
+        self.slice_rows(rows.saturating_sub(n), rows)
+    }
+
+    /// Materialized transpose: row i becomes F64 column `"r{i}"`
+    ///
+    /// The result has `row_count()` columns of length `col_count()`; input
+    /// column names are dropped. Errors unless every column is F64.
+    pub fn transpose_f64(&self) -> Result<Table, String> {
+        let mut data = Vec::with_capacity(self.columns.len());
+        for (name, col) in self.names.iter().zip(&self.columns) {
+            let slice = col.as_f64_slice().map_err(|_| {
+                format!(
+                    "transpose_f64: column '{}' must be F64, got {}",
+                    name,
+                    col.type_name()
+                )
+            })?;
+            data.push(slice);
+        }
+
+        let nrows = self.row_count();
+        let names = (0..nrows).map(|i| format!("r{}", i)).collect();
+        let columns = (0..nrows)
+            .map(|i| Column::F64(data.iter().map(|col| col[i]).collect()))
+            .collect();
+        Ok(Table::new(names, columns))
+    }
+
+    /// Reorder all rows by a stable sort of column `col`
+    ///
+    /// Nulls (NaN and temporal/I64/Sym sentinels) go last in both directions;
+    /// equal keys keep their original order. Sym keys sort by string, not
+    /// code. Errors if `col` is missing.
+    pub fn sort_by(&self, col: &str, descending: bool) -> Result<Table, String> {
+        let key = self
+            .column(col)
+            .ok_or_else(|| format!("sort_by: column '{}' not found", col))?;
+
+        // A stable sort of already-sorted data is the identity
+        if !descending && key.is_sorted_ascending() {
+            return Ok(self.clone());
+        }
+
+        let perm = match key {
+            Column::F64(data) => sort_permutation(
+                data,
+                descending,
+                |x| x.is_nan(),
+                |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            ),
+            Column::Date(data) => sort_permutation(data, descending, |&x| x == NULL_DATE, Ord::cmp),
+            Column::Timestamp(data) => {
+                sort_permutation(data, descending, |&x| x == NULL_TIMESTAMP, Ord::cmp)
+            }
+            Column::Ts(data) => sort_permutation(data, descending, |&x| x == NULL_TS, Ord::cmp),
+            Column::I64(data) => sort_permutation(data, descending, |&x| x == NULL_I64, Ord::cmp),
+            Column::Bool(data) => sort_permutation(data, descending, |_| false, Ord::cmp),
+            Column::F32(data) => sort_permutation(
+                data,
+                descending,
+                |x| x.is_nan(),
+                |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            ),
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(m) => sort_permutation(
+                m.as_slice(),
+                descending,
+                |x| x.is_nan(),
+                |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            ),
+            Column::Sym { codes, dict } => sort_permutation(
+                codes,
+                descending,
+                |&c| c == NULL_SYM,
+                |&a, &b| dict[a as usize].cmp(&dict[b as usize]),
+            ),
+        };
+
+        let columns = self.columns.iter().map(|c| c.take(&perm)).collect();
+        Ok(Table::new(self.names.clone(), columns))
+    }
+
+    /// Approximate equality: same column names in order, and each column
+    /// pair equal per `Column::approx_eq`
+    pub fn approx_eq(&self, other: &Table, epsilon: f64) -> bool {
+        self.names == other.names
+            && self.columns.len() == other.columns.len()
+            && self
+                .columns
+                .iter()
+                .zip(&other.columns)
+                .all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+
+    /// Stack tables vertically, in order
+    ///
+    /// Every input's schema must be compatible with the first's (see
+    /// `Schema::compatible_with`): same names, order and column kinds. An
+    /// empty slice gives an empty table.
+    pub fn concat_rows(tables: &[&Table]) -> Result<Table, String> {
+        let Some((first, rest)) = tables.split_first() else {
+            return Ok(Table::new(Vec::new(), Vec::new()));
+        };
+
+        let expected = first.schema();
+        for (k, t) in rest.iter().enumerate() {
+            t.schema()
+                .compatible_with(&expected)
+                .map_err(|e| format!("concat_rows: table {}: {}", k + 1, e))?;
+        }
+
+        let mut columns = first.columns.clone();
+        for t in rest {
+            for (col, other) in columns.iter_mut().zip(&t.columns) {
+                col.extend_from(other);
+            }
+        }
+        Ok(Table::new(first.names.clone(), columns))
+    }
+}
+
+/// Stable sort permutation with nulls last regardless of direction
+fn sort_permutation<T>(
+    data: &[T],
+    descending: bool,
+    is_null: impl Fn(&T) -> bool,
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..data.len()).collect();
+    perm.sort_by(|&i, &j| {
+        let (a, b) = (&data[i], &data[j]);
+        match (is_null(a), is_null(b)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) if descending => cmp(b, a),
+            (false, false) => cmp(a, b),
+        }
+    });
+    perm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_table() -> Table {
+        Table::new(
+            vec!["a".to_string(), "b".to_string(), "d".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0]),
+                Column::F64(vec![3.0, 4.0]),
+                Column::Date(vec![18628, 18629]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_slice_rows_mixed_types() {
+        let t = Table::new(
+            vec!["x".to_string(), "d".to_string(), "b".to_string()],
+            vec![
+                Column::F64(vec![1.0, f64::NAN, 3.0, 4.0]),
+                Column::Date(vec![10, 11, NULL_DATE, 13]),
+                Column::Bool(vec![true, false, true, false]),
+            ],
+        );
+
+        let s = t.slice_rows(1, 3);
+        assert_eq!(s.names, t.names);
+        assert!(s.columns[0].f64_data()[0].is_nan());
+        assert_eq!(s.columns[0].f64_data()[1], 3.0);
+        assert_eq!(s.columns[1].date_data(), &[11, NULL_DATE]);
+        assert_eq!(s.columns[2].bool_data(), &[false, true]);
+
+        let empty = t.slice_rows(2, 2);
+        assert_eq!(empty.row_count(), 0);
+        let types: Vec<_> = empty.columns.iter().map(|c| c.type_name()).collect();
+        assert_eq!(types, vec!["F64", "Date", "Bool"]);
+
+        assert_eq!(t.slice_rows(3, 99).row_count(), 1);
+    }
+
+    #[test]
+    fn test_head_tail() {
+        let t = Table::new(
+            vec!["x".to_string(), "d".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0, 3.0]),
+                Column::Date(vec![10, 11, 12]),
+            ],
+        );
+
+        let h = t.head(0);
+        assert_eq!(h.row_count(), 0);
+        assert_eq!(h.col_count(), 2);
+        assert!(t.head(1000).approx_eq(&t, 0.0));
+        assert_eq!(t.head(2).columns[1].date_data(), &[10, 11]);
+
+        let last = t.tail(2);
+        assert_eq!(last.columns[0].f64_data(), &[2.0, 3.0]);
+        assert_eq!(last.columns[1].date_data(), &[11, 12]);
+        assert!(t.tail(1000).approx_eq(&t, 0.0));
+        assert_eq!(t.tail(0).row_count(), 0);
+    }
+
+    #[test]
+    fn test_try_new_checks_shape() {
+        let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let t = Table::try_new(
+            names.clone(),
+            vec![
+                Column::F64(vec![1.0, 2.0]),
+                Column::Date(vec![1, 2]),
+                Column::I64(vec![1, 2]),
+            ],
+        )
+        .unwrap();
+        assert_eq!(t.row_count(), 2);
+
+        let err = Table::try_new(
+            names.clone(),
+            vec![
+                Column::F64(vec![1.0, 2.0]),
+                Column::Date(vec![1, 2]),
+                Column::I64(vec![1]),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(err, "try_new: column 2 ('c') has 1 rows, expected 2");
+
+        let err = Table::try_new(names, vec![Column::F64(vec![1.0])]).unwrap_err();
+        assert_eq!(err, "try_new: 3 names for 1 columns");
+
+        assert_eq!(Table::try_new(vec![], vec![]).unwrap().row_count(), 0);
+    }
+
+    #[test]
+    fn test_table_approx_eq() {
+        let t = Table::new(
+            vec!["x".to_string(), "d".to_string()],
+            vec![Column::F64(vec![1.0, f64::NAN]), Column::Date(vec![1, 2])],
+        );
+        let near = Table::new(
+            t.names.clone(),
+            vec![
+                Column::F64(vec![1.0 + 1e-10, f64::NAN]),
+                Column::Date(vec![1, 2]),
+            ],
+        );
+        assert!(t.approx_eq(&near, 1e-9));
+        assert!(!t.approx_eq(&near, 0.0));
+
+        let short = t.select(&["x"]).unwrap();
+        assert!(!t.approx_eq(&short, 1.0));
+
+        let fewer_rows = Table::new(
+            t.names.clone(),
+            vec![Column::F64(vec![1.0]), Column::Date(vec![1])],
+        );
+        assert!(!t.approx_eq(&fewer_rows, 1.0));
+
+        let renamed = Table::new(vec!["y".to_string(), "d".to_string()], t.columns.clone());
+        assert!(!t.approx_eq(&renamed, 1.0));
+    }
+
+    #[test]
+    fn test_select_reorders() {
+        let t = make_table().select(&["d", "a"]).unwrap();
+        assert_eq!(t.names, vec!["d", "a"]);
+        assert_eq!(t.columns[0].date_data(), &[18628, 18629]);
+        assert_eq!(t.columns[1].f64_data(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_select_duplicates() {
+        let t = make_table().select(&["b", "b"]).unwrap();
+        assert_eq!(t.names, vec!["b", "b"]);
+        assert_eq!(t.col_count(), 2);
+        assert_eq!(t.columns[1].f64_data(), &[3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_rename_column() {
+        let t = make_table().rename_column("b", "px").unwrap();
+        assert_eq!(t.names, vec!["a", "px", "d"]);
+        assert_eq!(t.columns[1].f64_data(), &[3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_rename_batch_and_swap() {
+        let t = make_table().rename(&[("a", "x"), ("b", "y")]).unwrap();
+        assert_eq!(t.names, vec!["x", "y", "d"]);
+
+        let t = make_table().rename(&[("a", "b"), ("b", "a")]).unwrap();
+        assert_eq!(t.names, vec!["b", "a", "d"]);
+        assert_eq!(t.columns[1].f64_data(), &[3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_rename_errors() {
+        let err = make_table().rename_column("nope", "x").unwrap_err();
+        assert_eq!(err, "rename: column 'nope' not found");
+
+        let err = make_table().rename_column("a", "b").unwrap_err();
+        assert_eq!(
+            err,
+            "rename: renaming 'a' to 'b' would duplicate a column name"
+        );
+        assert!(make_table().rename(&[("a", "z"), ("b", "z")]).is_err());
+    }
+
+    #[test]
+    fn test_transform_column() {
+        use crate::builtins::ops::dlog_column;
+
+        let t = make_table();
+        let out = t.transform_column("b", |c| dlog_column(c, 1)).unwrap();
+
+        assert_eq!(out.names, t.names);
+        assert!(out.columns[1].approx_eq(&dlog_column(&t.columns[1], 1), 0.0));
+        assert!(out.columns[0].approx_eq(&t.columns[0], 0.0));
+        assert!(out.columns[2].approx_eq(&t.columns[2], 0.0));
+    }
+
+    #[test]
+    fn test_transform_column_errors() {
+        let t = make_table();
+        let err = t.transform_column("nope", |c| c.clone()).unwrap_err();
+        assert_eq!(err, "transform_column: column 'nope' not found");
+
+        let err = t.transform_column("a", |c| c.slice(0, 1)).unwrap_err();
+        assert_eq!(
+            err,
+            "transform_column: column 'a' changed length from 2 to 1"
+        );
+    }
+
+    #[test]
+    fn test_transpose_f64() {
+        let t = Table::new(
+            vec!["x".to_string(), "y".to_string()],
+            vec![
+                Column::F64(vec![1.0, 2.0, 3.0]),
+                Column::F64(vec![4.0, f64::NAN, 6.0]),
+            ],
+        );
+        let tt = t.transpose_f64().unwrap();
+
+        assert_eq!(tt.names, vec!["r0", "r1", "r2"]);
+        assert_eq!(tt.row_count(), 2);
+        assert_eq!(tt.columns[0].f64_data(), &[1.0, 4.0]);
+        assert_eq!(tt.columns[1].f64_data()[0], 2.0);
+        assert!(tt.columns[1].f64_data()[1].is_nan());
+        assert_eq!(tt.columns[2].f64_data(), &[3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_transpose_f64_rejects_non_f64() {
+        let err = make_table().transpose_f64().unwrap_err();
+        assert_eq!(err, "transpose_f64: column 'd' must be F64, got Date");
+    }
+
+    #[test]
+    fn test_filter_rows_mixed() {
+        let t = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![10, NULL_DATE, 12, 13]),
+                Column::F64(vec![1.0, 2.0, f64::NAN, 4.0]),
+            ],
+        );
+        let mask = Column::Bool(vec![false, true, true, false]);
+
+        let out = t.filter_rows(&mask).unwrap();
+        assert_eq!(out.row_count(), 2);
+        assert_eq!(out.columns[0].date_data(), &[NULL_DATE, 12]);
+        let x = out.columns[1].f64_data();
+        assert_eq!(x[0], 2.0);
+        assert!(x[1].is_nan());
+    }
+
+    #[test]
+    fn test_dropna_any_vs_all() {
+        let t = Table::new(
+            vec!["d".to_string(), "x".to_string(), "y".to_string()],
+            vec![
+                Column::Date(vec![10, NULL_DATE, 12, NULL_DATE]),
+                Column::F64(vec![1.0, 2.0, f64::NAN, f64::NAN]),
+                Column::F64(vec![1.0, 2.0, 3.0, f64::NAN]),
+            ],
+        );
+
+        // The Date null in row 1 counts like a NaN
+        let any = t.dropna(None, DropHow::Any).unwrap();
+        assert_eq!(any.columns[0].date_data(), &[10]);
+
+        let all = t.dropna(None, DropHow::All).unwrap();
+        assert_eq!(all.columns[0].date_data(), &[10, NULL_DATE, 12]);
+        assert_eq!(all.columns[2].f64_data(), &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_dropna_subset() {
+        let t = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![10, NULL_DATE, 12]),
+                Column::F64(vec![1.0, 2.0, f64::NAN]),
+            ],
+        );
+
+        // Only "x" counts: the NULL_DATE row survives
+        let out = t.dropna(Some(&["x"]), DropHow::Any).unwrap();
+        assert_eq!(out.columns[0].date_data(), &[10, NULL_DATE]);
+
+        let out = t.dropna(Some(&["d"]), DropHow::All).unwrap();
+        assert_eq!(out.columns[0].date_data(), &[10, 12]);
+
+        assert_eq!(t.dropna(Some(&[]), DropHow::All).unwrap().row_count(), 3);
+        assert_eq!(
+            t.dropna(Some(&["nope"]), DropHow::Any).unwrap_err(),
+            "dropna: column 'nope' not found"
+        );
+    }
+
+    #[test]
+    fn test_filter_rows_all_true_and_all_false() {
+        let t = make_table();
+
+        let all = t.filter_rows(&Column::Bool(vec![true, true])).unwrap();
+        assert_eq!(all.names, t.names);
+        assert_eq!(all.columns[0].f64_data(), &[1.0, 2.0]);
+        assert_eq!(all.columns[2].date_data(), &[18628, 18629]);
+
+        let none = t.filter_rows(&Column::Bool(vec![false, false])).unwrap();
+        assert_eq!(none.col_count(), 3);
+        assert_eq!(none.row_count(), 0);
+        assert!(matches!(none.columns[2], Column::Date(_)));
+    }
+
+    #[test]
+    fn test_filter_rows_errors() {
+        let t = make_table();
+        assert!(t.filter_rows(&Column::Bool(vec![true])).is_err());
+        assert!(t.filter_rows(&Column::F64(vec![1.0, 0.0])).is_err());
+    }
+
+    #[test]
+    fn test_sort_by_stable() {
+        let t = Table::new(
+            vec!["k".to_string(), "id".to_string()],
+            vec![
+                Column::I64(vec![2, 1, 2, 1, 0]),
+                Column::I64(vec![0, 1, 2, 3, 4]),
+            ],
+        );
+
+        let asc = t.sort_by("k", false).unwrap();
+        assert_eq!(asc.columns[0].i64_data(), &[0, 1, 1, 2, 2]);
+        assert_eq!(asc.columns[1].i64_data(), &[4, 1, 3, 0, 2]);
+
+        let desc = t.sort_by("k", true).unwrap();
+        assert_eq!(desc.columns[0].i64_data(), &[2, 2, 1, 1, 0]);
+        assert_eq!(desc.columns[1].i64_data(), &[0, 2, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_sort_by_nulls_last() {
+        let t = Table::new(
+            vec!["x".to_string(), "d".to_string()],
+            vec![
+                Column::F64(vec![3.0, f64::NAN, 1.0, 2.0]),
+                Column::Date(vec![30, NULL_DATE, 10, 20]),
+            ],
+        );
+
+        for descending in [false, true] {
+            let out = t.sort_by("x", descending).unwrap();
+            assert!(out.columns[0].f64_data()[3].is_nan());
+            assert_eq!(out.columns[1].date_data()[3], NULL_DATE);
+        }
+
+        let out = t.sort_by("d", true).unwrap();
+        assert_eq!(out.columns[1].date_data(), &[30, 20, 10, NULL_DATE]);
+        assert_eq!(&out.columns[0].f64_data()[..3], &[3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_sort_by_already_sorted() {
+        let t = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![1, 2, 2, NULL_DATE]),
+                Column::F64(vec![4.0, 3.0, 2.0, 1.0]),
+            ],
+        );
+        assert!(t.sort_by("d", false).unwrap().approx_eq(&t, 0.0));
+
+        let desc = t.sort_by("d", true).unwrap();
+        assert_eq!(desc.columns[0].date_data(), &[2, 2, 1, NULL_DATE]);
+        assert_eq!(desc.columns[1].f64_data(), &[3.0, 2.0, 4.0, 1.0]);
+    }
+
+    #[test]
+    fn test_sort_by_keeps_rows_aligned() {
+        let t = make_table().sort_by("b", true).unwrap();
+        assert_eq!(t.columns[0].f64_data(), &[2.0, 1.0]);
+        assert_eq!(t.columns[1].f64_data(), &[4.0, 3.0]);
+        assert_eq!(t.columns[2].date_data(), &[18629, 18628]);
+
+        assert!(make_table().sort_by("zz", false).is_err());
+    }
+
+    #[test]
+    fn test_concat_rows_stacks_with_date() {
+        let a = make_table();
+        let b = Table::new(
+            a.names.clone(),
+            vec![
+                Column::F64(vec![5.0]),
+                Column::F64(vec![f64::NAN]),
+                Column::Date(vec![NULL_DATE]),
+            ],
+        );
+        let t = Table::concat_rows(&[&a, &b]).unwrap();
+        assert_eq!(t.row_count(), 3);
+        assert_eq!(t.columns[0].f64_data(), &[1.0, 2.0, 5.0]);
+        assert!(t.columns[1].f64_data()[2].is_nan());
+        assert_eq!(t.columns[2].date_data(), &[18628, 18629, NULL_DATE]);
+    }
+
+    #[test]
+    fn test_concat_rows_schema_mismatch() {
+        let a = make_table();
+        let reordered = a.select(&["b", "a", "d"]).unwrap();
+        let err = Table::concat_rows(&[&a, &reordered]).unwrap_err();
+        assert_eq!(err, "concat_rows: table 1: column 0 is 'b' vs 'a'");
+
+        let mut retyped = a.clone();
+        retyped.columns[2] = Column::Timestamp(vec![0, 1]);
+        let err = Table::concat_rows(&[&a, &retyped]).unwrap_err();
+        assert_eq!(
+            err,
+            "concat_rows: table 1: column 2 ('d') is Timestamp vs Date"
+        );
+    }
+
+    #[test]
+    fn test_concat_rows_identity_and_empty() {
+        let a = make_table();
+        let t = Table::concat_rows(&[&a]).unwrap();
+        assert_eq!(t.names, a.names);
+        assert_eq!(t.columns[0].f64_data(), a.columns[0].f64_data());
+
+        let empty = Table::concat_rows(&[]).unwrap();
+        assert_eq!((empty.row_count(), empty.col_count()), (0, 0));
+    }
+
+    #[test]
+    fn test_select_missing_column() {
+        let err = make_table().select(&["a", "zz"]).unwrap_err();
+        assert!(err.contains("'zz'"), "{}", err);
+    }
 }
-pub use orientation::{
-    lookup_ori, Ori, OriClass, OriSpec, ReduceMode, VecAxis, ORI_H, ORI_N, ORI_R, ORI_S, ORI_SPECS,
-    ORI_X, ORI_Z, ORI__H, ORI__N, ORI__S, ORI__Z,
-};
-// Table is now defined directly in this module
-pub use view::TableView;
@@ -2,6 +2,7 @@
 //!
 //! Provides O(1) view transformations while keeping physical storage columnar.
 //! Implements 10 orientations: 8 D4 symmetries + X (elementwise) + R (scalar reduce).
+//! The registry has 11 names because S is a synonym for Z.
 //!
 //! Design principles:
 //! - Physical storage is always columnar: cols[col][row]
@@ -224,7 +225,7 @@ impl Ori {
                 swap: true,
                 flip_i: true,
                 flip_j: true,
-            } => "??", // Unused 8th D4 orientation
+            } => "_SZ",
         }
     }
 }
@@ -232,7 +233,7 @@ impl Ori {
 /// Orientation specification with name and metadata
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct OriSpec {
-    /// Name token (H, Z, N, S, _H, _Z, _N, _S, X, R)
+    /// Name token (H, Z, N, S, _H, _Z, _N, _S, _SZ, X, R)
     pub name: &'static str,
 
     /// Compass string from blawk (for reference/testing)
@@ -245,8 +246,8 @@ pub struct OriSpec {
     pub class: OriClass,
 }
 
-/// Orientation registry - 10 canonical orientations
-pub const ORI_SPECS: [OriSpec; 10] = [
+/// Orientation registry - 10 orientations under 11 names (S = Z)
+pub const ORI_SPECS: [OriSpec; 11] = [
     // ===== Column-Major (ColwiseLike) =====
     // H = "NSWE": Normal, columns contiguous
     OriSpec {
@@ -337,6 +338,18 @@ pub const ORI_SPECS: [OriSpec; 10] = [
         },
         class: OriClass::RowwiseLike,
     },
+    // _SZ: Transposed with both axes reversed (anti-transpose)
+    // No blawk compass string; reachable via composition (e.g. Z then _H)
+    OriSpec {
+        name: "_SZ",
+        compass: "_SZ",
+        ori: Ori::D4 {
+            swap: true,
+            flip_i: true,
+            flip_j: true,
+        },
+        class: OriClass::RowwiseLike,
+    },
     // ===== Special Modes =====
     // X = Elementwise mode
     OriSpec {
@@ -406,6 +419,11 @@ pub const ORI__S: Ori = Ori::D4 {
     flip_i: false,
     flip_j: true,
 };
+pub const ORI__SZ: Ori = Ori::D4 {
+    swap: true,
+    flip_i: true,
+    flip_j: true,
+};
 
 #[cfg(test)]
 mod tests {
@@ -490,16 +508,16 @@ mod tests {
 
     #[test]
     fn test_all_ten_orientations() {
-        // Verify all 10 orientations are registered
-        assert_eq!(ORI_SPECS.len(), 10);
+        // Verify all 10 orientations are registered (11 names, S = Z)
+        assert_eq!(ORI_SPECS.len(), 11);
 
         let names: Vec<&str> = ORI_SPECS.iter().map(|s| s.name).collect();
         assert_eq!(
             names,
-            vec!["H", "N", "_N", "_H", "Z", "S", "_Z", "_S", "X", "R"]
+            vec!["H", "N", "_N", "_H", "Z", "S", "_Z", "_S", "_SZ", "X", "R"]
         );
 
-        // Verify 4 colwise + 4 rowwise + 2 special
+        // Verify 4 colwise + 5 rowwise names (4 distinct) + 2 special
         let colwise = ORI_SPECS
             .iter()
             .filter(|s| s.class == OriClass::ColwiseLike)
@@ -509,7 +527,18 @@ mod tests {
             .filter(|s| s.class == OriClass::RowwiseLike)
             .count();
         assert_eq!(colwise, 4);
-        assert_eq!(rowwise, 4);
+        assert_eq!(rowwise, 5);
+    }
+
+    #[test]
+    fn test_canonical_names_round_trip() {
+        // Every orientation's canonical name looks up to the same orientation
+        for spec in ORI_SPECS.iter() {
+            let name = spec.ori.canonical_name();
+            assert_eq!(lookup_ori(name).unwrap().ori, spec.ori, "{}", name);
+        }
+        assert_eq!(ORI__SZ.canonical_name(), "_SZ");
+        assert_eq!(lookup_ori("_SZ").unwrap().ori, ORI__SZ);
     }
 
     #[test]
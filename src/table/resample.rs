@@ -0,0 +1,208 @@
+//! Time-bar resampling
+
+use super::group_by::{group_indices, run_indices};
+use super::{Column, Table, NULL_TIMESTAMP};
+
+impl Table {
+    /// OHLC bars of `price_col` over `period_ns`-wide bars of `ts_col`
+    ///
+    /// Rows are bucketed by `ts_col.timestamp_floor(period_ns)`. Within a
+    /// bar, open/close are the first/last non-NaN prices in row order and
+    /// high/low the max/min; a bar with no valid price gets NaN for all
+    /// four. Rows with a null timestamp are dropped.
+    ///
+    /// The result has columns `ts_col` (the bar start, same type as the
+    /// input), `open`, `high`, `low`, `close`, sorted by bar time. Errors if
+    /// either column is missing, `ts_col` is not Timestamp/Ts, `price_col`
+    /// is not F64, or `period_ns` is not positive.
+    pub fn resample_ohlc(
+        &self,
+        ts_col: &str,
+        price_col: &str,
+        period_ns: i64,
+    ) -> Result<Table, String> {
+        let ts = self
+            .column(ts_col)
+            .ok_or_else(|| format!("resample_ohlc: column '{}' not found", ts_col))?;
+        let price = self
+            .column(price_col)
+            .ok_or_else(|| format!("resample_ohlc: column '{}' not found", price_col))?;
+        let prices = price.as_f64_slice().map_err(|_| {
+            format!(
+                "resample_ohlc: column '{}' must be F64, got {}",
+                price_col,
+                price.type_name()
+            )
+        })?;
+
+        let bars = ts
+            .timestamp_floor(period_ns)
+            .map_err(|e| format!("resample_ohlc: {}", e))?;
+        let bar_ts = match &bars {
+            Column::Timestamp(data) | Column::Ts(data) => data,
+            _ => unreachable!("timestamp_floor returns Timestamp or Ts"),
+        };
+
+        // Sorted input (the usual tick stream) gives contiguous bars already
+        // in time order; otherwise hash and sort the bar keys
+        let sorted = bars.is_sorted_ascending();
+        let (groups, keys) = if sorted {
+            run_indices(bar_ts)
+        } else {
+            group_indices(bar_ts)
+        };
+        // NULL_TS == NULL_TIMESTAMP: one check covers both
+        let mut order: Vec<usize> = (0..keys.len())
+            .filter(|&g| keys[g] != NULL_TIMESTAMP)
+            .collect();
+        if !sorted {
+            order.sort_by_key(|&g| keys[g]);
+        }
+
+        let n = order.len();
+        let mut out_ts = Vec::with_capacity(n);
+        let (mut open, mut high, mut low, mut close) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+
+        for g in order {
+            let mut valid = groups[g].iter().map(|&i| prices[i]).filter(|p| !p.is_nan());
+            let (o, h, l, c) = match valid.next() {
+                Some(first) => valid.fold((first, first, first, first), |(o, h, l, _), p| {
+                    (o, h.max(p), l.min(p), p)
+                }),
+                None => (f64::NAN, f64::NAN, f64::NAN, f64::NAN),
+            };
+            out_ts.push(keys[g]);
+            open.push(o);
+            high.push(h);
+            low.push(l);
+            close.push(c);
+        }
+
+        Ok(Table::new(
+            vec![
+                ts_col.to_string(),
+                "open".to_string(),
+                "high".to_string(),
+                "low".to_string(),
+                "close".to_string(),
+            ],
+            vec![
+                match bars {
+                    Column::Ts(_) => Column::Ts(out_ts),
+                    _ => Column::Timestamp(out_ts),
+                },
+                Column::F64(open),
+                Column::F64(high),
+                Column::F64(low),
+                Column::F64(close),
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60_000_000_000;
+    const SEC: i64 = 1_000_000_000;
+
+    fn ticks(ts: Vec<i64>, px: Vec<f64>) -> Table {
+        Table::new(
+            vec!["ts".to_string(), "px".to_string()],
+            vec![Column::Timestamp(ts), Column::F64(px)],
+        )
+    }
+
+    #[test]
+    fn test_resample_two_minute_bars() {
+        // Second bar's ticks arrive first; output must still be time-sorted
+        let t = ticks(
+            vec![
+                MINUTE + 5 * SEC,
+                MINUTE + 20 * SEC,
+                MINUTE + 59 * SEC,
+                SEC,
+                10 * SEC,
+                30 * SEC,
+                45 * SEC,
+                NULL_TIMESTAMP,
+            ],
+            vec![20.0, 25.0, 22.0, 10.0, 12.0, f64::NAN, 9.0, 100.0],
+        );
+
+        let bars = t.resample_ohlc("ts", "px", MINUTE).unwrap();
+        assert_eq!(bars.names, vec!["ts", "open", "high", "low", "close"]);
+        assert_eq!(bars.columns[0].timestamp_data(), &[0, MINUTE]);
+        assert_eq!(bars.columns[1].f64_data(), &[10.0, 20.0]);
+        assert_eq!(bars.columns[2].f64_data(), &[12.0, 25.0]);
+        assert_eq!(bars.columns[3].f64_data(), &[9.0, 20.0]);
+        assert_eq!(bars.columns[4].f64_data(), &[9.0, 22.0]);
+    }
+
+    #[test]
+    fn test_resample_sorted_matches_unsorted() {
+        let ts = vec![
+            SEC,
+            10 * SEC,
+            MINUTE,
+            MINUTE + SEC,
+            3 * MINUTE,
+            NULL_TIMESTAMP,
+        ];
+        let px = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let sorted = ticks(ts, px);
+        assert!(sorted.columns[0].is_sorted_ascending());
+
+        let perm = [4, 0, 5, 2, 1, 3];
+        let shuffled = Table::new(
+            sorted.names.clone(),
+            sorted.columns.iter().map(|c| c.take(&perm)).collect(),
+        );
+        assert!(!shuffled.columns[0].is_sorted_ascending());
+
+        let a = sorted.resample_ohlc("ts", "px", MINUTE).unwrap();
+        let b = shuffled.resample_ohlc("ts", "px", MINUTE).unwrap();
+        assert_eq!(a.columns[0].timestamp_data(), &[0, MINUTE, 3 * MINUTE]);
+        assert_eq!(a.columns[1].f64_data(), &[1.0, 3.0, 5.0]);
+        assert_eq!(a.columns[4].f64_data(), &[2.0, 4.0, 5.0]);
+        // Row order inside a bar differs, so only high/low must agree
+        assert!(a.columns[0].approx_eq(&b.columns[0], 0.0));
+        assert!(a.columns[2].approx_eq(&b.columns[2], 0.0));
+        assert!(a.columns[3].approx_eq(&b.columns[3], 0.0));
+    }
+
+    #[test]
+    fn test_resample_empty_bar_is_nan() {
+        let t = ticks(vec![0, MINUTE], vec![f64::NAN, 5.0]);
+        let bars = t.resample_ohlc("ts", "px", MINUTE).unwrap();
+
+        for col in &bars.columns[1..] {
+            assert!(col.f64_data()[0].is_nan());
+            assert_eq!(col.f64_data()[1], 5.0);
+        }
+    }
+
+    #[test]
+    fn test_resample_errors() {
+        let t = ticks(vec![0], vec![1.0]);
+        assert_eq!(
+            t.resample_ohlc("nope", "px", MINUTE).unwrap_err(),
+            "resample_ohlc: column 'nope' not found"
+        );
+        assert_eq!(
+            t.resample_ohlc("px", "px", MINUTE).unwrap_err(),
+            "resample_ohlc: timestamp_floor: expected Timestamp column, got F64"
+        );
+        assert_eq!(
+            t.resample_ohlc("ts", "ts", MINUTE).unwrap_err(),
+            "resample_ohlc: column 'ts' must be F64, got Timestamp"
+        );
+        assert!(t.resample_ohlc("ts", "px", 0).is_err());
+    }
+}
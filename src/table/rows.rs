@@ -0,0 +1,127 @@
+//! Row-at-a-time access for inspecting small tables
+//!
+//! A convenience for glue code and tests, not a hot path: every accessor
+//! looks the column up and matches its variant per call. Kernels should
+//! work on column slices instead.
+
+use super::{Column, Table};
+
+/// A column addressed by name or by position
+pub trait ColumnKey {
+    /// Position of this column in `table`, if it exists
+    fn index_in(&self, table: &Table) -> Option<usize>;
+}
+
+impl ColumnKey for usize {
+    fn index_in(&self, table: &Table) -> Option<usize> {
+        (*self < table.col_count()).then_some(*self)
+    }
+}
+
+impl ColumnKey for &str {
+    fn index_in(&self, table: &Table) -> Option<usize> {
+        table.column_index(self)
+    }
+}
+
+/// One row of a table (see `Table::iter_rows`)
+#[derive(Clone, Copy, Debug)]
+pub struct RowRef<'a> {
+    table: &'a Table,
+    row: usize,
+}
+
+impl<'a> RowRef<'a> {
+    /// Row number within the table
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    fn column(&self, key: impl ColumnKey) -> &'a Column {
+        let idx = key.index_in(self.table).expect("RowRef: column not found");
+        &self.table.columns[idx]
+    }
+
+    /// F64 value in this row (NaN for null)
+    ///
+    /// # Panics
+    /// Panics if the column does not exist or is not F64.
+    pub fn get_f64(&self, key: impl ColumnKey) -> f64 {
+        let col = self.column(key);
+        match col.as_f64_slice() {
+            Ok(data) => data[self.row],
+            Err(_) => panic!("RowRef: expected F64 column, got {}", col.type_name()),
+        }
+    }
+
+    /// Date value in this row (NULL_DATE for null)
+    ///
+    /// # Panics
+    /// Panics if the column does not exist or is not Date.
+    pub fn get_date(&self, key: impl ColumnKey) -> i32 {
+        match self.column(key) {
+            Column::Date(data) => data[self.row],
+            col => panic!("RowRef: expected Date column, got {}", col.type_name()),
+        }
+    }
+
+    /// True if this row's value is null (see `Column::is_null`)
+    ///
+    /// # Panics
+    /// Panics if the column does not exist.
+    pub fn is_null(&self, key: impl ColumnKey) -> bool {
+        self.column(key).is_null(self.row)
+    }
+}
+
+impl Table {
+    /// Iterate rows as `RowRef`s, in order
+    pub fn iter_rows(&self) -> impl Iterator<Item = RowRef<'_>> {
+        (0..self.row_count()).map(move |row| RowRef { table: self, row })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::NULL_DATE;
+
+    #[test]
+    fn test_iter_rows_mixed_table() {
+        let t = Table::new(
+            vec!["date".to_string(), "px".to_string()],
+            vec![
+                Column::Date(vec![18628, NULL_DATE, 18630]),
+                Column::F64(vec![101.5, 102.0, f64::NAN]),
+            ],
+        );
+
+        let mut dates = Vec::new();
+        let mut prices = Vec::new();
+        for r in t.iter_rows() {
+            // Name and position address the same column
+            assert_eq!(r.get_date("date"), r.get_date(0));
+            assert_eq!(r.is_null("px"), r.is_null(1));
+            dates.push((!r.is_null("date")).then(|| r.get_date("date")));
+            prices.push((!r.is_null(1)).then(|| r.get_f64(1)));
+        }
+
+        assert_eq!(dates, vec![Some(18628), None, Some(18630)]);
+        assert_eq!(prices, vec![Some(101.5), Some(102.0), None]);
+        assert_eq!(t.iter_rows().map(|r| r.row()).last(), Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "RowRef: expected Date column, got F64")]
+    fn test_row_ref_wrong_type_panics() {
+        let t = Table::new(vec!["x".to_string()], vec![Column::F64(vec![1.0])]);
+        t.iter_rows().next().unwrap().get_date("x");
+    }
+
+    #[test]
+    #[should_panic(expected = "RowRef: column not found")]
+    fn test_row_ref_missing_column_panics() {
+        let t = Table::new(vec!["x".to_string()], vec![Column::F64(vec![1.0])]);
+        t.iter_rows().next().unwrap().get_f64(3);
+    }
+}
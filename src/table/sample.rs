@@ -0,0 +1,137 @@
+//! Reproducible row sampling
+//!
+//! Uses a small inline SplitMix64 generator rather than the `rand` crate:
+//! bootstrap backtests only need a fast, seedable stream whose output is
+//! stable across platforms and crate versions.
+
+use super::Table;
+
+/// SplitMix64: any seed (including 0) gives a full-period stream
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Index in [0, n) by multiply-shift; `n` must be non-zero
+    fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+}
+
+impl Table {
+    /// `n` rows chosen by a PRNG seeded with `seed`
+    ///
+    /// The same seed and table always give the same rows, in the order
+    /// drawn. With replacement a row may repeat; without, the draw is a
+    /// partial Fisher-Yates shuffle, so `n == row_count()` is a full
+    /// permutation. All columns are gathered with the same indices.
+    ///
+    /// Errors without replacement if `n > row_count()`, and with
+    /// replacement if `n > 0` on an empty table.
+    pub fn sample_rows(
+        &self,
+        n: usize,
+        seed: u64,
+        with_replacement: bool,
+    ) -> Result<Table, String> {
+        let rows = self.row_count();
+        let mut rng = SplitMix64(seed);
+
+        let indices: Vec<usize> = if with_replacement {
+            if rows == 0 && n > 0 {
+                return Err(format!(
+                    "sample_rows: cannot draw {} rows from an empty table",
+                    n
+                ));
+            }
+            (0..n).map(|_| rng.below(rows)).collect()
+        } else {
+            if n > rows {
+                return Err(format!(
+                    "sample_rows: n {} > row count {} without replacement",
+                    n, rows
+                ));
+            }
+            let mut perm: Vec<usize> = (0..rows).collect();
+            for i in 0..n {
+                let j = i + rng.below(rows - i);
+                perm.swap(i, j);
+            }
+            perm.truncate(n);
+            perm
+        };
+
+        let columns = self.columns.iter().map(|c| c.take(&indices)).collect();
+        Ok(Table::new(self.names.clone(), columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Column;
+
+    fn numbered(n: usize) -> Table {
+        Table::new(
+            vec!["id".to_string(), "x".to_string()],
+            vec![
+                Column::I64((0..n as i64).collect()),
+                Column::F64((0..n).map(|i| i as f64 * 10.0).collect()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_sample_same_seed_same_rows() {
+        let t = numbered(50);
+        let a = t.sample_rows(10, 42, false).unwrap();
+        let b = t.sample_rows(10, 42, false).unwrap();
+        assert!(a.approx_eq(&b, 0.0));
+        assert!(!a.approx_eq(&t.sample_rows(10, 43, false).unwrap(), 0.0));
+
+        // Columns stay aligned
+        for r in a.iter_rows() {
+            assert_eq!(
+                r.get_f64("x"),
+                a.columns[0].i64_data()[r.row()] as f64 * 10.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_sample_without_replacement_is_distinct() {
+        let t = numbered(20);
+        let all = t.sample_rows(20, 7, false).unwrap();
+        let mut ids = all.columns[0].i64_data().to_vec();
+        ids.sort_unstable();
+        assert_eq!(ids, (0..20).collect::<Vec<i64>>());
+
+        assert_eq!(
+            t.sample_rows(21, 7, false).unwrap_err(),
+            "sample_rows: n 21 > row count 20 without replacement"
+        );
+    }
+
+    #[test]
+    fn test_sample_with_replacement_repeats() {
+        let t = numbered(3);
+        let out = t.sample_rows(30, 1, true).unwrap();
+        assert_eq!(out.row_count(), 30);
+
+        let ids = out.columns[0].i64_data();
+        assert!(ids.iter().all(|&i| (0..3).contains(&i)));
+        let mut distinct = ids.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert!(distinct.len() < ids.len());
+
+        assert!(numbered(0).sample_rows(1, 1, true).is_err());
+        assert_eq!(numbered(0).sample_rows(0, 1, true).unwrap().row_count(), 0);
+    }
+}
@@ -0,0 +1,210 @@
+//! Table schemas: ordered column names and type tags
+
+use super::{Column, Table};
+use std::fmt;
+
+/// Type tag of a `Column` variant
+///
+/// Also the declared column type for `io::read_csv` (formats noted below).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnKind {
+    /// Floating point → `Column::F64`
+    F64,
+    /// Floating point → `Column::F32` (parsed as f32)
+    F32,
+    /// `YYYY-MM-DD` → `Column::Date` (days since epoch)
+    Date,
+    /// ISO-8601 `YYYY-MM-DDTHH:MM:SS[.fffffffff][Z]` → `Column::Timestamp` (ns since epoch)
+    Timestamp,
+    /// Same format as Timestamp → `Column::Ts` (deprecated)
+    Ts,
+    /// Signed integer → `Column::I64`
+    I64,
+    /// `true` / `false` → `Column::Bool` (no null)
+    Bool,
+    /// Any text → `Column::Sym` (interned)
+    Sym,
+}
+
+impl Column {
+    /// Type tag of this column
+    pub fn kind(&self) -> ColumnKind {
+        match self {
+            Column::F64(_) => ColumnKind::F64,
+            Column::F32(_) => ColumnKind::F32,
+            Column::Date(_) => ColumnKind::Date,
+            Column::Timestamp(_) => ColumnKind::Timestamp,
+            Column::Ts(_) => ColumnKind::Ts,
+            Column::I64(_) => ColumnKind::I64,
+            Column::Bool(_) => ColumnKind::Bool,
+            Column::Sym { .. } => ColumnKind::Sym,
+            #[cfg(feature = "mmap")]
+            Column::F64Mmap(_) => ColumnKind::F64,
+        }
+    }
+}
+
+/// Ordered (name, kind) pairs describing a table's columns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schema {
+    pub fields: Vec<(String, ColumnKind)>,
+}
+
+/// First divergence between two schemas
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaError {
+    /// Different number of columns
+    ColumnCount { left: usize, right: usize },
+    /// Column `index` has different names
+    Name {
+        index: usize,
+        left: String,
+        right: String,
+    },
+    /// Column `index` (same name) has different kinds
+    Kind {
+        index: usize,
+        name: String,
+        left: ColumnKind,
+        right: ColumnKind,
+    },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::ColumnCount { left, right } => {
+                write!(f, "{} columns vs {}", left, right)
+            }
+            SchemaError::Name { index, left, right } => {
+                write!(f, "column {} is '{}' vs '{}'", index, left, right)
+            }
+            SchemaError::Kind {
+                index,
+                name,
+                left,
+                right,
+            } => write!(
+                f,
+                "column {} ('{}') is {:?} vs {:?}",
+                index, name, left, right
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl Schema {
+    /// Check that `other` has the same names and kinds in the same order
+    ///
+    /// Column order matters: a reordered schema reports a `Name` divergence
+    /// at the first differing position.
+    pub fn compatible_with(&self, other: &Schema) -> Result<(), SchemaError> {
+        for (index, ((ln, lk), (rn, rk))) in self.fields.iter().zip(&other.fields).enumerate() {
+            if ln != rn {
+                return Err(SchemaError::Name {
+                    index,
+                    left: ln.clone(),
+                    right: rn.clone(),
+                });
+            }
+            if lk != rk {
+                return Err(SchemaError::Kind {
+                    index,
+                    name: ln.clone(),
+                    left: *lk,
+                    right: *rk,
+                });
+            }
+        }
+        if self.fields.len() != other.fields.len() {
+            return Err(SchemaError::ColumnCount {
+                left: self.fields.len(),
+                right: other.fields.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Table {
+    /// Column names and kinds, in order
+    pub fn schema(&self) -> Schema {
+        Schema {
+            fields: self
+                .names
+                .iter()
+                .zip(&self.columns)
+                .map(|(name, col)| (name.clone(), col.kind()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(names: &[&str], columns: Vec<Column>) -> Table {
+        Table::new(names.iter().map(|n| n.to_string()).collect(), columns)
+    }
+
+    #[test]
+    fn test_schema_matching() {
+        let a = table(
+            &["d", "px"],
+            vec![Column::Date(vec![1]), Column::F64(vec![1.0])],
+        );
+        let b = table(
+            &["d", "px"],
+            vec![Column::Date(vec![]), Column::F64(vec![])],
+        );
+        assert_eq!(
+            a.schema().fields,
+            vec![
+                ("d".to_string(), ColumnKind::Date),
+                ("px".to_string(), ColumnKind::F64)
+            ]
+        );
+        assert!(a.schema().compatible_with(&b.schema()).is_ok());
+    }
+
+    #[test]
+    fn test_schema_reordered() {
+        let a = table(
+            &["d", "px"],
+            vec![Column::Date(vec![1]), Column::F64(vec![1.0])],
+        );
+        let b = a.select(&["px", "d"]).unwrap();
+        let err = a.schema().compatible_with(&b.schema()).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaError::Name {
+                index: 0,
+                left: "d".to_string(),
+                right: "px".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_schema_type_divergence_and_count() {
+        let a = table(
+            &["d", "px"],
+            vec![Column::Date(vec![1]), Column::F64(vec![1.0])],
+        );
+        let b = table(
+            &["d", "px"],
+            vec![Column::Date(vec![1]), Column::F32(vec![1.0])],
+        );
+        let err = a.schema().compatible_with(&b.schema()).unwrap_err();
+        assert_eq!(err.to_string(), "column 1 ('px') is F64 vs F32");
+
+        let c = a.select(&["d"]).unwrap();
+        assert_eq!(
+            a.schema().compatible_with(&c.schema()),
+            Err(SchemaError::ColumnCount { left: 2, right: 1 })
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Interned symbol (categorical string) columns
+//!
+//! A `Column::Sym` stores one `u32` code per row and a shared dictionary of
+//! distinct strings. Row ops (filter, take, slice) copy codes and share the
+//! dictionary; grouping and joining can work on codes alone.
+
+use super::{Column, NULL_SYM};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Interns strings into codes for a `Column::Sym`
+///
+/// Codes are assigned in first-seen order, so equal strings always share a
+/// code and the dictionary has no duplicates.
+#[derive(Clone, Debug, Default)]
+pub struct SymBuilder {
+    codes: Vec<u32>,
+    dict: Vec<String>,
+    lookup: HashMap<String, u32>,
+}
+
+impl SymBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder seeded with an existing dictionary (codes keep their meaning)
+    pub fn with_dict(dict: &[String]) -> Self {
+        let mut b = SymBuilder::new();
+        for s in dict {
+            b.intern(s);
+        }
+        b
+    }
+
+    /// Code for `s`, adding it to the dictionary if new
+    ///
+    /// # Panics
+    /// Panics if the dictionary would reach `NULL_SYM` entries.
+    pub fn intern(&mut self, s: &str) -> u32 {
+        if let Some(&code) = self.lookup.get(s) {
+            return code;
+        }
+        let code = u32::try_from(self.dict.len())
+            .ok()
+            .filter(|&c| c != NULL_SYM)
+            .expect("SymBuilder: dictionary full");
+        self.dict.push(s.to_string());
+        self.lookup.insert(s.to_string(), code);
+        code
+    }
+
+    /// Append a row; `None` appends NULL_SYM
+    pub fn push(&mut self, s: Option<&str>) {
+        let code = match s {
+            Some(s) => self.intern(s),
+            None => NULL_SYM,
+        };
+        self.codes.push(code);
+    }
+
+    /// Number of rows pushed so far
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Finish into a `Column::Sym`
+    pub fn finish(self) -> Column {
+        Column::Sym {
+            codes: self.codes,
+            dict: Arc::new(self.dict),
+        }
+    }
+}
+
+impl<'a> FromIterator<Option<&'a str>> for SymBuilder {
+    fn from_iter<I: IntoIterator<Item = Option<&'a str>>>(iter: I) -> Self {
+        let mut b = SymBuilder::new();
+        iter.into_iter().for_each(|s| b.push(s));
+        b
+    }
+}
+
+impl Column {
+    /// Code of element `i` (NULL_SYM for null)
+    ///
+    /// # Panics
+    /// Panics if the column is not Sym or `i` is out of bounds.
+    pub fn sym_code(&self, i: usize) -> u32 {
+        match self {
+            Column::Sym { codes, .. } => codes[i],
+            _ => panic!("Not a Sym column"),
+        }
+    }
+
+    /// String of element `i`, `None` for null
+    ///
+    /// # Panics
+    /// Panics if the column is not Sym or `i` is out of bounds.
+    pub fn sym_str(&self, i: usize) -> Option<&str> {
+        match self {
+            Column::Sym { codes, dict } => match codes[i] {
+                NULL_SYM => None,
+                code => Some(dict[code as usize].as_str()),
+            },
+            _ => panic!("Not a Sym column"),
+        }
+    }
+}
+
+/// Append `other`'s symbols to `codes`/`dict`, re-coding if the
+/// dictionaries differ (see `Column::extend_from`)
+pub(super) fn extend_sym(
+    codes: &mut Vec<u32>,
+    dict: &mut Arc<Vec<String>>,
+    other_codes: &[u32],
+    other_dict: &Arc<Vec<String>>,
+) {
+    if Arc::ptr_eq(dict, other_dict) || dict == other_dict {
+        codes.extend_from_slice(other_codes);
+        return;
+    }
+
+    let mut b = SymBuilder::with_dict(dict);
+    let remap: Vec<u32> = other_dict.iter().map(|s| b.intern(s)).collect();
+    codes.extend(other_codes.iter().map(|&c| match c {
+        NULL_SYM => NULL_SYM,
+        c => remap[c as usize],
+    }));
+    *dict = Arc::new(b.dict);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sym_builder_dedups_tickers() {
+        let tickers = ["AAPL", "MSFT", "AAPL", "GOOG", "MSFT", "AAPL"];
+        let col = tickers
+            .iter()
+            .map(|&s| Some(s))
+            .collect::<SymBuilder>()
+            .finish();
+
+        let Column::Sym { codes, dict } = &col else {
+            panic!("expected Sym");
+        };
+        assert_eq!(dict.as_slice(), &["AAPL", "MSFT", "GOOG"]);
+        assert_eq!(codes, &[0, 1, 0, 2, 1, 0]);
+        assert_eq!(col.len(), 6);
+        assert_eq!(col.type_name(), "Sym");
+        for (i, &s) in tickers.iter().enumerate() {
+            assert_eq!(col.sym_str(i), Some(s));
+        }
+        assert!(!col.has_nulls());
+    }
+
+    #[test]
+    fn test_sym_null_code() {
+        let mut b = SymBuilder::new();
+        b.push(Some("IBM"));
+        b.push(None);
+        b.push(Some("IBM"));
+        let col = b.finish();
+
+        assert_eq!(col.sym_code(1), NULL_SYM);
+        assert_eq!(col.sym_str(1), None);
+        assert_eq!(col.sym_code(2), 0);
+        assert!(col.has_nulls());
+        assert!(col.is_null(1) && !col.is_null(0));
+        assert_eq!(col.null_count(), 1);
+    }
+
+    #[test]
+    fn test_sym_row_ops_share_dict() {
+        let col = [Some("a"), Some("b"), None, Some("a")]
+            .into_iter()
+            .collect::<SymBuilder>()
+            .finish();
+
+        let taken = col.take(&[3, 2, 1]);
+        assert_eq!(taken.sym_str(0), Some("a"));
+        assert_eq!(taken.sym_str(1), None);
+        assert_eq!(taken.sym_str(2), Some("b"));
+        let (Column::Sym { dict: d1, .. }, Column::Sym { dict: d2, .. }) = (&col, &taken) else {
+            panic!("expected Sym");
+        };
+        assert!(Arc::ptr_eq(d1, d2));
+    }
+
+    #[test]
+    fn test_sym_extend_recodes_foreign_dict() {
+        let mut a = [Some("x"), Some("y")]
+            .into_iter()
+            .collect::<SymBuilder>()
+            .finish();
+        let b = [Some("z"), None, Some("x")]
+            .into_iter()
+            .collect::<SymBuilder>()
+            .finish();
+        a.extend_from(&b);
+
+        let strs: Vec<Option<&str>> = (0..a.len()).map(|i| a.sym_str(i)).collect();
+        assert_eq!(strs, vec![Some("x"), Some("y"), Some("z"), None, Some("x")]);
+        assert_eq!(a.sym_code(4), a.sym_code(0));
+    }
+}
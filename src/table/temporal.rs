@@ -0,0 +1,305 @@
+//! Calendar arithmetic on Date and Timestamp columns
+//!
+//! Nulls pass through unchanged. Results that would overflow the storage
+//! type, or land on the null sentinel itself, are errors rather than
+//! saturating: a silently clamped date is a wrong date. The one exception
+//! is `date_to_timestamp`, a type conversion whose target simply cannot
+//! hold dates beyond ±292 years; it saturates and says so.
+
+use super::{Column, NULL_DATE, NULL_I64, NULL_TIMESTAMP, NULL_TS};
+
+/// Nanoseconds per day (UTC, no leap seconds)
+const NS_PER_DAY: i64 = 86_400_000_000_000;
+
+impl Column {
+    /// Shift every non-null Date by `days`
+    ///
+    /// NULL_DATE stays null. Errors if the column is not Date or if any
+    /// shifted value overflows i32 (or would equal NULL_DATE).
+    pub fn date_add_days(&self, days: i32) -> Result<Column, String> {
+        let Column::Date(data) = self else {
+            return Err(format!(
+                "date_add_days: expected Date column, got {}",
+                self.type_name()
+            ));
+        };
+
+        data.iter()
+            .map(|&d| {
+                if d == NULL_DATE {
+                    return Ok(NULL_DATE);
+                }
+                d.checked_add(days)
+                    .filter(|&v| v != NULL_DATE)
+                    .ok_or_else(|| format!("date_add_days: {} + {} overflows i32 days", d, days))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Column::Date)
+    }
+
+    /// Floor every non-null Timestamp (or Ts) to a multiple of `period_ns`
+    ///
+    /// Uses floor division, so pre-epoch (negative) timestamps round down
+    /// toward -inf: with a 60s period, -1ns floors to -60s, not 0. Nulls
+    /// stay null. Errors if the column is not Timestamp/Ts, `period_ns` is
+    /// not positive, or a floored value would overflow i64.
+    pub fn timestamp_floor(&self, period_ns: i64) -> Result<Column, String> {
+        if period_ns <= 0 {
+            return Err(format!(
+                "timestamp_floor: period must be positive, got {}",
+                period_ns
+            ));
+        }
+
+        let floor = |data: &[i64], null: i64| {
+            data.iter()
+                .map(|&ts| {
+                    if ts == null {
+                        return Ok(null);
+                    }
+                    ts.checked_sub(ts.rem_euclid(period_ns))
+                        .filter(|&v| v != null)
+                        .ok_or_else(|| {
+                            format!(
+                                "timestamp_floor: flooring {} to {}ns overflows i64",
+                                ts, period_ns
+                            )
+                        })
+                })
+                .collect::<Result<Vec<_>, String>>()
+        };
+
+        match self {
+            Column::Timestamp(data) => floor(data, NULL_TIMESTAMP).map(Column::Timestamp),
+            Column::Ts(data) => floor(data, NULL_TS).map(Column::Ts),
+            _ => Err(format!(
+                "timestamp_floor: expected Timestamp column, got {}",
+                self.type_name()
+            )),
+        }
+    }
+
+    /// Timestamp (or Ts) nanoseconds to Date days since the epoch
+    ///
+    /// Floors toward -inf, so 1ns before the epoch is day -1, not day 0.
+    /// Nulls become NULL_DATE. Every i64 nanosecond count is within about
+    /// ±106752 days, so the result always fits i32 and never hits
+    /// NULL_DATE; no clamping is needed in this direction.
+    ///
+    /// # Panics
+    /// Panics if the column is not Timestamp or Ts.
+    pub fn timestamp_to_date(&self) -> Column {
+        // NULL_TS == NULL_TIMESTAMP: one check covers both
+        let (Column::Timestamp(data) | Column::Ts(data)) = self else {
+            panic!(
+                "timestamp_to_date: expected Timestamp column, got {}",
+                self.type_name()
+            );
+        };
+        Column::Date(
+            data.iter()
+                .map(|&ts| {
+                    if ts == NULL_TIMESTAMP {
+                        NULL_DATE
+                    } else {
+                        ts.div_euclid(NS_PER_DAY) as i32
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Date days to Timestamp nanoseconds at midnight UTC
+    ///
+    /// NULL_DATE becomes NULL_TIMESTAMP. Only about ±292 years around the
+    /// epoch are representable in i64 nanoseconds; dates outside that
+    /// saturate to `i64::MAX` / `i64::MIN + 1` (never the null sentinel).
+    ///
+    /// # Panics
+    /// Panics if the column is not Date.
+    pub fn date_to_timestamp(&self) -> Column {
+        let Column::Date(data) = self else {
+            panic!(
+                "date_to_timestamp: expected Date column, got {}",
+                self.type_name()
+            );
+        };
+        Column::Timestamp(
+            data.iter()
+                .map(|&d| {
+                    if d == NULL_DATE {
+                        NULL_TIMESTAMP
+                    } else {
+                        (d as i64)
+                            .saturating_mul(NS_PER_DAY)
+                            .max(NULL_TIMESTAMP + 1)
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Day differences `a - b` between two Date columns, as I64
+///
+/// NULL_I64 where either side is NULL_DATE. Never overflows: the difference
+/// of two i32 values always fits in i64.
+///
+/// # Panics
+/// Panics if either column is not Date or the lengths differ.
+pub fn date_diff(a: &Column, b: &Column) -> Column {
+    let (Column::Date(a), Column::Date(b)) = (a, b) else {
+        panic!("date_diff: expected Date columns");
+    };
+    assert_eq!(a.len(), b.len(), "date_diff: column length mismatch");
+
+    Column::I64(
+        a.iter()
+            .zip(b)
+            .map(|(&x, &y)| {
+                if x == NULL_DATE || y == NULL_DATE {
+                    NULL_I64
+                } else {
+                    x as i64 - y as i64
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NS_PER_SEC: i64 = 1_000_000_000;
+
+    #[test]
+    fn test_date_add_days_keeps_nulls() {
+        let col = Column::Date(vec![18628, NULL_DATE, -5]);
+        let out = col.date_add_days(7).unwrap();
+        assert_eq!(out.date_data(), &[18635, NULL_DATE, 2]);
+
+        let back = out.date_add_days(-7).unwrap();
+        assert_eq!(back.date_data(), col.date_data());
+    }
+
+    #[test]
+    fn test_date_add_days_overflow_errors() {
+        let col = Column::Date(vec![0, i32::MAX - 1]);
+        let err = col.date_add_days(10).unwrap_err();
+        assert_eq!(
+            err,
+            format!("date_add_days: {} + 10 overflows i32 days", i32::MAX - 1)
+        );
+
+        // Landing exactly on the null sentinel is an overflow too
+        assert!(Column::Date(vec![i32::MIN + 1]).date_add_days(-1).is_err());
+        assert!(Column::F64(vec![1.0]).date_add_days(1).is_err());
+    }
+
+    #[test]
+    fn test_date_diff_nulls() {
+        let a = Column::Date(vec![18635, NULL_DATE, 100, i32::MAX]);
+        let b = Column::Date(vec![18628, 1, NULL_DATE, i32::MIN + 1]);
+        let out = date_diff(&a, &b);
+        assert_eq!(
+            out.i64_data(),
+            &[
+                7,
+                NULL_I64,
+                NULL_I64,
+                i32::MAX as i64 - (i32::MIN as i64 + 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_floor_minute() {
+        let minute = 60 * NS_PER_SEC;
+        let col = Column::Timestamp(vec![
+            minute * 5 + 30 * NS_PER_SEC,
+            minute * 6 - 1, // just before the 6-minute boundary
+            minute * 6,
+            -1, // pre-epoch: floors down, not toward zero
+            -minute - 1,
+            NULL_TIMESTAMP,
+        ]);
+
+        let out = col.timestamp_floor(minute).unwrap();
+        assert_eq!(
+            out.timestamp_data(),
+            &[
+                minute * 5,
+                minute * 5,
+                minute * 6,
+                -minute,
+                -2 * minute,
+                NULL_TIMESTAMP
+            ]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_date_across_epoch() {
+        let col = Column::Timestamp(vec![
+            0,
+            NS_PER_DAY - 1,
+            NS_PER_DAY,
+            -1, // pre-epoch: floors to the previous day
+            -NS_PER_DAY,
+            -NS_PER_DAY - 1,
+            NULL_TIMESTAMP,
+            i64::MAX,
+            i64::MIN + 1,
+        ]);
+        let out = col.timestamp_to_date();
+        assert_eq!(
+            out.date_data(),
+            &[0, 0, 1, -1, -1, -2, NULL_DATE, 106751, -106752]
+        );
+        assert_eq!(
+            Column::Ts(vec![NS_PER_DAY * 3, NULL_TS])
+                .timestamp_to_date()
+                .date_data(),
+            &[3, NULL_DATE]
+        );
+    }
+
+    #[test]
+    fn test_date_to_timestamp_round_trip() {
+        let col = Column::Date(vec![0, 1, -1, 18628, NULL_DATE]);
+        let ts = col.date_to_timestamp();
+        assert_eq!(
+            ts.timestamp_data(),
+            &[
+                0,
+                NS_PER_DAY,
+                -NS_PER_DAY,
+                18628 * NS_PER_DAY,
+                NULL_TIMESTAMP
+            ]
+        );
+        assert_eq!(ts.timestamp_to_date().date_data(), col.date_data());
+
+        // Out of i64 range: saturate, never land on the null sentinel
+        let far = Column::Date(vec![i32::MAX, i32::MIN + 1]).date_to_timestamp();
+        assert_eq!(far.timestamp_data(), &[i64::MAX, i64::MIN + 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "timestamp_to_date: expected Timestamp column, got Date")]
+    fn test_timestamp_to_date_wrong_type() {
+        Column::Date(vec![1]).timestamp_to_date();
+    }
+
+    #[test]
+    fn test_timestamp_floor_errors() {
+        let col = Column::Timestamp(vec![0]);
+        assert!(col.timestamp_floor(0).is_err());
+        assert!(col.timestamp_floor(-5).is_err());
+        assert!(Column::Date(vec![0]).timestamp_floor(10).is_err());
+        assert!(Column::Timestamp(vec![i64::MIN + 1])
+            .timestamp_floor(3)
+            .is_err());
+    }
+}
@@ -4,9 +4,52 @@
 //! Physical storage remains columnar, orientation just changes interpretation.
 
 use super::orientation::{Ori, OriClass, ReduceMode, VecAxis, ORI_H};
-use super::Table;
+use super::{Column, Table};
+use std::fmt;
 use std::sync::Arc;
 
+/// Checked element access error (see `TableView::try_get_f64`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ViewError {
+    /// Logical (i, j) outside the view's logical shape
+    OutOfBounds {
+        i: usize,
+        j: usize,
+        shape: (usize, usize),
+    },
+    /// Physical column has a different type than requested
+    TypeMismatch {
+        col: usize,
+        name: String,
+        expected: &'static str,
+        actual: &'static str,
+    },
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViewError::OutOfBounds { i, j, shape } => write!(
+                f,
+                "index ({}, {}) out of bounds for logical shape {}x{}",
+                i, j, shape.0, shape.1
+            ),
+            ViewError::TypeMismatch {
+                col,
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "column {} ('{}') is {}, not {}",
+                col, name, actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ViewError {}
+
 /// A view of a Table with an orientation
 ///
 /// This is the key abstraction for O(1) orientation changes:
@@ -95,18 +138,129 @@ impl TableView {
     /// Maps through orientation to physical storage.
     ///
     /// # Panics
-    /// Panics if indices are out of bounds or column type mismatch.
+    /// Panics if indices are out of bounds or column type mismatch
+    /// (the message names the column and its actual type).
+    /// See `try_get_f64` for a non-panicking version.
     pub fn get_f64(&self, i: usize, j: usize) -> f64 {
-        let (nr, nc) = self.physical_shape();
-        let (phys_r, phys_c) = self.ori.map_ij(nr, nc, i, j);
+        self.try_get_f64(i, j)
+            .unwrap_or_else(|e| panic!("get_f64: {}", e))
+    }
 
-        // Physical storage is columns[phys_c][phys_r]
+    /// Checked access to an F64 element at logical indices (i, j)
+    ///
+    /// Validates (i, j) against `logical_shape()` before mapping through the
+    /// orientation, then checks the physical column is F64.
+    pub fn try_get_f64(&self, i: usize, j: usize) -> Result<f64, ViewError> {
+        let (phys_r, phys_c) = self.checked_map_ij(i, j)?;
         match &self.table.columns[phys_c] {
-            super::Column::F64(data) => data[phys_r],
-            _ => panic!("Column {} is not F64", phys_c),
+            Column::F64(data) => Ok(data[phys_r]),
+            other => Err(self.type_mismatch(phys_c, "F64", other)),
+        }
+    }
+
+    /// Checked access to an I64 element at logical indices (i, j)
+    ///
+    /// Same validation as `try_get_f64`; nulls are returned as `NULL_I64`.
+    pub fn try_get_i64(&self, i: usize, j: usize) -> Result<i64, ViewError> {
+        let (phys_r, phys_c) = self.checked_map_ij(i, j)?;
+        match &self.table.columns[phys_c] {
+            Column::I64(data) => Ok(data[phys_r]),
+            other => Err(self.type_mismatch(phys_c, "I64", other)),
+        }
+    }
+
+    /// Bounds-check logical (i, j) and map to physical (row, col)
+    fn checked_map_ij(&self, i: usize, j: usize) -> Result<(usize, usize), ViewError> {
+        let shape = self.logical_shape();
+        if i >= shape.0 || j >= shape.1 {
+            return Err(ViewError::OutOfBounds { i, j, shape });
+        }
+        let (nr, nc) = self.physical_shape();
+        Ok(self.ori.map_ij(nr, nc, i, j))
+    }
+
+    fn type_mismatch(&self, col: usize, expected: &'static str, actual: &Column) -> ViewError {
+        ViewError::TypeMismatch {
+            col,
+            name: self.table.names[col].clone(),
+            expected,
+            actual: actual.type_name(),
+        }
+    }
+
+    /// Build a Table whose physical layout equals this view's logical layout
+    ///
+    /// Walks every logical (i, j) through `map_ij`. Flips reverse rows and/or
+    /// column order (any column types, names follow their columns). Transposing
+    /// orientations (Z family) produce a genuinely transposed table whose
+    /// column k is named `"r{row}"` after the physical row it came from. Each
+    /// and Real have no reordering and return a clone.
+    ///
+    /// Unlike the O(1) orientation change this copies all data: O(n).
+    ///
+    /// # Errors
+    /// `TypeMismatch` for transposing orientations if any column is not F64
+    /// (a physical row mixing a Date and an F64 cannot become one typed
+    /// column). Non-transposing orientations accept every column type.
+    pub fn materialize(&self) -> Result<Table, ViewError> {
+        let (nr, nc) = self.physical_shape();
+        let (log_nr, log_nc) = self.logical_shape();
+
+        match self.ori {
+            Ori::D4 { swap: false, .. } => {
+                let rows: Vec<usize> = (0..log_nr)
+                    .map(|i| self.ori.map_ij(nr, nc, i, 0).0)
+                    .collect();
+                let identity_rows = rows.iter().enumerate().all(|(k, &r)| k == r);
+
+                let mut names = Vec::with_capacity(log_nc);
+                let mut columns = Vec::with_capacity(log_nc);
+                for j in 0..log_nc {
+                    let phys_c = self.ori.map_ij(nr, nc, 0, j).1;
+                    let col = &self.table.columns[phys_c];
+                    names.push(self.table.names[phys_c].clone());
+                    columns.push(if identity_rows {
+                        col.clone()
+                    } else {
+                        col.take(&rows)
+                    });
+                }
+                Ok(Table::new(names, columns))
+            }
+            Ori::D4 { swap: true, .. } => {
+                if let Some((c, col)) = self
+                    .table
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .find(|(_, col)| !matches!(col, Column::F64(_)))
+                {
+                    return Err(self.type_mismatch(c, "F64", col));
+                }
+
+                let mut names = Vec::with_capacity(log_nc);
+                let mut columns = Vec::with_capacity(log_nc);
+                for j in 0..log_nc {
+                    let phys_r = self.ori.map_ij(nr, nc, 0, j).0;
+                    names.push(format!("r{}", phys_r));
+                    columns.push(Column::F64(
+                        (0..log_nr).map(|i| self.get_f64(i, j)).collect(),
+                    ));
+                }
+                Ok(Table::new(names, columns))
+            }
+            Ori::Each | Ori::Real => Ok((*self.table).clone()),
         }
     }
 
+    /// Project a subset of columns by name, preserving the current orientation
+    ///
+    /// See `Table::select`. The result owns a new table.
+    pub fn select_columns(&self, names: &[&str]) -> Result<TableView, String> {
+        let table = self.table.select(names)?;
+        Ok(TableView::with_ori(table, self.ori))
+    }
+
     /// Check if this view shares the same underlying table with another view
     pub fn shares_table_with(&self, other: &TableView) -> bool {
         Arc::ptr_eq(&self.table, &other.table)
@@ -228,6 +382,32 @@ mod tests {
         assert_eq!(view.get_f64(3, 2), 23.0);
     }
 
+    #[test]
+    #[should_panic(expected = "column 1 ('mask') is Bool, not F64")]
+    fn test_get_f64_on_bool_column_panics() {
+        let table = Table::new(
+            vec!["x".to_string(), "mask".to_string()],
+            vec![Column::F64(vec![1.0, 2.0]), Column::Bool(vec![true, false])],
+        );
+        let view = TableView::new(table);
+        view.get_f64(0, 1);
+    }
+
+    #[test]
+    fn test_select_columns_preserves_orientation() {
+        let view = TableView::with_ori(make_test_table(), ORI_Z);
+        let sub = view.select_columns(&["col3", "col1"]).unwrap();
+
+        assert_eq!(sub.ori, ORI_Z);
+        assert_eq!(sub.table.names, vec!["col3", "col1"]);
+        assert_eq!(sub.physical_shape(), (3, 2));
+        assert_eq!(sub.logical_shape(), (2, 3));
+        // Logical (0, 1) under Z → physical (1, 0) → col3[1] = 13
+        assert_eq!(sub.get_f64(0, 1), 13.0);
+
+        assert!(view.select_columns(&["missing"]).is_err());
+    }
+
     #[test]
     fn test_multiple_views_same_table() {
         let table = Arc::new(make_test_table());
@@ -318,4 +498,146 @@ mod tests {
         assert!(view_r.compose_orientation(ORI_Z).is_none());
         assert!(view_r.compose_orientation(ORI_H).is_none());
     }
+
+    #[test]
+    fn test_materialize_h_is_clone() {
+        let table = make_test_table();
+        let out = TableView::with_ori(table.clone(), ORI_H)
+            .materialize()
+            .unwrap();
+        assert_eq!(out.names, table.names);
+        for (a, b) in out.columns.iter().zip(&table.columns) {
+            assert_eq!(a.f64_data(), b.f64_data());
+        }
+    }
+
+    #[test]
+    fn test_materialize_z_transposes() {
+        let table = make_test_table();
+        let (nr, nc) = (table.row_count(), table.col_count());
+        let out = TableView::with_ori(table.clone(), ORI_Z)
+            .materialize()
+            .unwrap();
+
+        // Hand-transposed: column r = physical row r
+        assert_eq!((out.row_count(), out.col_count()), (nc, nr));
+        assert_eq!(out.names, vec!["r0", "r1", "r2"]);
+        for r in 0..nr {
+            let expected: Vec<f64> = table.columns.iter().map(|c| c.f64_data()[r]).collect();
+            assert_eq!(out.columns[r].f64_data(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_materialize_matches_logical_view() {
+        use crate::table::{ORI_N, ORI__H, ORI__SZ, ORI__Z};
+
+        for ori in [ORI_N, ORI__H, ORI__Z, ORI__SZ] {
+            let view = TableView::with_ori(make_test_table(), ori);
+            let out = TableView::new(view.materialize().unwrap());
+            assert_eq!(out.logical_shape(), view.logical_shape());
+            let (lr, lc) = view.logical_shape();
+            for i in 0..lr {
+                for j in 0..lc {
+                    assert_eq!(out.get_f64(i, j), view.get_f64(i, j), "{:?}", ori);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_materialize_flip_keeps_types() {
+        use crate::table::{NULL_DATE, ORI__H};
+
+        let table = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![
+                Column::Date(vec![1, NULL_DATE]),
+                Column::F64(vec![0.5, 1.5]),
+            ],
+        );
+        let out = TableView::with_ori(table, ORI__H).materialize().unwrap();
+        assert_eq!(out.names, vec!["x", "d"]);
+        assert_eq!(out.columns[0].f64_data(), &[1.5, 0.5]);
+        assert_eq!(out.columns[1].date_data(), &[NULL_DATE, 1]);
+    }
+
+    #[test]
+    fn test_materialize_transpose_rejects_date_column() {
+        use crate::table::ORI_Z;
+
+        let table = Table::new(
+            vec!["d".to_string(), "x".to_string()],
+            vec![Column::Date(vec![1, 2]), Column::F64(vec![0.5, 1.5])],
+        );
+        let err = TableView::with_ori(table.clone(), ORI_Z)
+            .materialize()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ViewError::TypeMismatch {
+                col: 0,
+                name: "d".to_string(),
+                expected: "F64",
+                actual: "Date",
+            }
+        );
+
+        // Transposing just the F64 columns works
+        let x = TableView::with_ori(table.select(&["x"]).unwrap(), ORI_Z);
+        let out = x.materialize().unwrap();
+        assert_eq!(out.names, vec!["r0", "r1"]);
+    }
+
+    #[test]
+    fn test_try_get_f64_out_of_range() {
+        let view = TableView::with_ori(make_test_table(), ORI_H);
+        let (nr, nc) = view.logical_shape();
+        assert!(view.try_get_f64(nr - 1, nc - 1).is_ok());
+
+        let err = view.try_get_f64(nr, 0).unwrap_err();
+        assert_eq!(
+            err,
+            ViewError::OutOfBounds {
+                i: nr,
+                j: 0,
+                shape: (nr, nc)
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_get_f64_transposed_bounds() {
+        // Physical 3x4; under Z the logical shape is 4x3
+        let view = TableView::with_ori(make_test_table(), ORI_Z);
+        assert_eq!(view.logical_shape(), (4, 3));
+
+        assert_eq!(view.try_get_f64(3, 2).unwrap(), view.get_f64(3, 2));
+        assert!(matches!(
+            view.try_get_f64(0, 3),
+            Err(ViewError::OutOfBounds { j: 3, .. })
+        ));
+        assert!(view.try_get_f64(4, 0).is_err());
+    }
+
+    #[test]
+    fn test_try_get_wrong_type() {
+        let table = Table::new(
+            vec!["x".to_string(), "n".to_string()],
+            vec![Column::F64(vec![1.5]), Column::I64(vec![7])],
+        );
+        let view = TableView::new(table);
+
+        let err = view.try_get_f64(0, 1).unwrap_err();
+        assert_eq!(err.to_string(), "column 1 ('n') is I64, not F64");
+        assert_eq!(view.try_get_i64(0, 1).unwrap(), 7);
+        assert!(matches!(
+            view.try_get_i64(0, 0),
+            Err(ViewError::TypeMismatch {
+                expected: "I64",
+                actual: "F64",
+                ..
+            })
+        ));
+    }
 }
@@ -0,0 +1,472 @@
+//! Fused vs. unfused pipeline equivalence
+//!
+//! Each fused pipeline must produce the same output as applying the
+//! corresponding column ops one at a time.
+
+use blawktrust::builtins::kernels_masked::dlog_no_nulls;
+use blawktrust::builtins::{
+    clip_column, diff_column, dlog_column, ori_ops, rolling_moments_past_only_f64, MomentsMask,
+};
+use blawktrust::exec;
+use blawktrust::pipeline::{ExecutionValue, Executor, OpId, PipeIR, Planner, RollWindow, Step};
+use blawktrust::{Column, Table, TableView, ORI_H, ORI_Z};
+
+fn run(ir: &PipeIR, input: Table) -> Table {
+    let plan = Planner::plan(ir).unwrap();
+    let mut executor = Executor::new();
+    match executor.execute(&plan, input).unwrap().value {
+        ExecutionValue::Table(t) => t,
+        other => panic!("Expected Table result, got {:?}", other),
+    }
+}
+
+fn assert_same(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!(
+            (x.is_nan() && y.is_nan()) || x == y || (x - y).abs() < 1e-12,
+            "mismatch at {}: {} vs {}",
+            i,
+            x,
+            y
+        );
+    }
+}
+
+#[test]
+fn diff_fused_matches_column_op() {
+    let data = vec![1.0, 4.0, f64::NAN, 16.0, 25.0, 36.0];
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    for lag in [1usize, 2, 10] {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Diff,
+            args: vec![lag as f64],
+        });
+
+        let fused = run(&ir, input.clone());
+        let baseline = diff_column(&Column::F64(data.clone()), lag);
+        assert_same(fused.columns[0].f64_data(), baseline.f64_data());
+    }
+}
+
+#[test]
+fn diff_chain_fused_matches_column_ops() {
+    let data: Vec<f64> = (0..50).map(|i| (i * i) as f64).collect();
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    // (o H) (diff 1) (diff 1) (x* 0.5)
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Diff,
+        args: vec![1.0],
+    });
+    ir.push(Step::Op {
+        name: OpId::Diff,
+        args: vec![1.0],
+    });
+    ir.push(Step::Op {
+        name: OpId::MulConst,
+        args: vec![0.5],
+    });
+
+    let fused = run(&ir, input);
+
+    let d2 = diff_column(&diff_column(&Column::F64(data), 1), 1);
+    let expected: Vec<f64> = d2.f64_data().iter().map(|x| x * 0.5).collect();
+    assert_same(fused.columns[0].f64_data(), &expected);
+}
+
+#[test]
+fn dlog_scale_exp_fused_matches_manual() {
+    let data = vec![100.0, 101.0, 99.5, f64::NAN, 102.0, 103.5];
+    let input = Table::new(vec!["px".to_string()], vec![Column::F64(data.clone())]);
+
+    // (o H) (dlog) (x* 2) (exp) — single fused pass
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Dlog,
+        args: vec![1.0],
+    });
+    ir.push(Step::Op {
+        name: OpId::MulConst,
+        args: vec![2.0],
+    });
+    ir.push(Step::Op {
+        name: OpId::Exp,
+        args: vec![],
+    });
+
+    let plan = Planner::plan(&ir).unwrap();
+    assert_eq!(plan.segments.len(), 1);
+    let fused = run(&ir, input);
+
+    let mut expected = vec![f64::NAN; data.len()];
+    for i in 1..data.len() {
+        expected[i] = (2.0 * (data[i].ln() - data[i - 1].ln())).exp();
+    }
+    assert_same(fused.columns[0].f64_data(), &expected);
+}
+
+#[test]
+fn pow_sqrt_fused_matches_manual() {
+    let data = vec![4.0, -9.0, f64::NAN, 0.25];
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    // (o H) (pow 3) (sqrt)
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Pow,
+        args: vec![3.0],
+    });
+    ir.push(Step::Op {
+        name: OpId::Sqrt,
+        args: vec![],
+    });
+
+    let fused = run(&ir, input);
+    let expected: Vec<f64> = data.iter().map(|x| x.powf(3.0).sqrt()).collect();
+    assert_same(fused.columns[0].f64_data(), &expected);
+}
+
+#[test]
+fn rolling_moments_ops_match_kernel() {
+    let data: Vec<f64> = (0..40).map(|i| ((i * 7) % 11) as f64 - 3.0).collect();
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+    let direct = rolling_moments_past_only_f64(
+        &data,
+        5,
+        None,
+        MomentsMask::new(MomentsMask::MEAN | MomentsMask::STD),
+        None,
+    );
+
+    for (op, expected) in [
+        (OpId::RollMean(RollWindow::new(5)), direct.mean.unwrap()),
+        (OpId::RollStd(RollWindow::new(5)), direct.std.unwrap()),
+    ] {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: op,
+            args: vec![],
+        });
+        let out = run(&ir, input.clone());
+        assert_same(out.columns[0].f64_data(), &expected);
+    }
+}
+
+#[test]
+fn rolling_std_between_fused_ops() {
+    let data: Vec<f64> = (1..60).map(|i| 100.0 + (i as f64).sin()).collect();
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    // (o H) (dlog 1) (roll_std 10) (x* 2)
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Dlog,
+        args: vec![1.0],
+    });
+    ir.push(Step::Op {
+        name: OpId::RollStd(RollWindow::new(10)),
+        args: vec![],
+    });
+    ir.push(Step::Op {
+        name: OpId::MulConst,
+        args: vec![2.0],
+    });
+    let out = run(&ir, input);
+
+    let mut returns = vec![f64::NAN; data.len()];
+    for i in 1..data.len() {
+        returns[i] = data[i].ln() - data[i - 1].ln();
+    }
+    let std =
+        rolling_moments_past_only_f64(&returns, 10, None, MomentsMask::new(MomentsMask::STD), None)
+            .std
+            .unwrap();
+    let expected: Vec<f64> = std.iter().map(|s| s * 2.0).collect();
+    assert_same(out.columns[0].f64_data(), &expected);
+}
+
+#[test]
+fn rolling_min_periods_emits_earlier() {
+    // Series starts with missing history (e.g. a late listing)
+    let mut data = vec![f64::NAN; 4];
+    data.extend((0..10).map(|i| i as f64));
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    let roll_mean = |w: RollWindow| {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::RollMean(w),
+            args: vec![],
+        });
+        run(&ir, input.clone()).columns[0].f64_data().to_vec()
+    };
+
+    let full = roll_mean(RollWindow::new(6));
+    let early = roll_mean(RollWindow::with_min_periods(6, 2));
+
+    let first_value = |v: &[f64]| v.iter().position(|x| !x.is_nan()).unwrap();
+    assert_eq!(first_value(&full), 10);
+    assert_eq!(first_value(&early), 6);
+    assert_eq!(early[6], 0.5); // window [0..5] has valid values 0, 1
+    assert_same(&early[10..], &full[10..]);
+
+    let direct =
+        rolling_moments_past_only_f64(&data, 6, Some(2), MomentsMask::new(MomentsMask::MEAN), None);
+    assert_same(&early, &direct.mean.unwrap());
+}
+
+#[test]
+fn chunked_dlog_matches_whole_column() {
+    let data: Vec<f64> = (1..=30).map(|i| 100.0 + (i as f64 * 0.7).sin()).collect();
+    let whole = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Dlog,
+        args: vec![1.0],
+    });
+    let expected = run(&ir, whole);
+
+    let plan = Planner::plan(&ir).unwrap();
+    let chunks = [&data[..13], &data[13..]]
+        .map(|part| Table::new(vec!["x".to_string()], vec![Column::F64(part.to_vec())]));
+
+    let mut parts = Vec::new();
+    Executor::new()
+        .execute_chunked(&plan, chunks.into_iter(), &mut |t| parts.push(t))
+        .unwrap();
+
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].row_count(), 13);
+    let refs: Vec<&Table> = parts.iter().collect();
+    let chunked = Table::concat_rows(&refs).unwrap();
+    assert_same(
+        chunked.columns[0].f64_data(),
+        expected.columns[0].f64_data(),
+    );
+    // The seam row sees the previous chunk's last value
+    assert!(!chunked.columns[0].f64_data()[13].is_nan());
+}
+
+#[test]
+fn chunked_cumsum_matches_whole_column() {
+    let data = vec![
+        1.5,
+        -2.0,
+        f64::NAN,
+        4.25,
+        0.5,
+        3.0,
+        -1.0,
+        f64::NAN,
+        2.0,
+        7.5,
+    ];
+    let whole = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Cs1,
+        args: vec![],
+    });
+    let expected = run(&ir, whole);
+
+    let plan = Planner::plan(&ir).unwrap();
+    let chunks = [&data[..4], &data[4..]]
+        .map(|part| Table::new(vec!["x".to_string()], vec![Column::F64(part.to_vec())]));
+
+    let mut parts = Vec::new();
+    Executor::new()
+        .execute_chunked(&plan, chunks.into_iter(), &mut |t| parts.push(t))
+        .unwrap();
+
+    assert_eq!(parts.len(), 2);
+    let refs: Vec<&Table> = parts.iter().collect();
+    let chunked = Table::concat_rows(&refs).unwrap();
+    assert_same(
+        chunked.columns[0].f64_data(),
+        expected.columns[0].f64_data(),
+    );
+    // The second chunk continues the first chunk's running total
+    assert_eq!(chunked.columns[0].f64_data()[4], 4.25);
+}
+
+#[test]
+fn exec_run_diff_matches_column_op() {
+    let data = vec![1.0, 4.0, f64::NAN, 16.0, 25.0, 36.0];
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    for lag in [1usize, 2, 10] {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Diff,
+            args: vec![lag as f64],
+        });
+
+        let out = exec::run(&ir, input.clone()).unwrap();
+        let baseline = diff_column(&Column::F64(data.clone()), lag);
+        assert_same(out.columns[0].f64_data(), baseline.f64_data());
+    }
+}
+
+#[test]
+fn exec_run_expr_dlog_scale_exp_matches_manual() {
+    use blawktrust::expr::Expr;
+
+    let data = vec![100.0, 101.0, 99.5, f64::NAN, 102.0, 103.5];
+    let input = Table::new(vec!["px".to_string()], vec![Column::F64(data.clone())]);
+
+    // Same chain as above minus the exp, which Expr has no node for
+    let e = Expr::Mul(
+        Box::new(Expr::Dlog(Box::new(Expr::col("px")), 1)),
+        Box::new(Expr::Const(2.0)),
+    );
+    let out = exec::run_expr(&e, input).unwrap();
+
+    let mut expected = vec![f64::NAN; data.len()];
+    for i in 1..data.len() {
+        expected[i] = 2.0 * (data[i].ln() - data[i - 1].ln());
+    }
+    assert_same(out.f64_data(), &expected);
+}
+
+#[test]
+fn ln_add_fused_matches_manual() {
+    // (o H) (ln) (x+ 1)
+    let data = vec![1.0, 10.0, 0.0, -2.0, f64::NAN, 0.5];
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Ln,
+        args: vec![],
+    });
+    ir.push(Step::Op {
+        name: OpId::AddConst,
+        args: vec![1.0],
+    });
+    assert!(Planner::plan(&ir).unwrap().segments[0].is_fusable());
+
+    let fused = run(&ir, input);
+    // ln of x <= 0 is NaN, not -inf
+    let expected: Vec<f64> = data
+        .iter()
+        .map(|&x| if x <= 0.0 { f64::NAN } else { x.ln() + 1.0 })
+        .collect();
+    assert_same(fused.columns[0].f64_data(), &expected);
+}
+
+#[test]
+fn dlog_non_positive_agrees_across_kernels() {
+    let data = vec![100.0, 0.0, 50.0, 55.0, -5.0, 20.0, 22.0, f64::NAN, 30.0];
+    let n = data.len();
+
+    // Column op
+    let column = dlog_column(&Column::F64(data.clone()), 1);
+
+    // Raw no-nulls kernel
+    let mut raw = vec![0.0; n];
+    dlog_no_nulls(&mut raw, &data, 1);
+
+    // Fused pipeline
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Dlog,
+        args: vec![1.0],
+    });
+    let fused = run(
+        &ir,
+        Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]),
+    );
+
+    // Rowwise: the series laid out across one row
+    let wide = Table::new(
+        (0..n).map(|i| format!("c{}", i)).collect(),
+        data.iter().map(|&x| Column::F64(vec![x])).collect(),
+    );
+    let rowwise = ori_ops::dlog(&TableView::with_ori(wide, ORI_Z));
+    let rowwise: Vec<f64> = rowwise.columns.iter().map(|c| c.f64_data()[0]).collect();
+
+    let mut expected = vec![f64::NAN; n];
+    expected[3] = 55.0_f64.ln() - 50.0_f64.ln();
+    expected[6] = 22.0_f64.ln() - 20.0_f64.ln();
+
+    assert_same(column.f64_data(), &expected);
+    assert_same(&raw, &expected);
+    assert_same(fused.columns[0].f64_data(), &expected);
+    assert_same(&rowwise, &expected);
+}
+
+#[test]
+fn clip_fused_matches_column_op() {
+    let data = vec![-3.0, 0.5, f64::NAN, 2.5, 9.0];
+    let input = Table::new(vec!["x".to_string()], vec![Column::F64(data.clone())]);
+
+    // NaN arg leaves that side open: (clip NaN 1) is an upper bound only
+    for (lo, hi) in [(Some(0.0), Some(1.0)), (None, Some(1.0)), (Some(0.0), None)] {
+        let mut ir = PipeIR::new();
+        ir.push(Step::OriSet(ORI_H));
+        ir.push(Step::Op {
+            name: OpId::Clip,
+            args: vec![lo.unwrap_or(f64::NAN), hi.unwrap_or(f64::NAN)],
+        });
+        ir.push(Step::Op {
+            name: OpId::MulConst,
+            args: vec![2.0],
+        });
+
+        let fused = run(&ir, input.clone());
+        let clipped = clip_column(&Column::F64(data.clone()), lo, hi);
+        let expected: Vec<f64> = clipped.f64_data().iter().map(|x| x * 2.0).collect();
+        assert_same(fused.columns[0].f64_data(), &expected);
+    }
+}
+
+#[test]
+fn view_pipeline_matches_hand_built_ir() {
+    let input = Table::new(
+        vec!["a".to_string(), "b".to_string()],
+        vec![
+            Column::F64(vec![100.0, 101.0, f64::NAN, 99.0, 103.0, 104.5, 102.0]),
+            Column::F64(vec![10.0, 10.5, 10.25, 11.0, 10.75, 11.5, 12.0]),
+        ],
+    );
+
+    let mut ir = PipeIR::new();
+    ir.push(Step::OriSet(ORI_H));
+    ir.push(Step::Op {
+        name: OpId::Dlog,
+        args: vec![],
+    });
+    ir.push(Step::Op {
+        name: OpId::MulConst,
+        args: vec![2.0],
+    });
+    let expected = run(&ir, input.clone());
+
+    let view = TableView::new(input);
+    let fluent = view.dlog().mul(2.0).collect().unwrap();
+
+    assert_eq!(fluent.names, expected.names);
+    for (f, e) in fluent.columns.iter().zip(&expected.columns) {
+        assert_same(f.f64_data(), e.f64_data());
+    }
+    // The view is untouched and can start another chain
+    assert_eq!(view.table.columns[0].f64_data()[0], 100.0);
+}